@@ -18,6 +18,7 @@ mod iter_simple_sparse_set;
 mod iter_simple_system;
 mod iter_simple_wide;
 mod iter_simple_wide_sparse_set;
+mod par_iter_reduce;
 mod par_iter_simple;
 
 use heavy_compute::*;
@@ -29,6 +30,7 @@ criterion_group!(
     iter_simple,
     heavy_compute,
     par_iter_simple,
+    par_iter_reduce,
 );
 
 fn iter_simple(c: &mut Criterion) {
@@ -131,3 +133,26 @@ fn par_iter_simple(c: &mut Criterion) {
         });
     }
 }
+
+fn par_iter_reduce(c: &mut Criterion) {
+    let mut group = c.benchmark_group("par_iter_reduce");
+    group.warm_up_time(std::time::Duration::from_millis(500));
+    group.measurement_time(std::time::Duration::from_secs(4));
+    group.bench_function("map_reduce", |b| {
+        let mut bench = par_iter_reduce::Benchmark::new();
+        b.iter(move || bench.map_reduce());
+    });
+    group.bench_function("fold", |b| {
+        let mut bench = par_iter_reduce::Benchmark::new();
+        b.iter(move || bench.fold());
+    });
+    group.bench_function("parallel", |b| {
+        let mut bench = par_iter_reduce::Benchmark::new();
+        b.iter(move || bench.parallel());
+    });
+    group.bench_function("mutex", |b| {
+        let mut bench = par_iter_reduce::Benchmark::new();
+        b.iter(move || bench.mutex());
+    });
+    group.finish();
+}
@@ -0,0 +1,62 @@
+use std::sync::Mutex;
+
+use bevy_ecs::prelude::*;
+use bevy_tasks::{ComputeTaskPool, TaskPool};
+use bevy_utils::Parallel;
+use glam::*;
+
+#[derive(Component, Copy, Clone)]
+struct Position(Vec3);
+
+#[derive(Component, Copy, Clone)]
+struct Velocity(Vec3);
+
+pub struct Benchmark<'w>(World, QueryState<(&'w Position, &'w Velocity)>);
+
+impl<'w> Benchmark<'w> {
+    pub fn new() -> Self {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+
+        let mut world = World::new();
+
+        world.spawn_batch(std::iter::repeat((Position(Vec3::X), Velocity(Vec3::X))).take(100_000));
+
+        let query = world.query::<(&Position, &Velocity)>();
+        Self(world, query)
+    }
+
+    #[inline(never)]
+    pub fn map_reduce(&mut self) -> Vec3 {
+        self.1
+            .par_iter(&self.0)
+            .map_reduce(|(p, v)| p.0 * v.0, |a, b| a + b)
+            .unwrap_or_default()
+    }
+
+    #[inline(never)]
+    pub fn fold(&mut self) -> Vec3 {
+        self.1.par_iter(&self.0).fold(
+            || Vec3::ZERO,
+            |total, (p, v)| total + p.0 * v.0,
+            |a, b| a + b,
+        )
+    }
+
+    #[inline(never)]
+    pub fn parallel(&mut self) -> Vec3 {
+        let mut totals = Parallel::<Vec3>::default();
+        self.1
+            .par_iter(&self.0)
+            .for_each_local(&totals, |total, (p, v)| *total += p.0 * v.0);
+        totals.iter_mut().map(|total| *total).sum()
+    }
+
+    #[inline(never)]
+    pub fn mutex(&mut self) -> Vec3 {
+        let total = Mutex::new(Vec3::ZERO);
+        self.1.par_iter(&self.0).for_each(|(p, v)| {
+            *total.lock().unwrap() += p.0 * v.0;
+        });
+        total.into_inner().unwrap()
+    }
+}
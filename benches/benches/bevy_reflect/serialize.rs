@@ -0,0 +1,177 @@
+use std::time::Duration;
+
+use bevy_reflect::{
+    serde::{ReflectBinaryReader, ReflectBinaryWriter, ReflectDeserializer, ReflectSerializer},
+    Reflect, TypeRegistry,
+};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use serde::de::DeserializeSeed;
+
+criterion_group!(
+    benches,
+    serialized_size,
+    serialize_ron,
+    serialize_binary,
+    deserialize_ron,
+    deserialize_binary,
+);
+criterion_main!(benches);
+
+const WARM_UP_TIME: Duration = Duration::from_millis(500);
+const MEASUREMENT_TIME: Duration = Duration::from_secs(4);
+const SIZES: [usize; 3] = [16, 128, 1024];
+
+#[derive(Reflect)]
+struct Unit {
+    name: String,
+    translation: (f32, f32, f32),
+    rotation: [f32; 4],
+    health: Option<u32>,
+    tags: Vec<String>,
+    state: UnitState,
+}
+
+#[derive(Reflect)]
+enum UnitState {
+    Idle,
+    Moving { speed: f32 },
+}
+
+fn units(count: usize) -> Vec<Unit> {
+    (0..count)
+        .map(|i| Unit {
+            name: format!("unit_{i}"),
+            translation: (i as f32, 0.0, -(i as f32)),
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            health: (i % 2 == 0).then_some(100),
+            tags: vec!["enemy".to_string(), "flying".to_string()],
+            state: if i % 3 == 0 {
+                UnitState::Idle
+            } else {
+                UnitState::Moving { speed: 2.5 }
+            },
+        })
+        .collect()
+}
+
+fn registry() -> TypeRegistry {
+    let mut registry = TypeRegistry::default();
+    registry.register::<Unit>();
+    registry
+}
+
+fn to_ron(units: &[Unit], registry: &TypeRegistry) -> Vec<String> {
+    units
+        .iter()
+        .map(|unit| ron::to_string(&ReflectSerializer::new(unit, registry)).unwrap())
+        .collect()
+}
+
+fn to_binary(units: &[Unit], registry: &TypeRegistry) -> Vec<u8> {
+    let mut writer = ReflectBinaryWriter::new(registry);
+    for unit in units {
+        writer.write(unit).unwrap();
+    }
+    writer.finish()
+}
+
+/// Checks that the binary format is smaller than RON for every benchmarked size.
+///
+/// This runs along with the benchmarks, including with `cargo test --benches`.
+fn serialized_size(_: &mut Criterion) {
+    let registry = registry();
+    for size in SIZES {
+        let units = units(size);
+        let ron: usize = to_ron(&units, &registry).iter().map(String::len).sum();
+        let binary = to_binary(&units, &registry).len();
+        assert!(
+            binary < ron,
+            "{size} values: binary ({binary} bytes) should be smaller than RON ({ron} bytes)"
+        );
+    }
+}
+
+fn serialize_ron(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("serialize_ron");
+    group.warm_up_time(WARM_UP_TIME);
+    group.measurement_time(MEASUREMENT_TIME);
+    let registry = registry();
+
+    for size in SIZES {
+        let units = units(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &units,
+            |bencher, units| {
+                bencher.iter(|| to_ron(black_box(units), &registry));
+            },
+        );
+    }
+}
+
+fn serialize_binary(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("serialize_binary");
+    group.warm_up_time(WARM_UP_TIME);
+    group.measurement_time(MEASUREMENT_TIME);
+    let registry = registry();
+
+    for size in SIZES {
+        let units = units(size);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &units,
+            |bencher, units| {
+                bencher.iter(|| to_binary(black_box(units), &registry));
+            },
+        );
+    }
+}
+
+fn deserialize_ron(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("deserialize_ron");
+    group.warm_up_time(WARM_UP_TIME);
+    group.measurement_time(MEASUREMENT_TIME);
+    let registry = registry();
+
+    for size in SIZES {
+        let ron = to_ron(&units(size), &registry);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(size), &ron, |bencher, ron| {
+            bencher.iter(|| {
+                for value in black_box(ron) {
+                    let mut deserializer = ron::de::Deserializer::from_str(value).unwrap();
+                    ReflectDeserializer::new(&registry)
+                        .deserialize(&mut deserializer)
+                        .unwrap();
+                }
+            });
+        });
+    }
+}
+
+fn deserialize_binary(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("deserialize_binary");
+    group.warm_up_time(WARM_UP_TIME);
+    group.measurement_time(MEASUREMENT_TIME);
+    let registry = registry();
+
+    for size in SIZES {
+        let binary = to_binary(&units(size), &registry);
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &binary,
+            |bencher, binary| {
+                bencher.iter(|| {
+                    let mut reader =
+                        ReflectBinaryReader::new(black_box(binary), &registry).unwrap();
+                    for _ in 0..size {
+                        reader.read().unwrap();
+                    }
+                });
+            },
+        );
+    }
+}
@@ -96,6 +96,8 @@ impl Default for App {
 
         #[cfg(feature = "bevy_reflect")]
         app.init_resource::<AppTypeRegistry>();
+        #[cfg(feature = "reflect_functions")]
+        app.init_resource::<AppFunctionRegistry>();
         app.add_plugins(MainSchedulePlugin);
         app.add_systems(
             First,
@@ -599,6 +601,67 @@ impl App {
         self
     }
 
+    /// Registers the given function into the [`AppFunctionRegistry`] resource.
+    ///
+    /// The function is registered using its [name], which for functions converted
+    /// with [`IntoFunction`] defaults to the full path of the function.
+    /// To register a function under a different name, use [`register_function_with_name`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a function has already been registered with the same name,
+    /// or if the function is missing a name.
+    ///
+    /// # Example
+    /// ```
+    /// use bevy_app::App;
+    /// use bevy_ecs::reflect::AppFunctionRegistry;
+    ///
+    /// fn add(a: i32, b: i32) -> i32 {
+    ///     a + b
+    /// }
+    ///
+    /// let mut app = App::new();
+    /// app.register_function(add);
+    ///
+    /// let registry = app.world().resource::<AppFunctionRegistry>().read();
+    /// assert!(registry.contains(std::any::type_name_of_val(&add)));
+    /// ```
+    ///
+    /// See [`bevy_reflect::func::FunctionRegistry::register`].
+    ///
+    /// [name]: bevy_reflect::func::FunctionInfo::name
+    /// [`IntoFunction`]: bevy_reflect::func::IntoFunction
+    /// [`register_function_with_name`]: Self::register_function_with_name
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_function<F, Marker>(&mut self, function: F) -> &mut Self
+    where
+        F: bevy_reflect::func::IntoFunction<Marker> + 'static,
+    {
+        self.main_mut().register_function(function);
+        self
+    }
+
+    /// Registers the given function into the [`AppFunctionRegistry`] resource using the given name.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a function has already been registered with the same name.
+    ///
+    /// See [`bevy_reflect::func::FunctionRegistry::register_with_name`].
+    #[cfg(feature = "reflect_functions")]
+    pub fn register_function_with_name<F, Marker>(
+        &mut self,
+        name: impl Into<std::borrow::Cow<'static, str>>,
+        function: F,
+    ) -> &mut Self
+    where
+        F: bevy_reflect::func::IntoFunction<Marker> + 'static,
+    {
+        self.main_mut().register_function_with_name(name, function);
+        self
+    }
+
     /// Returns a reference to the [`World`].
     pub fn world(&self) -> &World {
         self.main().world()
@@ -663,6 +726,34 @@ impl App {
         self.sub_apps.sub_apps.remove(&label.intern())
     }
 
+    /// Removes the [`SubApp`] with the given label and runs it on its own thread, updating it every
+    /// `tick_rate` independently of this app's updates.
+    ///
+    /// The sub-app is no longer extracted into, so worlds should communicate through a
+    /// [`message_channel`](crate::message_channel) instead. The thread's handle is stored in the
+    /// [`SubAppThreads`](crate::SubAppThreads) resource, which can stop it and give the sub-app back.
+    /// A `tick_rate` of [`Duration::ZERO`](std::time::Duration::ZERO) updates the sub-app as fast as possible.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`SubApp`] doesn't exist, or if it holds non-send resources.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run_sub_app_on_thread(
+        &mut self,
+        label: impl AppLabel,
+        tick_rate: std::time::Duration,
+    ) -> &mut Self {
+        let label = label.intern();
+        let sub_app = self.remove_sub_app(label).unwrap_or_else(|| {
+            panic!("No sub-app with label '{:?}' exists.", label);
+        });
+        let thread = crate::SubAppThread::spawn(format!("{label:?}"), sub_app, tick_rate);
+        self.world_mut()
+            .get_resource_or_insert_with(crate::SubAppThreads::default)
+            .insert(label, thread);
+        self
+    }
+
     /// Extract data from the main world into the [`SubApp`] with the given label and perform an update if it exists.
     pub fn update_sub_app_by_label(&mut self, label: impl AppLabel) {
         self.sub_apps.update_subapp_by_label(label);
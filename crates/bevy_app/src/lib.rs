@@ -15,7 +15,10 @@ mod plugin_group;
 mod schedule_runner;
 mod sub_app;
 #[cfg(not(target_arch = "wasm32"))]
+mod sub_app_thread;
+#[cfg(not(target_arch = "wasm32"))]
 mod terminal_ctrl_c_handler;
+mod test_app;
 
 pub use app::*;
 pub use bevy_derive::DynamicPlugin;
@@ -26,7 +29,10 @@ pub use plugin_group::*;
 pub use schedule_runner::*;
 pub use sub_app::*;
 #[cfg(not(target_arch = "wasm32"))]
+pub use sub_app_thread::*;
+#[cfg(not(target_arch = "wasm32"))]
 pub use terminal_ctrl_c_handler::*;
+pub use test_app::*;
 
 #[allow(missing_docs)]
 pub mod prelude {
@@ -0,0 +1,336 @@
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        mpsc, Arc, Mutex, PoisonError,
+    },
+    thread::JoinHandle,
+};
+
+use bevy_ecs::{
+    event::{Event, EventWriter, Events},
+    system::{Res, Resource},
+};
+use bevy_utils::{tracing::debug, Duration, HashMap, Instant};
+
+use crate::{AppExit, InternedAppLabel, PluginsState, SubApp};
+
+/// How often a [`SubAppThread`] checks whether the plugins of its sub-app are ready.
+const PLUGINS_READY_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// A [`SubApp`] running on its own thread at its own tick rate.
+///
+/// Created by [`App::run_sub_app_on_thread`](crate::App::run_sub_app_on_thread), and stored in [`SubAppThreads`].
+///
+/// The thread finishes the sub-app's plugins if needed, then updates it once per tick until it is
+/// [stopped](Self::stop) or an [`AppExit`] event is sent in its world.
+/// Dropping the handle stops the thread and waits for it to finish.
+///
+/// [Non-send resources](bevy_ecs::system::NonSend) can only be accessed and dropped on the thread that
+/// inserted them, so the sub-app can't hold any when it is moved to its thread. Non-send resources inserted
+/// by the sub-app's own systems must be removed before the thread is stopped.
+pub struct SubAppThread {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<SubApp>>,
+}
+
+impl SubAppThread {
+    /// Spawns a thread updating `sub_app` every `tick_rate`.
+    ///
+    /// A `tick_rate` of [`Duration::ZERO`] updates the sub-app as fast as possible.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sub_app` holds non-send resources.
+    pub fn spawn(name: impl Into<String>, mut sub_app: SubApp, tick_rate: Duration) -> Self {
+        let name = name.into();
+        let non_send = sub_app
+            .world()
+            .storages()
+            .non_send_resources
+            .iter()
+            .find(|(_, data)| data.is_present());
+        if let Some((id, _)) = non_send {
+            panic!(
+                "Sub-app `{name}` can't run on its own thread, as it holds the non-send resource `{}`",
+                sub_app.world().components().get_name(id).unwrap_or_default()
+            );
+        }
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let thread_stop = stop.clone();
+        let handle = std::thread::Builder::new()
+            .name(name)
+            .spawn(move || {
+                while sub_app.plugins_state() == PluginsState::Adding {
+                    if thread_stop.load(Ordering::Acquire) {
+                        return sub_app;
+                    }
+                    // Plugins don't signal when they become ready, so poll them,
+                    // waking up early when the thread is stopped.
+                    std::thread::park_timeout(PLUGINS_READY_POLL_INTERVAL);
+                }
+                if sub_app.plugins_state() == PluginsState::Ready {
+                    sub_app.finish();
+                }
+                if sub_app.plugins_state() == PluginsState::Finished {
+                    sub_app.cleanup();
+                }
+
+                let mut next_tick = Instant::now();
+                while !thread_stop.load(Ordering::Acquire) {
+                    sub_app.update();
+                    if sub_app
+                        .world()
+                        .get_resource::<Events<AppExit>>()
+                        .is_some_and(|events| !events.is_empty())
+                    {
+                        debug!("sub-app thread exiting after `AppExit`");
+                        break;
+                    }
+
+                    next_tick += tick_rate;
+                    let now = Instant::now();
+                    if next_tick > now {
+                        std::thread::sleep(next_tick - now);
+                    } else {
+                        // Don't try to catch up after falling behind.
+                        next_tick = now;
+                    }
+                }
+                sub_app
+            })
+            .expect("failed to spawn sub-app thread");
+        Self {
+            stop,
+            handle: Some(handle),
+        }
+    }
+
+    /// Returns `true` if the thread is still updating the sub-app.
+    pub fn is_running(&self) -> bool {
+        self.handle
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished())
+    }
+
+    /// Stops the thread after its current update, and returns the sub-app.
+    ///
+    /// Returns `None` if the thread panicked.
+    pub fn stop(mut self) -> Option<SubApp> {
+        let handle = self.handle.take()?;
+        self.stop.store(true, Ordering::Release);
+        handle.thread().unpark();
+        handle.join().ok()
+    }
+}
+
+impl Drop for SubAppThread {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Release);
+        if let Some(handle) = self.handle.take() {
+            handle.thread().unpark();
+            // Wait for the thread, so the sub-app isn't updated after the main app is gone.
+            let _ = handle.join();
+        }
+    }
+}
+
+/// The [`SubAppThread`]s of an app, by the label of their sub-app.
+///
+/// This resource is added to the main world by [`App::run_sub_app_on_thread`](crate::App::run_sub_app_on_thread).
+#[derive(Resource, Default)]
+pub struct SubAppThreads {
+    threads: HashMap<InternedAppLabel, SubAppThread>,
+}
+
+impl SubAppThreads {
+    /// Adds the thread running the sub-app with the given label, returning the previous one if any.
+    pub fn insert(
+        &mut self,
+        label: InternedAppLabel,
+        thread: SubAppThread,
+    ) -> Option<SubAppThread> {
+        self.threads.insert(label, thread)
+    }
+
+    /// Returns the thread running the sub-app with the given label, if any.
+    pub fn get(&self, label: InternedAppLabel) -> Option<&SubAppThread> {
+        self.threads.get(&label)
+    }
+
+    /// Returns `true` if the sub-app with the given label is running on its thread.
+    pub fn is_running(&self, label: InternedAppLabel) -> bool {
+        self.get(label).is_some_and(SubAppThread::is_running)
+    }
+
+    /// Stops the thread running the sub-app with the given label, and returns the sub-app.
+    ///
+    /// Returns `None` if there is no such thread or it panicked.
+    pub fn stop(&mut self, label: InternedAppLabel) -> Option<SubApp> {
+        self.threads.remove(&label)?.stop()
+    }
+}
+
+/// Creates a typed channel to send messages between worlds, such as between the main app and a
+/// [`SubApp`] running on its own thread.
+///
+/// Both ends are resources: insert the [`MessageSender`] in the sending world and the
+/// [`MessageReceiver`] in the receiving one. Messages of an [`Event`] type can be turned into
+/// events of the receiving world with the [`receive_messages`] system.
+pub fn message_channel<T: Send + 'static>() -> (MessageSender<T>, MessageReceiver<T>) {
+    let (sender, receiver) = mpsc::channel();
+    (MessageSender(sender), MessageReceiver(Mutex::new(receiver)))
+}
+
+/// The sending end of a [`message_channel`].
+#[derive(Resource)]
+pub struct MessageSender<T: Send + 'static>(mpsc::Sender<T>);
+
+impl<T: Send + 'static> MessageSender<T> {
+    /// Sends a message, returning `false` if the [`MessageReceiver`] was dropped.
+    pub fn send(&self, message: T) -> bool {
+        self.0.send(message).is_ok()
+    }
+}
+
+impl<T: Send + 'static> Clone for MessageSender<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+/// The receiving end of a [`message_channel`].
+#[derive(Resource)]
+pub struct MessageReceiver<T: Send + 'static>(Mutex<mpsc::Receiver<T>>);
+
+impl<T: Send + 'static> MessageReceiver<T> {
+    /// Returns the next message, if any was sent.
+    pub fn try_recv(&self) -> Option<T> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .try_recv()
+            .ok()
+    }
+
+    /// Returns every message sent so far, in order.
+    pub fn drain(&self) -> Vec<T> {
+        self.0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .try_iter()
+            .collect()
+    }
+}
+
+/// Sends the messages received by a [`MessageReceiver<T>`] as events of type `T`.
+pub fn receive_messages<T: Event>(receiver: Res<MessageReceiver<T>>, mut events: EventWriter<T>) {
+    events.send_batch(receiver.drain());
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
+
+    use crate::{
+        self as bevy_app, message_channel, App, AppLabel, Plugin, PluginsState, SubApp,
+        SubAppThread, SubAppThreads,
+    };
+    use bevy_utils::{Duration, Instant};
+
+    #[derive(AppLabel, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    struct Server;
+
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct ServerUpdate;
+
+    #[derive(Event, Debug, Clone, PartialEq)]
+    struct Input(u32);
+
+    #[derive(Resource, Default)]
+    struct Total(u32);
+
+    fn simulate(
+        mut inputs: EventReader<Input>,
+        mut total: ResMut<Total>,
+        snapshots: Res<crate::MessageSender<u32>>,
+    ) {
+        for Input(value) in inputs.read() {
+            total.0 += value;
+            snapshots.send(total.0);
+        }
+    }
+
+    #[test]
+    fn sub_app_runs_on_its_own_thread() {
+        let (input_sender, input_receiver) = message_channel::<Input>();
+        let (snapshot_sender, snapshot_receiver) = message_channel::<u32>();
+
+        let mut server = SubApp::new();
+        server.update_schedule = Some(ServerUpdate.intern());
+        server
+            .add_event::<Input>()
+            .init_resource::<Total>()
+            .insert_resource(input_receiver)
+            .insert_resource(snapshot_sender)
+            .add_systems(
+                ServerUpdate,
+                (
+                    crate::receive_messages::<Input>,
+                    simulate,
+                    bevy_ecs::event::event_update_system,
+                )
+                    .chain(),
+            );
+
+        let mut app = App::new();
+        app.insert_sub_app(Server, server);
+        app.run_sub_app_on_thread(Server, Duration::from_millis(1));
+        assert!(app.get_sub_app(Server).is_none());
+
+        for value in 1..=3 {
+            input_sender.send(Input(value));
+        }
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let mut snapshots = Vec::new();
+        while snapshots.len() < 3 && Instant::now() < deadline {
+            snapshots.extend(snapshot_receiver.drain());
+            std::thread::yield_now();
+        }
+        assert_eq!(snapshots, [1, 3, 6]);
+
+        let mut threads = app.world_mut().resource_mut::<SubAppThreads>();
+        assert!(threads.is_running(Server.intern()));
+        let server = threads.stop(Server.intern()).unwrap();
+        assert_eq!(server.world().resource::<Total>().0, 6);
+        assert!(!threads.is_running(Server.intern()));
+    }
+
+    struct NeverReadyPlugin;
+
+    impl Plugin for NeverReadyPlugin {
+        fn build(&self, _app: &mut App) {}
+
+        fn ready(&self, _app: &App) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn stopping_while_plugins_are_not_ready() {
+        let mut sub_app = SubApp::new();
+        sub_app.add_plugins(NeverReadyPlugin);
+        let thread = SubAppThread::spawn("not ready", sub_app, Duration::ZERO);
+
+        let mut sub_app = thread.stop().unwrap();
+        assert_eq!(sub_app.plugins_state(), PluginsState::Adding);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-send resource")]
+    fn non_send_resources_are_rejected() {
+        let mut sub_app = SubApp::new();
+        sub_app.world_mut().insert_non_send_resource(Total(0));
+        SubAppThread::spawn("non-send", sub_app, Duration::ZERO);
+    }
+}
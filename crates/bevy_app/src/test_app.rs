@@ -0,0 +1,265 @@
+use std::{
+    any::{Any, TypeId},
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+};
+
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::{Event, EventCursor, Events},
+    query::{QueryFilter, ROQueryItem, ReadOnlyQueryData},
+    system::Resource,
+};
+use bevy_utils::HashMap;
+
+use crate::App;
+
+/// A headless [`App`] for integration tests, with helpers to step the app,
+/// send events and make assertions about the world.
+///
+/// [`TestApp`] dereferences to [`App`], so plugins and systems are added the same way.
+/// Other crates extend [`App`] with test helpers that are also available here,
+/// such as manual time control in `bevy_time` and input simulation in `bevy_input`.
+///
+/// ```
+/// # use bevy_app::{TestApp, Update};
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Event, Clone, Debug, PartialEq)]
+/// struct Damage(u32);
+///
+/// #[derive(Component, Debug, PartialEq)]
+/// struct Health(u32);
+///
+/// fn apply_damage(mut events: EventReader<Damage>, mut query: Query<&mut Health>) {
+///     for Damage(amount) in events.read() {
+///         for mut health in &mut query {
+///             health.0 = health.0.saturating_sub(*amount);
+///         }
+///     }
+/// }
+///
+/// let mut app = TestApp::new();
+/// app.add_event::<Damage>().add_systems(Update, apply_damage);
+/// let player = app.world_mut().spawn(Health(10)).id();
+///
+/// app.send_event(Damage(3)).update();
+/// app.assert_component(player, &Health(7));
+/// app.assert_event(|damage: &Damage| damage.0 == 3);
+/// ```
+pub struct TestApp {
+    app: App,
+    event_cursors: HashMap<TypeId, Box<dyn Any + Send + Sync>>,
+}
+
+impl TestApp {
+    /// Creates a [`TestApp`] wrapping [`App::new`].
+    pub fn new() -> Self {
+        Self::from_app(App::new())
+    }
+
+    /// Creates a [`TestApp`] wrapping an existing [`App`].
+    pub fn from_app(app: App) -> Self {
+        Self {
+            app,
+            event_cursors: HashMap::default(),
+        }
+    }
+
+    /// Returns the wrapped [`App`].
+    pub fn into_app(self) -> App {
+        self.app
+    }
+
+    /// Runs the default schedules of all sub-apps once.
+    ///
+    /// See [`App::update`].
+    pub fn update(&mut self) -> &mut Self {
+        self.app.update();
+        self
+    }
+
+    /// Runs [`update`](Self::update) `count` times.
+    pub fn update_n(&mut self, count: usize) -> &mut Self {
+        for _ in 0..count {
+            self.app.update();
+        }
+        self
+    }
+
+    /// Sends an event, which systems can read during the next [`update`](Self::update).
+    pub fn send_event<E: Event>(&mut self, event: E) -> &mut Self {
+        self.app.world_mut().send_event(event);
+        self
+    }
+
+    /// Returns the events of type `E` sent since the last call to this method,
+    /// or since the event was registered for the first call.
+    ///
+    /// Events are only kept for two updates, so this should be called at least every other update.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event type hasn't been added to the app.
+    pub fn events<E: Event + Clone>(&mut self) -> Vec<E> {
+        let events = self
+            .app
+            .world()
+            .get_resource::<Events<E>>()
+            .unwrap_or_else(|| {
+                panic!(
+                    "event `{}` has not been added to the app",
+                    std::any::type_name::<E>()
+                )
+            });
+        let cursor = self
+            .event_cursors
+            .entry(TypeId::of::<E>())
+            .or_insert_with(|| Box::new(EventCursor::<E>::default()))
+            .downcast_mut::<EventCursor<E>>()
+            .expect("event cursors are stored by the type id of their event");
+        cursor.read(events).cloned().collect()
+    }
+
+    /// Asserts that an event of type `E` matching `predicate` was sent since the last read
+    /// of these [`events`](Self::events), and returns it.
+    ///
+    /// Other events sent in the meantime are consumed as well.
+    #[track_caller]
+    pub fn assert_event<E: Event + Clone + Debug>(&mut self, predicate: impl Fn(&E) -> bool) -> E {
+        let events = self.events::<E>();
+        match events.iter().find(|event| predicate(event)) {
+            Some(event) => event.clone(),
+            None => panic!(
+                "no matching `{}` event was sent, got {events:?}",
+                std::any::type_name::<E>()
+            ),
+        }
+    }
+
+    /// Asserts that no event of type `E` was sent since the last read of these [`events`](Self::events).
+    #[track_caller]
+    pub fn assert_no_events<E: Event + Clone + Debug>(&mut self) {
+        let events = self.events::<E>();
+        assert!(
+            events.is_empty(),
+            "expected no `{}` events, got {events:?}",
+            std::any::type_name::<E>()
+        );
+    }
+
+    /// Returns the results of a read-only query over the main world.
+    pub fn query<D: ReadOnlyQueryData>(&mut self) -> Vec<ROQueryItem<'_, D>> {
+        self.query_filtered::<D, ()>()
+    }
+
+    /// Returns the results of a filtered read-only query over the main world.
+    pub fn query_filtered<D: ReadOnlyQueryData, F: QueryFilter>(
+        &mut self,
+    ) -> Vec<ROQueryItem<'_, D>> {
+        let mut state = self.app.world_mut().query_filtered::<D, F>();
+        state.iter(self.app.world()).collect()
+    }
+
+    /// Asserts that exactly `expected` entities match the query filter `F`.
+    #[track_caller]
+    pub fn assert_query_count<F: QueryFilter>(&mut self, expected: usize) {
+        let count = self.query_filtered::<Entity, F>().len();
+        assert_eq!(
+            count,
+            expected,
+            "unexpected number of entities matching `{}`",
+            std::any::type_name::<F>()
+        );
+    }
+
+    /// Asserts that `entity` has a component of type `C` equal to `expected`.
+    #[track_caller]
+    pub fn assert_component<C: Component + PartialEq + Debug>(&self, entity: Entity, expected: &C) {
+        let Some(component) = self.app.world().get::<C>(entity) else {
+            panic!(
+                "{entity:?} has no `{}` component",
+                std::any::type_name::<C>()
+            );
+        };
+        assert_eq!(component, expected);
+    }
+
+    /// Asserts that the resource of type `R` exists and is equal to `expected`.
+    #[track_caller]
+    pub fn assert_resource<R: Resource + PartialEq + Debug>(&self, expected: &R) {
+        let Some(resource) = self.app.world().get_resource::<R>() else {
+            panic!("resource `{}` does not exist", std::any::type_name::<R>());
+        };
+        assert_eq!(resource, expected);
+    }
+}
+
+impl Default for TestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for TestApp {
+    type Target = App;
+
+    fn deref(&self) -> &Self::Target {
+        &self.app
+    }
+}
+
+impl DerefMut for TestApp {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.app
+    }
+}
+
+impl From<App> for TestApp {
+    fn from(app: App) -> Self {
+        Self::from_app(app)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+
+    use crate::{TestApp, Update};
+
+    #[derive(Event, Clone, Debug, PartialEq)]
+    struct Spawned(Entity);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct Marker;
+
+    fn spawn_marker(mut commands: Commands, mut events: EventWriter<Spawned>) {
+        let entity = commands.spawn(Marker).id();
+        events.send(Spawned(entity));
+    }
+
+    #[test]
+    fn reads_each_event_once() {
+        let mut app = TestApp::new();
+        app.add_event::<Spawned>().add_systems(Update, spawn_marker);
+
+        app.update_n(2);
+        assert_eq!(app.events::<Spawned>().len(), 2);
+        app.assert_no_events::<Spawned>();
+
+        app.update();
+        let Spawned(entity) = app.assert_event(|_: &Spawned| true);
+        app.assert_component(entity, &Marker);
+        app.assert_query_count::<With<Marker>>(3);
+        assert_eq!(app.query::<&Marker>().len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "no matching")]
+    fn assert_event_panics_without_match() {
+        let mut app = TestApp::new();
+        app.add_event::<Spawned>();
+        app.send_event(Spawned(Entity::PLACEHOLDER));
+        app.assert_event(|event: &Spawned| event.0 != Entity::PLACEHOLDER);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::io::{AssetReader, AssetReaderError, PathStream, Reader};
+use async_lock::Semaphore;
 use bevy_utils::HashMap;
-use crossbeam_channel::{Receiver, Sender};
 use parking_lot::RwLock;
 use std::{path::Path, sync::Arc};
 
@@ -10,7 +10,7 @@ use std::{path::Path, sync::Arc};
 /// This is built primarily for unit tests.
 pub struct GatedReader<R: AssetReader> {
     reader: R,
-    gates: Arc<RwLock<HashMap<Box<Path>, (Sender<()>, Receiver<()>)>>>,
+    gates: Arc<RwLock<HashMap<Box<Path>, Arc<Semaphore>>>>,
 }
 
 impl<R: AssetReader + Clone> Clone for GatedReader<R> {
@@ -24,7 +24,7 @@ impl<R: AssetReader + Clone> Clone for GatedReader<R> {
 
 /// Opens path "gates" for a [`GatedReader`].
 pub struct GateOpener {
-    gates: Arc<RwLock<HashMap<Box<Path>, (Sender<()>, Receiver<()>)>>>,
+    gates: Arc<RwLock<HashMap<Box<Path>, Arc<Semaphore>>>>,
 }
 
 impl GateOpener {
@@ -32,10 +32,10 @@ impl GateOpener {
     /// If multiple operations are expected, call `open` the expected number of calls.
     pub fn open<P: AsRef<Path>>(&self, path: P) {
         let mut gates = self.gates.write();
-        let gates = gates
+        let gate = gates
             .entry_ref(path.as_ref())
-            .or_insert_with(crossbeam_channel::unbounded);
-        gates.0.send(()).unwrap();
+            .or_insert_with(|| Arc::new(Semaphore::new(0)));
+        gate.add_permits(1);
     }
 }
 
@@ -56,14 +56,14 @@ impl<R: AssetReader> GatedReader<R> {
 
 impl<R: AssetReader> AssetReader for GatedReader<R> {
     async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
-        let receiver = {
+        let gate = {
             let mut gates = self.gates.write();
-            let gates = gates
+            gates
                 .entry_ref(path.as_ref())
-                .or_insert_with(crossbeam_channel::unbounded);
-            gates.1.clone()
+                .or_insert_with(|| Arc::new(Semaphore::new(0)))
+                .clone()
         };
-        receiver.recv().unwrap();
+        gate.acquire().await.forget();
         let result = self.reader.read(path).await?;
         Ok(result)
     }
@@ -1,6 +1,7 @@
 use crate::{
     io::{processor_gated::ProcessorGatedReader, AssetSourceEvent, AssetWatcher},
     processor::AssetProcessorData,
+    server::LoadLimiter,
 };
 use bevy_ecs::system::Resource;
 use bevy_utils::tracing::{error, warn};
@@ -134,6 +135,7 @@ pub struct AssetSourceBuilder {
     >,
     pub watch_warning: Option<&'static str>,
     pub processed_watch_warning: Option<&'static str>,
+    pub max_concurrent_loads: Option<usize>,
 }
 
 impl AssetSourceBuilder {
@@ -158,6 +160,7 @@ impl AssetSourceBuilder {
             watcher: None,
             processed_event_receiver: None,
             processed_watcher: None,
+            load_limiter: LoadLimiter::new(self.max_concurrent_loads),
         };
 
         if watch {
@@ -264,6 +267,13 @@ impl AssetSourceBuilder {
         self
     }
 
+    /// Limits the number of assets that can be loaded from this source at the same time. Loads beyond this limit
+    /// wait in a queue and start in order of their [`LoadPriority`](crate::LoadPriority).
+    pub fn with_max_concurrent_loads(mut self, max_concurrent_loads: usize) -> Self {
+        self.max_concurrent_loads = Some(max_concurrent_loads.max(1));
+        self
+    }
+
     /// Returns a builder containing the "platform default source" for the given `path` and `processed_path`.
     /// For most platforms, this will use [`FileAssetReader`](crate::io::file::FileAssetReader) / [`FileAssetWriter`](crate::io::file::FileAssetWriter),
     /// but some platforms (such as Android) have their own default readers / writers / watchers.
@@ -366,6 +376,7 @@ pub struct AssetSource {
     processed_watcher: Option<Box<dyn AssetWatcher>>,
     event_receiver: Option<crossbeam_channel::Receiver<AssetSourceEvent>>,
     processed_event_receiver: Option<crossbeam_channel::Receiver<AssetSourceEvent>>,
+    load_limiter: LoadLimiter,
 }
 
 impl AssetSource {
@@ -428,6 +439,31 @@ impl AssetSource {
         self.processed_event_receiver.as_ref()
     }
 
+    /// Returns the maximum number of assets that can be loaded from this source at the same time, if there is one.
+    #[inline]
+    pub fn max_concurrent_loads(&self) -> Option<usize> {
+        self.load_limiter.max_concurrent_loads()
+    }
+
+    /// Returns the number of loads from this source that are waiting for other loads to finish,
+    /// because of [`AssetSource::max_concurrent_loads`].
+    #[inline]
+    pub fn queued_loads(&self) -> usize {
+        self.load_limiter.queued()
+    }
+
+    /// Returns the number of loads from this source that are currently in progress. This is only
+    /// tracked if the source has a [`AssetSource::max_concurrent_loads`] limit.
+    #[inline]
+    pub fn active_loads(&self) -> usize {
+        self.load_limiter.running()
+    }
+
+    #[inline]
+    pub(crate) fn load_limiter(&self) -> &LoadLimiter {
+        &self.load_limiter
+    }
+
     /// Returns true if the assets in this source should be processed.
     #[inline]
     pub fn should_process(&self) -> bool {
@@ -447,7 +447,7 @@ mod tests {
         },
         loader::{AssetLoader, LoadContext},
         Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets, DependencyLoadState, LoadState,
+        AssetPlugin, AssetServer, Assets, DependencyLoadState, LoadPriority, LoadState,
         RecursiveDependencyLoadState,
     };
     use bevy_app::{App, Update};
@@ -1471,6 +1471,232 @@ mod tests {
         });
     }
 
+    fn limited_test_app(dir: Dir, max_concurrent_loads: usize) -> (App, GateOpener) {
+        let mut app = App::new();
+        let (gated_memory_reader, gate_opener) = GatedReader::new(MemoryAssetReader { root: dir });
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(gated_memory_reader.clone()))
+                .with_max_concurrent_loads(max_concurrent_loads),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            LogPlugin::default(),
+            AssetPlugin::default(),
+        ))
+        .init_asset::<CoolText>()
+        .init_asset::<SubText>()
+        .init_resource::<StoredEvents>()
+        .register_asset_loader(CoolTextLoader)
+        .add_systems(Update, store_asset_events);
+        (app, gate_opener)
+    }
+
+    fn insert_cool_texts(dir: &Dir, texts: &[&str]) {
+        for text in texts {
+            dir.insert_asset_text(
+                Path::new(&format!("{text}.cool.ron")),
+                &format!(
+                    "(text: \"{text}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+                ),
+            );
+        }
+    }
+
+    fn wait_for_queued_loads(app: &mut App, count: usize) {
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        run_app_until(app, |_| {
+            let source = asset_server.get_source(AssetSourceId::Default).unwrap();
+            (source.queued_loads() == count).then_some(())
+        });
+    }
+
+    #[test]
+    fn load_priority() {
+        let dir = Dir::default();
+        insert_cool_texts(&dir, &["a", "b", "c", "d"]);
+        let (mut app, gate_opener) = limited_test_app(dir, 1);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+
+        // "a" starts right away and blocks the only load slot until its gate is opened
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        app.update();
+        let b: Handle<CoolText> =
+            asset_server.load_with_priority("b.cool.ron", LoadPriority::Background);
+        let c: Handle<CoolText> = asset_server.load("c.cool.ron");
+        let d: Handle<CoolText> = asset_server.load_with_priority("d.cool.ron", LoadPriority::High);
+        wait_for_queued_loads(&mut app, 3);
+
+        for path in ["b.cool.ron", "c.cool.ron", "d.cool.ron", "a.cool.ron"] {
+            gate_opener.open(path);
+        }
+        run_app_until(&mut app, |world| {
+            let assets = world.resource::<Assets<CoolText>>();
+            [&a, &b, &c, &d]
+                .iter()
+                .all(|handle| assets.contains(*handle))
+                .then_some(())
+        });
+        // Let `store_asset_events` observe the events sent during the last update
+        app.update();
+
+        let added = app
+            .world()
+            .resource::<StoredEvents>()
+            .0
+            .iter()
+            .filter_map(|event| match event {
+                AssetEvent::Added { id } => Some(*id),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(added, vec![a.id(), d.id(), c.id(), b.id()]);
+        let source = asset_server.get_source(AssetSourceId::Default).unwrap();
+        assert_eq!(source.queued_loads(), 0);
+        assert_eq!(source.active_loads(), 0);
+    }
+
+    #[test]
+    fn dropping_handles_cancels_queued_load() {
+        let dir = Dir::default();
+        insert_cool_texts(&dir, &["a", "b"]);
+        let (mut app, gate_opener) = limited_test_app(dir, 1);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        app.update();
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        let b_id = b.id();
+        wait_for_queued_loads(&mut app, 1);
+
+        // Dropping the only handle to "b" removes it from the queue
+        drop(b);
+        wait_for_queued_loads(&mut app, 0);
+        assert!(asset_server.get_load_state(b_id).is_none());
+
+        gate_opener.open("a.cool.ron");
+        run_app_until(&mut app, |world| {
+            world
+                .resource::<Assets<CoolText>>()
+                .contains(&a)
+                .then_some(())
+        });
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(get::<CoolText>(app.world(), b_id).is_none());
+        let source = asset_server.get_source(AssetSourceId::Default).unwrap();
+        assert_eq!(source.active_loads(), 0);
+
+        // Loading "b" again starts a fresh load
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        gate_opener.open("b.cool.ron");
+        run_app_until(&mut app, |world| {
+            let text = get::<CoolText>(world, b.id())?;
+            assert_eq!(text.text, "b");
+            Some(())
+        });
+    }
+
+    /// Publishes a partial version of the asset (and one of its labeled assets) and then waits for the test to
+    /// release it before finishing the load.
+    struct ProgressiveTextLoader {
+        release: Arc<async_lock::Semaphore>,
+    }
+
+    impl AssetLoader for ProgressiveTextLoader {
+        type Asset = CoolText;
+
+        type Settings = ();
+
+        type Error = std::io::Error;
+
+        async fn load<'a>(
+            &'a self,
+            reader: &'a mut dyn Reader,
+            _settings: &'a Self::Settings,
+            load_context: &'a mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let text = String::from_utf8_lossy(&bytes).into_owned();
+            load_context.publish_partial(CoolText {
+                text: format!("{text} (partial)"),
+                ..Default::default()
+            });
+            load_context.publish_partial_labeled(
+                "sub",
+                SubText {
+                    text: "sub (partial)".to_string(),
+                },
+            );
+
+            self.release.acquire().await.forget();
+            let sub = load_context.add_labeled_asset(
+                "sub".to_string(),
+                SubText {
+                    text: "sub".to_string(),
+                },
+            );
+            Ok(CoolText {
+                text,
+                sub_texts: vec![sub],
+                ..Default::default()
+            })
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["progressive"]
+        }
+    }
+
+    #[test]
+    fn publish_partial_assets() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.progressive"), "a");
+        let (mut app, gate_opener) = test_app(dir);
+        let release = Arc::new(async_lock::Semaphore::new(0));
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .init_resource::<StoredEvents>()
+            .register_asset_loader(ProgressiveTextLoader {
+                release: release.clone(),
+            })
+            .add_systems(Update, store_asset_events);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+
+        gate_opener.open("a.progressive");
+        let handle: Handle<CoolText> = asset_server.load("a.progressive");
+        let sub_handle: Handle<SubText> = asset_server.load("a.progressive#sub");
+        run_app_until(&mut app, |world| {
+            let text = get::<CoolText>(world, handle.id())?;
+            let sub_text = get::<SubText>(world, sub_handle.id())?;
+            assert_eq!(text.text, "a (partial)");
+            assert_eq!(sub_text.text, "sub (partial)");
+            Some(())
+        });
+        assert_eq!(asset_server.load_state(&handle), LoadState::Loading);
+
+        release.add_permits(1);
+        run_app_until(&mut app, |world| {
+            let text = get::<CoolText>(world, handle.id())?;
+            (text.text == "a").then_some(())
+        });
+        app.update();
+        assert_eq!(asset_server.load_state(&handle), LoadState::Loaded);
+        assert_eq!(
+            get::<SubText>(app.world(), sub_handle.id()).unwrap().text,
+            "sub"
+        );
+
+        let events = &app.world().resource::<StoredEvents>().0;
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0], AssetEvent::Added { id: handle.id() });
+        assert!(events.contains(&AssetEvent::Modified { id: handle.id() }));
+        assert!(events.contains(&AssetEvent::LoadedWithDependencies { id: handle.id() }));
+    }
+
     #[test]
     fn ignore_system_ambiguities_on_assets() {
         let mut app = App::new();
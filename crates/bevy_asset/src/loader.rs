@@ -3,11 +3,11 @@ use crate::{
     loader_builders::NestedLoader,
     meta::{AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings},
     path::AssetPath,
-    Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, UntypedAssetId,
-    UntypedHandle,
+    Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, InternalAssetEvent,
+    UntypedAssetId, UntypedHandle,
 };
 use bevy_ecs::world::World;
-use bevy_utils::{BoxedFuture, ConditionalSendFuture, CowArc, HashMap, HashSet};
+use bevy_utils::{tracing::warn, BoxedFuture, ConditionalSendFuture, CowArc, HashMap, HashSet};
 use downcast_rs::{impl_downcast, Downcast};
 use ron::error::SpannedError;
 use serde::{Deserialize, Serialize};
@@ -315,6 +315,10 @@ pub struct LoadContext<'a> {
     /// Direct dependencies used by this loader.
     pub(crate) loader_dependencies: HashMap<AssetPath<'static>, AssetHash>,
     pub(crate) labeled_assets: HashMap<CowArc<'static, str>, LabeledAsset>,
+    /// The id of the asset that [`LoadContext::publish_partial`] updates, if this context supports it.
+    partial_asset_id: Option<UntypedAssetId>,
+    /// Keeps the labeled assets published with [`LoadContext::publish_partial_labeled`] alive until the load finishes.
+    partial_labeled_handles: HashMap<CowArc<'static, str>, UntypedHandle>,
 }
 
 impl<'a> LoadContext<'a> {
@@ -333,9 +337,85 @@ impl<'a> LoadContext<'a> {
             dependencies: HashSet::default(),
             loader_dependencies: HashMap::default(),
             labeled_assets: HashMap::default(),
+            partial_asset_id: None,
+            partial_labeled_handles: HashMap::default(),
         }
     }
 
+    /// Allows [`LoadContext::publish_partial`] to publish intermediate versions of the asset with the given `id`.
+    pub(crate) fn with_partial_asset_id(mut self, id: Option<UntypedAssetId>) -> Self {
+        self.partial_asset_id = id;
+        self
+    }
+
+    /// Returns `true` if intermediate versions of the asset published with [`LoadContext::publish_partial`] and
+    /// [`LoadContext::publish_partial_labeled`] will be made available to the app.
+    ///
+    /// This is `false` for loads that do not end up in an [`Assets`] collection, such as nested
+    /// [direct loads](crate::DirectNestedLoader) and loads performed by the
+    /// [`AssetProcessor`](crate::processor::AssetProcessor). Loaders can use this to skip building intermediate versions.
+    pub fn can_publish_partial(&self) -> bool {
+        self.partial_asset_id.is_some()
+    }
+
+    /// Publishes an intermediate version of the asset that is currently being loaded, such as a texture that only
+    /// contains its lowest mip levels. The intermediate version is inserted into the [`Assets`] collection while the asset
+    /// is still [`LoadState::Loading`](crate::LoadState::Loading), which sends [`AssetEvent::Added`](crate::AssetEvent::Added)
+    /// the first time and [`AssetEvent::Modified`](crate::AssetEvent::Modified) afterwards. When the load finishes, the
+    /// final asset replaces the last intermediate version.
+    ///
+    /// Intermediate versions do not have their dependencies tracked. If the load fails, the last published version
+    /// stays in the [`Assets`] collection.
+    ///
+    /// This does nothing if [`LoadContext::can_publish_partial`] is `false`, or if `A` is not the type of the asset
+    /// being loaded.
+    pub fn publish_partial<A: Asset>(&mut self, asset: A) {
+        let Some(id) = self.partial_asset_id else {
+            return;
+        };
+        if id.type_id() != TypeId::of::<A>() {
+            warn!(
+                "Cannot publish a partial {} for '{}', which is loading an asset of a different type",
+                std::any::type_name::<A>(),
+                self.asset_path
+            );
+            return;
+        }
+        self.asset_server
+            .send_asset_event(InternalAssetEvent::LoadedPartial {
+                id,
+                asset: Box::new(asset),
+            });
+    }
+
+    /// Publishes an intermediate version of the labeled asset with the given `label`. This behaves like
+    /// [`LoadContext::publish_partial`], but for an asset that will later be added with [`LoadContext::add_labeled_asset`]
+    /// (or one of its variants). If the loader never adds the labeled asset, the intermediate version is removed once
+    /// the load finishes.
+    ///
+    /// This does nothing if [`LoadContext::can_publish_partial`] is `false`.
+    pub fn publish_partial_labeled<A: Asset>(
+        &mut self,
+        label: impl Into<CowArc<'static, str>>,
+        asset: A,
+    ) -> Handle<A> {
+        let label = label.into();
+        let labeled_path = self.asset_path.clone().with_label(label.clone());
+        let handle = self
+            .asset_server
+            .get_or_create_path_handle::<A>(labeled_path, None);
+        if self.can_publish_partial() {
+            self.asset_server
+                .send_asset_event(InternalAssetEvent::LoadedPartial {
+                    id: handle.id().untyped(),
+                    asset: Box::new(asset),
+                });
+            self.partial_labeled_handles
+                .insert(label, handle.clone().untyped());
+        }
+        handle
+    }
+
     /// Begins a new labeled asset load. Use the returned [`LoadContext`] to load
     /// dependencies for the new asset and call [`LoadContext::finish`] to finalize the asset load.
     /// When finished, make sure you call [`LoadContext::add_labeled_asset`] to add the results back to the parent
@@ -529,6 +609,7 @@ impl<'a> LoadContext<'a> {
                 reader,
                 false,
                 self.populate_hashes,
+                None,
             )
             .await
             .map_err(|error| LoadDirectError {
@@ -5,7 +5,7 @@ use crate::{
     io::Reader,
     meta::{meta_transform_settings, AssetMetaDyn, MetaTransform, Settings},
     Asset, AssetLoadError, AssetPath, ErasedAssetLoader, ErasedLoadedAsset, Handle, LoadContext,
-    LoadDirectError, LoadedAsset, LoadedUntypedAsset,
+    LoadDirectError, LoadPriority, LoadedAsset, LoadedUntypedAsset,
 };
 use std::any::TypeId;
 use std::sync::Arc;
@@ -118,9 +118,12 @@ impl<'ctx, 'builder> NestedLoader<'ctx, 'builder> {
     pub fn load<'c, A: Asset>(self, path: impl Into<AssetPath<'c>>) -> Handle<A> {
         let path = path.into().to_owned();
         let handle = if self.load_context.should_load_dependencies {
-            self.load_context
-                .asset_server
-                .load_with_meta_transform(path, self.meta_transform, ())
+            self.load_context.asset_server.load_with_meta_transform(
+                path,
+                self.meta_transform,
+                (),
+                LoadPriority::Normal,
+            )
         } else {
             self.load_context
                 .asset_server
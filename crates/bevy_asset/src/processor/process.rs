@@ -355,6 +355,7 @@ impl<'a> ProcessContext<'a> {
                 &mut reader,
                 false,
                 true,
+                None,
             )
             .await?;
         for (path, full_hash) in &loaded_asset.loader_dependencies {
@@ -4,6 +4,8 @@ use crate::{
     Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
     UntypedAssetId, UntypedHandle,
 };
+
+use super::LoadCancellation;
 use bevy_ecs::world::World;
 use bevy_utils::tracing::warn;
 use bevy_utils::{Entry, HashMap, HashSet, TypeIdMap};
@@ -37,6 +39,8 @@ pub(crate) struct AssetInfo {
     /// The number of handle drops to skip for this asset.
     /// See usage (and comments) in `get_or_create_path_handle` for context.
     handle_drops_to_skip: usize,
+    /// Cancels the in-flight load of this asset when all of its handles are dropped.
+    pub(crate) load_cancellation: Option<LoadCancellation>,
 }
 
 impl AssetInfo {
@@ -55,6 +59,7 @@ impl AssetInfo {
             dependants_waiting_on_load: HashSet::default(),
             dependants_waiting_on_recursive_dep_load: HashSet::default(),
             handle_drops_to_skip: 0,
+            load_cancellation: None,
         }
     }
 }
@@ -665,6 +670,9 @@ impl AssetInfos {
         let type_id = entry.key().type_id();
 
         let info = entry.remove();
+        if let Some(load_cancellation) = &info.load_cancellation {
+            load_cancellation.cancel();
+        }
         let Some(path) = &info.path else {
             return true;
         };
@@ -0,0 +1,291 @@
+use bevy_utils::HashSet;
+use parking_lot::Mutex;
+use std::{
+    cmp::Reverse,
+    collections::BTreeMap,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::{Context, Poll, Waker},
+};
+
+/// The priority of an asset load, used to decide which queued load starts next when an
+/// [`AssetSource`] limits the number of concurrent loads.
+///
+/// Loads with the same priority start in the order they were requested.
+///
+/// [`AssetSource`]: crate::io::AssetSource
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LoadPriority {
+    /// The asset is not needed yet, such as when prefetching assets for an upcoming level.
+    Background,
+    /// The default priority.
+    #[default]
+    Normal,
+    /// The asset is needed as soon as possible, such as when it is currently visible.
+    High,
+}
+
+/// Limits the number of loads that can run at the same time for a single [`AssetSource`].
+/// Loads that cannot start right away wait in a queue ordered by [`LoadPriority`].
+///
+/// [`AssetSource`]: crate::io::AssetSource
+#[derive(Default)]
+pub(crate) struct LoadLimiter {
+    max_concurrent_loads: Option<usize>,
+    state: Arc<Mutex<LoadLimiterState>>,
+}
+
+#[derive(Default)]
+struct LoadLimiterState {
+    running: usize,
+    next_ticket: u64,
+    waiting: BTreeMap<(Reverse<LoadPriority>, u64), Waker>,
+    granted: HashSet<u64>,
+}
+
+impl LoadLimiterState {
+    /// Hands the permit of a finished load to the highest priority waiting load, if there is one.
+    fn release(&mut self) {
+        self.running -= 1;
+        if let Some(((_, ticket), waker)) = self.waiting.pop_first() {
+            self.running += 1;
+            self.granted.insert(ticket);
+            waker.wake();
+        }
+    }
+}
+
+impl LoadLimiter {
+    pub(crate) fn new(max_concurrent_loads: Option<usize>) -> Self {
+        Self {
+            max_concurrent_loads,
+            state: Default::default(),
+        }
+    }
+
+    /// The maximum number of loads that can run at the same time, if there is one.
+    pub(crate) fn max_concurrent_loads(&self) -> Option<usize> {
+        self.max_concurrent_loads
+    }
+
+    /// Returns the number of loads currently waiting for a [`LoadPermit`].
+    pub(crate) fn queued(&self) -> usize {
+        self.state.lock().waiting.len()
+    }
+
+    /// Returns the number of loads currently holding a [`LoadPermit`].
+    pub(crate) fn running(&self) -> usize {
+        self.state.lock().running
+    }
+
+    /// Waits until a load with the given `priority` is allowed to run. The load should hold on to
+    /// the returned [`LoadPermit`] until it has finished.
+    pub(crate) fn acquire(&self, priority: LoadPriority) -> AcquireLoadPermit {
+        AcquireLoadPermit {
+            max_concurrent_loads: self.max_concurrent_loads,
+            state: self.state.clone(),
+            priority,
+            ticket: None,
+        }
+    }
+}
+
+/// Allows a single load to run. Dropping it lets the next queued load start.
+pub(crate) struct LoadPermit {
+    state: Option<Arc<Mutex<LoadLimiterState>>>,
+}
+
+impl Drop for LoadPermit {
+    fn drop(&mut self) {
+        if let Some(state) = &self.state {
+            state.lock().release();
+        }
+    }
+}
+
+/// A [`Future`] that resolves to a [`LoadPermit`] once the load is allowed to run.
+/// Dropping it before it resolves removes the load from the queue.
+pub(crate) struct AcquireLoadPermit {
+    max_concurrent_loads: Option<usize>,
+    state: Arc<Mutex<LoadLimiterState>>,
+    priority: LoadPriority,
+    ticket: Option<u64>,
+}
+
+impl Future for AcquireLoadPermit {
+    type Output = LoadPermit;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Some(max_concurrent_loads) = self.max_concurrent_loads else {
+            return Poll::Ready(LoadPermit { state: None });
+        };
+
+        let this = &mut *self;
+        let mut state = this.state.lock();
+        match this.ticket {
+            None => {
+                if state.waiting.is_empty() && state.running < max_concurrent_loads {
+                    state.running += 1;
+                    drop(state);
+                    return Poll::Ready(LoadPermit {
+                        state: Some(this.state.clone()),
+                    });
+                }
+                let ticket = state.next_ticket;
+                state.next_ticket += 1;
+                state
+                    .waiting
+                    .insert((Reverse(this.priority), ticket), cx.waker().clone());
+                this.ticket = Some(ticket);
+                Poll::Pending
+            }
+            Some(ticket) => {
+                if state.granted.remove(&ticket) {
+                    drop(state);
+                    this.ticket = None;
+                    return Poll::Ready(LoadPermit {
+                        state: Some(this.state.clone()),
+                    });
+                }
+                if let Some(waker) = state.waiting.get_mut(&(Reverse(this.priority), ticket)) {
+                    waker.clone_from(cx.waker());
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for AcquireLoadPermit {
+    fn drop(&mut self) {
+        let Some(ticket) = self.ticket else {
+            return;
+        };
+        let mut state = self.state.lock();
+        if state
+            .waiting
+            .remove(&(Reverse(self.priority), ticket))
+            .is_none()
+            && state.granted.remove(&ticket)
+        {
+            // The permit was handed to this load after it was cancelled, so pass it on.
+            state.release();
+        }
+    }
+}
+
+/// Signals an in-flight asset load that it is no longer needed because every strong [`Handle`]
+/// to the asset was dropped.
+///
+/// [`Handle`]: crate::Handle
+#[derive(Clone, Default)]
+pub(crate) struct LoadCancellation {
+    inner: Arc<LoadCancellationInner>,
+}
+
+#[derive(Default)]
+struct LoadCancellationInner {
+    cancelled: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+impl LoadCancellation {
+    pub(crate) fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::Release);
+        if let Some(waker) = self.inner.waker.lock().take() {
+            waker.wake();
+        }
+    }
+
+    pub(crate) fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Resolves once [`LoadCancellation::cancel`] has been called.
+    pub(crate) fn cancelled(&self) -> impl Future<Output = ()> + '_ {
+        std::future::poll_fn(|cx| {
+            if self.is_cancelled() {
+                return Poll::Ready(());
+            }
+            *self.inner.waker.lock() = Some(cx.waker().clone());
+            // Check again in case `cancel` was called before the waker was stored.
+            if self.is_cancelled() {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        })
+    }
+}
+
+impl std::fmt::Debug for LoadCancellation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LoadCancellation")
+            .field("cancelled", &self.is_cancelled())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_lite::future::{block_on, poll_once};
+
+    #[test]
+    fn unlimited_loads_never_wait() {
+        let limiter = LoadLimiter::new(None);
+        let _a = block_on(limiter.acquire(LoadPriority::Background));
+        let _b = block_on(limiter.acquire(LoadPriority::Normal));
+        assert_eq!(limiter.queued(), 0);
+    }
+
+    #[test]
+    fn queued_loads_start_by_priority() {
+        let limiter = LoadLimiter::new(Some(1));
+        let first = block_on(limiter.acquire(LoadPriority::Normal));
+
+        let mut background = Box::pin(limiter.acquire(LoadPriority::Background));
+        let mut normal = Box::pin(limiter.acquire(LoadPriority::Normal));
+        let mut high = Box::pin(limiter.acquire(LoadPriority::High));
+        assert!(block_on(poll_once(&mut background)).is_none());
+        assert!(block_on(poll_once(&mut normal)).is_none());
+        assert!(block_on(poll_once(&mut high)).is_none());
+        assert_eq!(limiter.queued(), 3);
+
+        drop(first);
+        assert!(block_on(poll_once(&mut background)).is_none());
+        assert!(block_on(poll_once(&mut normal)).is_none());
+        let high_permit = block_on(poll_once(&mut high)).expect("high priority load should start");
+
+        drop(high_permit);
+        assert!(block_on(poll_once(&mut background)).is_none());
+        let normal_permit = block_on(poll_once(&mut normal)).expect("normal load should start");
+
+        drop(normal_permit);
+        let _background_permit =
+            block_on(poll_once(&mut background)).expect("background load should start");
+        assert_eq!(limiter.queued(), 0);
+        assert_eq!(limiter.running(), 1);
+    }
+
+    #[test]
+    fn dropped_waiters_pass_on_their_permit() {
+        let limiter = LoadLimiter::new(Some(1));
+        let first = block_on(limiter.acquire(LoadPriority::Normal));
+
+        let mut cancelled = Box::pin(limiter.acquire(LoadPriority::High));
+        let mut next = Box::pin(limiter.acquire(LoadPriority::Normal));
+        assert!(block_on(poll_once(&mut cancelled)).is_none());
+        assert!(block_on(poll_once(&mut next)).is_none());
+
+        // The permit is granted to `cancelled`, which is dropped before it observes it.
+        drop(first);
+        drop(cancelled);
+        let _next_permit = block_on(poll_once(&mut next)).expect("next load should start");
+        assert_eq!(limiter.running(), 1);
+    }
+}
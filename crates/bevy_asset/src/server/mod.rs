@@ -1,4 +1,5 @@
 mod info;
+mod load_queue;
 mod loaders;
 
 use crate::{
@@ -7,7 +8,7 @@ use crate::{
         AssetReaderError, AssetSource, AssetSourceEvent, AssetSourceId, AssetSources,
         ErasedAssetReader, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader,
     },
-    loader::{AssetLoader, ErasedAssetLoader, LoadContext, LoadedAsset},
+    loader::{AssetContainer, AssetLoader, ErasedAssetLoader, LoadContext, LoadedAsset},
     meta::{
         loader_settings_meta_transform, AssetActionMinimal, AssetMetaDyn, AssetMetaMinimal,
         MetaTransform, Settings,
@@ -24,6 +25,8 @@ use bevy_utils::{CowArc, HashSet};
 use crossbeam_channel::{Receiver, Sender};
 use futures_lite::StreamExt;
 use info::*;
+pub use load_queue::LoadPriority;
+pub(crate) use load_queue::{LoadCancellation, LoadLimiter};
 use loaders::*;
 use parking_lot::RwLock;
 use std::future::Future;
@@ -303,7 +306,21 @@ impl AssetServer {
     /// The asset load will fail and an error will be printed to the logs if the asset stored at `path` is not of type `A`.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Handle<A> {
-        self.load_with_meta_transform(path, None, ())
+        self.load_with_meta_transform(path, None, (), LoadPriority::Normal)
+    }
+
+    /// Begins loading an [`Asset`] of type `A` stored at `path` with the given [`LoadPriority`]. This behaves like
+    /// [`AssetServer::load`], but if the asset's [`AssetSource`] limits the number of concurrent loads, loads with a higher
+    /// priority will be started before loads with a lower priority.
+    ///
+    /// If the asset is already loading, its priority is not changed.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_priority<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        priority: LoadPriority,
+    ) -> Handle<A> {
+        self.load_with_meta_transform(path, None, (), priority)
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
@@ -327,7 +344,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, None, guard)
+        self.load_with_meta_transform(path, None, guard, LoadPriority::Normal)
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path`. The given `settings` function will override the asset's
@@ -339,7 +356,12 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         settings: impl Fn(&mut S) + Send + Sync + 'static,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), ())
+        self.load_with_meta_transform(
+            path,
+            Some(loader_settings_meta_transform(settings)),
+            (),
+            LoadPriority::Normal,
+        )
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
@@ -358,7 +380,12 @@ impl AssetServer {
         settings: impl Fn(&mut S) + Send + Sync + 'static,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), guard)
+        self.load_with_meta_transform(
+            path,
+            Some(loader_settings_meta_transform(settings)),
+            guard,
+            LoadPriority::Normal,
+        )
     }
 
     pub(crate) fn load_with_meta_transform<'a, A: Asset, G: Send + Sync + 'static>(
@@ -366,20 +393,42 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
         guard: G,
+        priority: LoadPriority,
     ) -> Handle<A> {
         let path = path.into().into_owned();
-        let (handle, should_load) = self.data.infos.write().get_or_create_path_handle::<A>(
+        let mut infos = self.data.infos.write();
+        let (handle, should_load) = infos.get_or_create_path_handle::<A>(
             path.clone(),
             HandleLoadingMode::Request,
             meta_transform,
         );
 
         if should_load {
-            let owned_handle = Some(handle.clone().untyped());
+            let cancellation = LoadCancellation::default();
+            // if a load was requested, the info was just created or updated
+            infos
+                .get_mut(handle.id().untyped())
+                .unwrap()
+                .load_cancellation = Some(cancellation.clone());
+            drop(infos);
+
+            // The load only holds a weak handle, so that dropping every strong handle cancels it.
+            let weak_handle = Some(handle.clone_weak().untyped());
             let server = self.clone();
             IoTaskPool::get()
                 .spawn(async move {
-                    if let Err(err) = server.load_internal(owned_handle, path, false, None).await {
+                    let load = async {
+                        Some(
+                            server
+                                .load_internal(weak_handle, path, false, None, priority)
+                                .await,
+                        )
+                    };
+                    let cancelled = async {
+                        cancellation.cancelled().await;
+                        None
+                    };
+                    if let Some(Err(err)) = futures_lite::future::or(load, cancelled).await {
                         error!("{}", err);
                     }
                     drop(guard);
@@ -399,7 +448,8 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
     ) -> Result<UntypedHandle, AssetLoadError> {
         let path: AssetPath = path.into();
-        self.load_internal(None, path, false, None).await
+        self.load_internal(None, path, false, None, LoadPriority::Normal)
+            .await
     }
 
     pub(crate) fn load_untyped_with_meta_transform<'a>(
@@ -486,16 +536,25 @@ impl AssetServer {
     ///
     /// `input_handle` must only be [`Some`] if `should_load` was true when retrieving `input_handle`. This is an optimization to
     /// avoid looking up `should_load` twice, but it means you _must_ be sure a load is necessary when calling this function with [`Some`].
+    /// `input_handle` may be weak, in which case the asset's strong handle is looked up when its meta transform is applied.
+    ///
+    /// The load waits for a permit from the [`AssetSource`] of `path` (ordered by `priority`) before reading anything.
     async fn load_internal<'a>(
         &self,
         input_handle: Option<UntypedHandle>,
         path: AssetPath<'a>,
         force: bool,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
     ) -> Result<UntypedHandle, AssetLoadError> {
         let asset_type_id = input_handle.as_ref().map(UntypedHandle::type_id);
 
         let path = path.into_owned();
+        let _permit = match self.get_source(path.source()) {
+            Ok(source) => Some(source.load_limiter().acquire(priority).await),
+            // a missing source is reported below
+            Err(_) => None,
+        };
         let path_clone = path.clone();
         let (mut meta, loader, mut reader) = self
             .get_meta_loader_and_reader(&path_clone, asset_type_id)
@@ -581,12 +640,29 @@ impl AssetServer {
             (handle.clone().unwrap(), path.clone())
         };
 
-        if let Some(meta_transform) = base_handle.meta_transform() {
-            (*meta_transform)(&mut *meta);
+        {
+            let strong_base_handle = match &base_handle {
+                UntypedHandle::Strong(_) => Some(base_handle.clone()),
+                UntypedHandle::Weak(id) => self.data.infos.read().get_id_handle(*id),
+            };
+            if let Some(meta_transform) = strong_base_handle
+                .as_ref()
+                .and_then(UntypedHandle::meta_transform)
+            {
+                (*meta_transform)(&mut *meta);
+            }
         }
 
         match self
-            .load_with_meta_loader_and_reader(&base_path, meta, &*loader, &mut *reader, true, false)
+            .load_with_meta_loader_and_reader(
+                &base_path,
+                meta,
+                &*loader,
+                &mut *reader,
+                true,
+                false,
+                Some(base_handle.id()),
+            )
             .await
         {
             Ok(loaded_asset) => {
@@ -649,7 +725,15 @@ impl AssetServer {
                     .infos
                     .read()
                     .get_path_handles(&path)
-                    .map(|handle| server.load_internal(Some(handle), path.clone(), true, None))
+                    .map(|handle| {
+                        server.load_internal(
+                            Some(handle),
+                            path.clone(),
+                            true,
+                            None,
+                            LoadPriority::Normal,
+                        )
+                    })
                     .collect::<Vec<_>>();
 
                 for result in requests {
@@ -660,7 +744,10 @@ impl AssetServer {
                 }
 
                 if !reloaded && server.data.infos.read().should_reload(&path) {
-                    if let Err(err) = server.load_internal(None, path, true, None).await {
+                    if let Err(err) = server
+                        .load_internal(None, path, true, None, LoadPriority::Normal)
+                        .await
+                    {
                         error!("{}", err);
                     }
                 }
@@ -873,7 +960,7 @@ impl AssetServer {
             .detach();
     }
 
-    fn send_asset_event(&self, event: InternalAssetEvent) {
+    pub(crate) fn send_asset_event(&self, event: InternalAssetEvent) {
         self.data.asset_event_sender.send(event).unwrap();
     }
 
@@ -1172,11 +1259,13 @@ impl AssetServer {
         reader: &mut dyn Reader,
         load_dependencies: bool,
         populate_hashes: bool,
+        partial_asset_id: Option<UntypedAssetId>,
     ) -> Result<ErasedLoadedAsset, AssetLoadError> {
         // TODO: experiment with this
         let asset_path = asset_path.clone_owned();
         let load_context =
-            LoadContext::new(self, asset_path.clone(), load_dependencies, populate_hashes);
+            LoadContext::new(self, asset_path.clone(), load_dependencies, populate_hashes)
+                .with_partial_asset_id(partial_asset_id);
         loader.load(reader, meta, load_context).await.map_err(|e| {
             AssetLoadError::AssetLoaderError(AssetLoaderError {
                 path: asset_path.clone_owned(),
@@ -1202,6 +1291,12 @@ pub fn handle_internal_asset_events(world: &mut World) {
                         &server.data.asset_event_sender,
                     );
                 }
+                InternalAssetEvent::LoadedPartial { id, asset } => {
+                    // Skip intermediate versions of assets whose handles have all been dropped
+                    if infos.contains_key(id) {
+                        asset.insert(id, world);
+                    }
+                }
                 InternalAssetEvent::LoadedWithDependencies { id } => {
                     let sender = infos
                         .dependency_loaded_event_sender
@@ -1316,6 +1411,10 @@ pub(crate) enum InternalAssetEvent {
         id: UntypedAssetId,
         loaded_asset: ErasedLoadedAsset,
     },
+    LoadedPartial {
+        id: UntypedAssetId,
+        asset: Box<dyn AssetContainer>,
+    },
     LoadedWithDependencies {
         id: UntypedAssetId,
     },
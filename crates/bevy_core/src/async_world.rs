@@ -0,0 +1,253 @@
+use std::future::{pending, Future};
+
+use async_channel::{Receiver, Sender};
+use bevy_app::prelude::*;
+use bevy_ecs::{prelude::*, world::CommandQueue};
+use bevy_tasks::{AsyncComputeTaskPool, Task};
+use bevy_utils::tracing::error;
+
+/// Adds the [`AsyncWorld`] resource, and applies the world accesses requested through it in [`First`].
+#[derive(Default)]
+pub struct AsyncWorldPlugin;
+
+impl Plugin for AsyncWorldPlugin {
+    fn build(&self, app: &mut App) {
+        let (sender, receiver) = async_channel::unbounded();
+        app.insert_resource(AsyncWorld { sender })
+            .insert_resource(AsyncWorldQueue { receiver })
+            .add_systems(First, apply_async_world_commands);
+    }
+}
+
+/// A handle letting futures access the [`World`] at its next sync point.
+///
+/// Tasks spawned on a [`TaskPool`](bevy_tasks::TaskPool) can't borrow the [`World`]. Instead, a clone
+/// of this resource can be moved into them, and its methods return futures that complete once the
+/// [`AsyncWorldPlugin`] applied the requested access, in [`First`]. This lets sequences spanning
+/// several frames read like straight-line code.
+///
+/// If the [`App`] is dropped before a request is applied, the future waiting for it never completes.
+///
+/// ```
+/// # use bevy_core::AsyncWorld;
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Event, Clone)]
+/// struct DoorOpened;
+///
+/// #[derive(Resource)]
+/// struct Message(&'static str);
+///
+/// fn start_cutscene(async_world: Res<AsyncWorld>) {
+///     async_world
+///         .spawn(|world| async move {
+///             world.event::<DoorOpened>().await;
+///             world.run(|world| world.insert_resource(Message("Who's there?"))).await;
+///             world.frames(120).await;
+///             world.run(|world| world.remove_resource::<Message>()).await;
+///         })
+///         .detach();
+/// }
+/// # bevy_ecs::system::assert_is_system(start_cutscene);
+/// ```
+#[derive(Resource, Clone)]
+pub struct AsyncWorld {
+    sender: Sender<CommandQueue>,
+}
+
+impl AsyncWorld {
+    /// Runs `f` with exclusive access to the [`World`] at its next sync point, and returns its result.
+    pub async fn run<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut World) -> R + Send + 'static,
+    ) -> R {
+        let (result_sender, result_receiver) = async_channel::bounded(1);
+        let mut queue = CommandQueue::default();
+        queue.push(move |world: &mut World| {
+            // The future may have been dropped while waiting.
+            let _ = result_sender.try_send(f(world));
+        });
+        self.apply(queue).await;
+        match result_receiver.recv().await {
+            Ok(result) => result,
+            Err(_) => pending().await,
+        }
+    }
+
+    /// Applies `queue` to the [`World`] at its next sync point, without waiting for it.
+    pub fn queue(&self, queue: CommandQueue) {
+        // The world may have been dropped, in which case nobody cares about its commands.
+        let _ = self.sender.try_send(queue);
+    }
+
+    /// Waits for the next sync point of the [`World`].
+    pub async fn next_frame(&self) {
+        self.run(|_| ()).await;
+    }
+
+    /// Waits for `count` sync points of the [`World`], so at least `count` frames.
+    pub async fn frames(&self, count: u32) {
+        for _ in 0..count {
+            self.next_frame().await;
+        }
+    }
+
+    /// Waits for the next event of type `E`, and returns it.
+    ///
+    /// The returned future starts listening at the first sync point after it is polled,
+    /// so events sent before that, including those sent since this was called, are missed.
+    ///
+    /// If `E` isn't registered as an event, an error is logged and the future never completes.
+    pub async fn event<E: Event + Clone>(&self) -> E {
+        let mut cursor = None;
+        loop {
+            let read = self
+                .run(move |world| {
+                    let Some(events) = world.get_resource::<Events<E>>() else {
+                        error!(
+                            "AsyncWorld::event can't wait for `{}`, which is not registered as an event",
+                            std::any::type_name::<E>()
+                        );
+                        return None;
+                    };
+                    let mut cursor = cursor.unwrap_or_else(|| events.get_cursor_current());
+                    let event = cursor.read(events).next().cloned();
+                    Some((cursor, event))
+                })
+                .await;
+            match read {
+                Some((_, Some(event))) => return event,
+                Some((next_cursor, None)) => cursor = Some(next_cursor),
+                None => return pending().await,
+            }
+        }
+    }
+
+    /// Waits until `condition` returns `true`, checking it once per frame.
+    pub async fn until(&self, condition: impl FnMut(&mut World) -> bool + Send + 'static) {
+        let mut condition = Some(condition);
+        loop {
+            let (done, returned) = self
+                .run({
+                    let mut condition = condition.take().unwrap();
+                    move |world| (condition(world), condition)
+                })
+                .await;
+            if done {
+                return;
+            }
+            condition = Some(returned);
+        }
+    }
+
+    /// Spawns the future returned by `f` on the [`AsyncComputeTaskPool`], giving it a clone of this handle.
+    pub fn spawn<F, T>(&self, f: impl FnOnce(AsyncWorld) -> F) -> Task<T>
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        AsyncComputeTaskPool::get().spawn(f(self.clone()))
+    }
+
+    async fn apply(&self, queue: CommandQueue) {
+        if self.sender.send(queue).await.is_err() {
+            pending::<()>().await;
+        }
+    }
+}
+
+#[derive(Resource)]
+struct AsyncWorldQueue {
+    receiver: Receiver<CommandQueue>,
+}
+
+/// Applies the world accesses requested through [`AsyncWorld`].
+///
+/// Only the requests sent before this system starts are applied, so that a future waiting
+/// for several sync points in a row waits for as many frames.
+pub fn apply_async_world_commands(world: &mut World) {
+    let receiver = world.resource::<AsyncWorldQueue>().receiver.clone();
+    for _ in 0..receiver.len() {
+        let Ok(mut queue) = receiver.try_recv() else {
+            break;
+        };
+        queue.apply(world);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use crate::{AsyncWorld, AsyncWorldPlugin, FrameCount, FrameCountPlugin, TaskPoolPlugin};
+    use bevy_app::prelude::*;
+    use bevy_ecs::prelude::*;
+
+    #[derive(Event, Clone)]
+    struct Ping(u32);
+
+    #[derive(Resource)]
+    struct Outcome(u32, u32);
+
+    fn update_until(app: &mut App, mut done: impl FnMut(&mut App) -> bool) {
+        let start = Instant::now();
+        while !done(app) {
+            assert!(start.elapsed() < Duration::from_secs(10), "timed out");
+            app.update();
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn async_world_access() {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            FrameCountPlugin,
+            AsyncWorldPlugin,
+        ))
+        .add_event::<Ping>();
+
+        let task = app
+            .world()
+            .resource::<AsyncWorld>()
+            .spawn(|world| async move {
+                let start = world.run(|world| world.resource::<FrameCount>().0).await;
+                world.frames(3).await;
+                let end = world.run(|world| world.resource::<FrameCount>().0).await;
+                let ping = world.event::<Ping>().await;
+                world
+                    .run(move |world| world.insert_resource(Outcome(end - start, ping.0)))
+                    .await;
+            });
+
+        update_until(&mut app, |app| {
+            app.world_mut().send_event(Ping(7));
+            app.world().contains_resource::<Outcome>()
+        });
+        let outcome = app.world().resource::<Outcome>();
+        // Each sync point is in a later frame, but the task may miss some of them.
+        assert!(outcome.0 >= 4);
+        assert_eq!(outcome.1, 7);
+        bevy_tasks::block_on(task);
+    }
+
+    #[test]
+    fn unregistered_event_never_completes() {
+        let mut app = App::new();
+        app.add_plugins((TaskPoolPlugin::default(), AsyncWorldPlugin));
+
+        let async_world = app.world().resource::<AsyncWorld>().clone();
+        let task = async_world.spawn(|world| async move {
+            world.event::<Ping>().await;
+        });
+        let _marker = async_world.spawn(|world| async move {
+            world.frames(3).await;
+            world
+                .run(|world| world.insert_resource(Outcome(0, 0)))
+                .await;
+        });
+
+        update_until(&mut app, |app| app.world().contains_resource::<Outcome>());
+        assert!(!task.is_finished());
+    }
+}
@@ -7,11 +7,13 @@
 
 //! This crate provides core functionality for Bevy Engine.
 
+mod async_world;
 mod name;
 #[cfg(feature = "serialize")]
 mod serde;
 mod task_pool_options;
 
+pub use async_world::*;
 use bevy_ecs::system::Resource;
 pub use name::*;
 pub use task_pool_options::*;
@@ -20,8 +22,8 @@ pub mod prelude {
     //! The Bevy Core Prelude.
     #[doc(hidden)]
     pub use crate::{
-        FrameCountPlugin, Name, NameOrEntity, TaskPoolOptions, TaskPoolPlugin,
-        TypeRegistrationPlugin,
+        AsyncWorld, AsyncWorldPlugin, FrameCountPlugin, Name, NameOrEntity, TaskPoolOptions,
+        TaskPoolPlugin, TypeRegistrationPlugin,
     };
 }
 
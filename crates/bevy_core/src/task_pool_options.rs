@@ -1,4 +1,4 @@
-use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPoolBuilder};
+use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPool, TaskPoolBuilder};
 use bevy_utils::tracing::trace;
 
 /// Defines a simple way to determine how many threads to use given the number of remaining cores
@@ -30,6 +30,24 @@ impl TaskPoolThreadAssignmentPolicy {
     }
 }
 
+/// Which of the default task pools may run the tasks of another one when their threads are idle.
+///
+/// Lending threads helps the borrowing pool finish its work sooner when the lending pools have nothing to
+/// do, at the cost of the lending pools picking up their new tasks later while their threads are busy with
+/// borrowed ones. See [`TaskPoolBuilder::lend_threads_to`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TaskPoolThreadBorrowingPolicy {
+    /// Each pool only runs its own tasks.
+    #[default]
+    Isolated,
+    /// Idle [`IoTaskPool`] and [`AsyncComputeTaskPool`] threads run [`ComputeTaskPool`] tasks, like systems
+    /// and parallel queries.
+    LendToCompute,
+    /// Idle [`ComputeTaskPool`] threads, like between the frames of a GPU bound app, run
+    /// [`AsyncComputeTaskPool`] tasks.
+    LendToAsyncCompute,
+}
+
 /// Helper for configuring and creating the default task pools. For end-users who want full control,
 /// set up [`TaskPoolPlugin`](super::TaskPoolPlugin)
 #[derive(Clone, Debug)]
@@ -47,6 +65,12 @@ pub struct TaskPoolOptions {
     pub async_compute: TaskPoolThreadAssignmentPolicy,
     /// Used to determine number of compute threads to allocate
     pub compute: TaskPoolThreadAssignmentPolicy,
+
+    /// Used to determine which pools run the tasks of other pools when idle
+    pub thread_borrowing: TaskPoolThreadBorrowingPolicy,
+
+    /// If set, the pools count their tasks, see [`TaskPoolBuilder::track_stats`]
+    pub track_stats: bool,
 }
 
 impl Default for TaskPoolOptions {
@@ -76,6 +100,10 @@ impl Default for TaskPoolOptions {
                 max_threads: usize::MAX,
                 percent: 1.0, // This 1.0 here means "whatever is left over"
             },
+
+            thread_borrowing: TaskPoolThreadBorrowingPolicy::Isolated,
+
+            track_stats: false,
         }
     }
 }
@@ -98,55 +126,79 @@ impl TaskPoolOptions {
 
         let mut remaining_threads = total_threads;
 
-        {
-            // Determine the number of IO threads we will use
-            let io_threads = self
-                .io
-                .get_number_of_threads(remaining_threads, total_threads);
-
-            trace!("IO Threads: {}", io_threads);
-            remaining_threads = remaining_threads.saturating_sub(io_threads);
-
+        // Determine the number of IO threads we will use
+        let io_threads = self
+            .io
+            .get_number_of_threads(remaining_threads, total_threads);
+        trace!("IO Threads: {}", io_threads);
+        remaining_threads = remaining_threads.saturating_sub(io_threads);
+
+        // Determine the number of async compute threads we will use
+        let async_compute_threads = self
+            .async_compute
+            .get_number_of_threads(remaining_threads, total_threads);
+        trace!("Async Compute Threads: {}", async_compute_threads);
+        remaining_threads = remaining_threads.saturating_sub(async_compute_threads);
+
+        // Determine the number of compute threads we will use
+        // This is intentionally last so that an end user can specify 1.0 as the percent
+        let compute_threads = self
+            .compute
+            .get_number_of_threads(remaining_threads, total_threads);
+        trace!("Compute Threads: {}", compute_threads);
+
+        let build_io = |borrower: Option<&TaskPool>| {
             IoTaskPool::get_or_init(|| {
-                TaskPoolBuilder::default()
+                lend_threads(TaskPoolBuilder::default(), borrower)
                     .num_threads(io_threads)
                     .thread_name("IO Task Pool".to_string())
+                    .track_stats(self.track_stats)
                     .build()
-            });
-        }
-
-        {
-            // Determine the number of async compute threads we will use
-            let async_compute_threads = self
-                .async_compute
-                .get_number_of_threads(remaining_threads, total_threads);
-
-            trace!("Async Compute Threads: {}", async_compute_threads);
-            remaining_threads = remaining_threads.saturating_sub(async_compute_threads);
-
+            })
+        };
+        let build_async_compute = |borrower: Option<&TaskPool>| {
             AsyncComputeTaskPool::get_or_init(|| {
-                TaskPoolBuilder::default()
+                lend_threads(TaskPoolBuilder::default(), borrower)
                     .num_threads(async_compute_threads)
                     .thread_name("Async Compute Task Pool".to_string())
+                    .track_stats(self.track_stats)
                     .build()
-            });
-        }
-
-        {
-            // Determine the number of compute threads we will use
-            // This is intentionally last so that an end user can specify 1.0 as the percent
-            let compute_threads = self
-                .compute
-                .get_number_of_threads(remaining_threads, total_threads);
-
-            trace!("Compute Threads: {}", compute_threads);
-
+            })
+        };
+        let build_compute = |borrower: Option<&TaskPool>| {
             ComputeTaskPool::get_or_init(|| {
-                TaskPoolBuilder::default()
+                lend_threads(TaskPoolBuilder::default(), borrower)
                     .num_threads(compute_threads)
                     .thread_name("Compute Task Pool".to_string())
+                    .track_stats(self.track_stats)
                     .build()
-            });
+            })
+        };
+
+        // A pool lends its threads when it is built, so borrowing pools are built first.
+        match self.thread_borrowing {
+            TaskPoolThreadBorrowingPolicy::Isolated => {
+                build_io(None);
+                build_async_compute(None);
+                build_compute(None);
+            }
+            TaskPoolThreadBorrowingPolicy::LendToCompute => {
+                let compute = build_compute(None);
+                build_io(Some(compute));
+                build_async_compute(Some(compute));
+            }
+            TaskPoolThreadBorrowingPolicy::LendToAsyncCompute => {
+                build_io(None);
+                let async_compute = build_async_compute(None);
+                build_compute(Some(async_compute));
+            }
         }
     }
 }
+
+fn lend_threads(builder: TaskPoolBuilder, borrower: Option<&TaskPool>) -> TaskPoolBuilder {
+    match borrower {
+        Some(borrower) => builder.lend_threads_to(borrower),
+        None => builder,
+    }
+}
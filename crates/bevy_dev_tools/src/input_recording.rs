@@ -0,0 +1,571 @@
+//! Recording of input and window events, and deterministic replay of them in a later run.
+//!
+//! Add [`InputRecordingPlugin::record`] to an [`App`] to write every input and window event it receives,
+//! frame by frame and together with each frame's [`Time<Real>`] delta, into a file holding one [`ron`] value per frame.
+//! Adding [`InputRecordingPlugin::replay`] instead feeds the recorded events and deltas back to the app,
+//! ignoring live input until the recording is exhausted, so that a session can be reproduced exactly.
+//!
+//! ```no_run
+//! # use bevy_app::prelude::*;
+//! # use bevy_dev_tools::input_recording::InputRecordingPlugin;
+//! let mut app = App::new();
+//! if std::env::args().any(|arg| arg == "--replay") {
+//!     app.add_plugins(InputRecordingPlugin::replay("session.ron"));
+//! } else {
+//!     app.add_plugins(InputRecordingPlugin::record("session.ron"));
+//! }
+//! ```
+
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+use bevy_app::{prelude::*, AppExit};
+use bevy_ecs::{event::EventCursor, prelude::*};
+use bevy_input::{
+    gamepad::GamepadEvent,
+    gestures::{DoubleTapGesture, PanGesture, PinchGesture, RotationGesture},
+    keyboard::{KeyboardFocusLost, KeyboardInput},
+    mouse::{MouseButtonInput, MouseMotion, MouseWheel},
+    touch::TouchInput,
+};
+use bevy_time::{Real, Time, TimeSystem, TimeUpdateStrategy};
+use bevy_utils::{
+    tracing::{info, warn},
+    Duration,
+};
+use bevy_window::{
+    CursorEntered, CursorLeft, CursorMoved, FileDragAndDrop, Ime, WindowBackendScaleFactorChanged,
+    WindowCloseRequested, WindowFocused, WindowMoved, WindowOccluded, WindowResized,
+    WindowScaleFactorChanged,
+};
+use serde::{Deserialize, Serialize};
+
+/// A plugin that records the input and window events of an [`App`] to a file, or replays them from one.
+///
+/// While recording, the events of each frame are stored together with its [`Time<Real>`] delta in an
+/// [`InputRecorder`], which appends the new frames to the file every [`save_interval`](Self::save_interval),
+/// when [stopped](InputRecorder::stop), and when an [`AppExit`] event is sent.
+///
+/// While replaying, the events of each recorded frame are sent in [`First`], after live input events
+/// are discarded, and the frame's delta is applied through [`TimeUpdateStrategy::ManualDuration`].
+/// Live window events, such as [`WindowCloseRequested`], are kept alongside the recorded ones.
+/// Once every frame has been replayed, live input and [`TimeUpdateStrategy::Automatic`] are restored;
+/// see [`InputReplayer::is_finished`].
+///
+/// Events refer to windows by [`Entity`], so the replaying app should spawn its windows in the same order.
+/// Events sent by systems running after [`First`], such as the gamepad events of `bevy_gilrs`,
+/// are not discarded while replaying, so those input sources should be disabled.
+pub struct InputRecordingPlugin {
+    /// Whether to record or replay, and the path of the recording.
+    pub mode: InputRecordingMode,
+    /// How much real time passes between appending the new frames to the file while recording,
+    /// so that most of the recording survives a crash.
+    pub save_interval: Duration,
+}
+
+/// Whether an [`InputRecordingPlugin`] records or replays events.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputRecordingMode {
+    /// Record events and write them to the given path.
+    Record(PathBuf),
+    /// Replay events read from the given path.
+    Replay(PathBuf),
+}
+
+impl InputRecordingPlugin {
+    /// The default [`save_interval`](Self::save_interval).
+    pub const DEFAULT_SAVE_INTERVAL: Duration = Duration::from_secs(10);
+
+    /// Creates a plugin recording events to `path`.
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            mode: InputRecordingMode::Record(path.into()),
+            save_interval: Self::DEFAULT_SAVE_INTERVAL,
+        }
+    }
+
+    /// Creates a plugin replaying the events recorded in `path`.
+    pub fn replay(path: impl Into<PathBuf>) -> Self {
+        Self {
+            mode: InputRecordingMode::Replay(path.into()),
+            save_interval: Self::DEFAULT_SAVE_INTERVAL,
+        }
+    }
+
+    /// Sets how much real time passes between appending the new frames to the file.
+    pub fn with_save_interval(mut self, save_interval: Duration) -> Self {
+        self.save_interval = save_interval;
+        self
+    }
+}
+
+impl Plugin for InputRecordingPlugin {
+    fn build(&self, app: &mut App) {
+        match &self.mode {
+            InputRecordingMode::Record(path) => {
+                app.insert_resource(InputRecorder::new(path.clone(), self.save_interval))
+                    .add_systems(Last, record_events);
+            }
+            InputRecordingMode::Replay(path) => {
+                let recording =
+                    InputRecording::load(path).expect("error reading input recording file");
+                app.insert_resource(InputReplayer::new(recording))
+                    .add_systems(
+                        First,
+                        replay_events
+                            .after(bevy_ecs::event::EventUpdates)
+                            .before(TimeSystem),
+                    );
+            }
+        }
+    }
+}
+
+/// The input and window events received by an app, frame by frame.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub struct InputRecording {
+    /// The recorded frames, in order.
+    pub frames: Vec<RecordedFrame>,
+}
+
+impl InputRecording {
+    /// Reads a recording from a file holding one [`ron`] [`RecordedFrame`] per line.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut frames = Vec::new();
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            frames.push(
+                ron::from_str(&line)
+                    .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+            );
+        }
+        Ok(Self { frames })
+    }
+
+    /// Writes the recording to a file, with one [`ron`] [`RecordedFrame`] per line.
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        write_frames(&mut writer, &self.frames)?;
+        writer.flush()
+    }
+}
+
+/// Writes each frame as a [`ron`] value on its own line.
+fn write_frames(writer: &mut impl Write, frames: &[RecordedFrame]) -> io::Result<()> {
+    for frame in frames {
+        ron::ser::to_writer(&mut *writer, frame)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+/// The events received during a single frame, and how much real time elapsed since the previous one.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+pub struct RecordedFrame {
+    /// The [`Time<Real>`] delta of the frame.
+    pub delta: Duration,
+    /// The events of the frame, grouped by type.
+    pub events: Vec<RecordedEvent>,
+}
+
+macro_rules! recorded_events {
+    (
+        input: { $($input_field:ident: $input_event:ident),* $(,)? },
+        window: { $($window_field:ident: $window_event:ident),* $(,)? } $(,)?
+    ) => {
+        /// An input or window event stored in an [`InputRecording`].
+        #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+        pub enum RecordedEvent {
+            $(
+                #[allow(missing_docs)]
+                $input_event($input_event),
+            )*
+            $(
+                #[allow(missing_docs)]
+                $window_event($window_event),
+            )*
+        }
+
+        $(
+            impl From<$input_event> for RecordedEvent {
+                fn from(event: $input_event) -> Self {
+                    Self::$input_event(event)
+                }
+            }
+        )*
+        $(
+            impl From<$window_event> for RecordedEvent {
+                fn from(event: $window_event) -> Self {
+                    Self::$window_event(event)
+                }
+            }
+        )*
+
+        impl RecordedEvent {
+            /// Sends the event to the world.
+            pub fn send(self, world: &mut World) {
+                match self {
+                    $(Self::$input_event(event) => {
+                        world.send_event(event);
+                    })*
+                    $(Self::$window_event(event) => {
+                        world.send_event(event);
+                    })*
+                }
+            }
+
+            /// Discards all pending input events of the recorded types, keeping window events.
+            fn clear_input(world: &mut World) {
+                $(
+                    if let Some(mut events) = world.get_resource_mut::<Events<$input_event>>() {
+                        events.clear();
+                    }
+                )*
+            }
+        }
+
+        /// A cursor for each recorded event type.
+        #[derive(Default)]
+        struct RecordedEventCursors {
+            $($input_field: EventCursor<$input_event>,)*
+            $($window_field: EventCursor<$window_event>,)*
+        }
+
+        impl RecordedEventCursors {
+            /// Appends the events sent since the last call to `output`.
+            fn read(&mut self, world: &World, output: &mut Vec<RecordedEvent>) {
+                $(
+                    if let Some(events) = world.get_resource::<Events<$input_event>>() {
+                        output.extend(self.$input_field.read(events).cloned().map(RecordedEvent::from));
+                    }
+                )*
+                $(
+                    if let Some(events) = world.get_resource::<Events<$window_event>>() {
+                        output.extend(self.$window_field.read(events).cloned().map(RecordedEvent::from));
+                    }
+                )*
+            }
+        }
+    };
+}
+
+recorded_events! {
+    input: {
+        keyboard_input: KeyboardInput,
+        keyboard_focus_lost: KeyboardFocusLost,
+        mouse_button_input: MouseButtonInput,
+        mouse_motion: MouseMotion,
+        mouse_wheel: MouseWheel,
+        pinch_gesture: PinchGesture,
+        rotation_gesture: RotationGesture,
+        double_tap_gesture: DoubleTapGesture,
+        pan_gesture: PanGesture,
+        touch_input: TouchInput,
+        gamepad_event: GamepadEvent,
+        cursor_moved: CursorMoved,
+        cursor_entered: CursorEntered,
+        cursor_left: CursorLeft,
+        ime: Ime,
+        file_drag_and_drop: FileDragAndDrop,
+    },
+    window: {
+        window_resized: WindowResized,
+        window_moved: WindowMoved,
+        window_focused: WindowFocused,
+        window_occluded: WindowOccluded,
+        window_close_requested: WindowCloseRequested,
+        window_scale_factor_changed: WindowScaleFactorChanged,
+        window_backend_scale_factor_changed: WindowBackendScaleFactorChanged,
+    },
+}
+
+/// Resource recording the events of each frame, inserted by [`InputRecordingPlugin::record`].
+#[derive(Resource)]
+pub struct InputRecorder {
+    path: PathBuf,
+    file: Option<BufWriter<File>>,
+    recording: InputRecording,
+    saved_frames: usize,
+    cursors: RecordedEventCursors,
+    exit_cursor: EventCursor<AppExit>,
+    save_interval: Duration,
+    since_save: Duration,
+    is_recording: bool,
+}
+
+impl InputRecorder {
+    fn new(path: PathBuf, save_interval: Duration) -> Self {
+        Self {
+            path,
+            file: None,
+            recording: InputRecording::default(),
+            saved_frames: 0,
+            cursors: RecordedEventCursors::default(),
+            exit_cursor: EventCursor::default(),
+            save_interval,
+            since_save: Duration::ZERO,
+            is_recording: true,
+        }
+    }
+
+    /// Returns the path the recording is written to.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the frames recorded so far.
+    pub fn recording(&self) -> &InputRecording {
+        &self.recording
+    }
+
+    /// Appends the frames recorded since the last save to [`path`](Self::path).
+    ///
+    /// The file is created, or truncated, on the first save.
+    pub fn save(&mut self) -> io::Result<()> {
+        let file = match &mut self.file {
+            Some(file) => file,
+            None => self.file.insert(BufWriter::new(File::create(&self.path)?)),
+        };
+        write_frames(file, &self.recording.frames[self.saved_frames..])?;
+        file.flush()?;
+        self.saved_frames = self.recording.frames.len();
+        Ok(())
+    }
+
+    /// Returns `true` until the recorder is [stopped](Self::stop) or the app exits.
+    pub fn is_recording(&self) -> bool {
+        self.is_recording
+    }
+
+    /// Stops recording, and appends the remaining frames to [`path`](Self::path).
+    pub fn stop(&mut self) -> io::Result<()> {
+        self.is_recording = false;
+        self.save()
+    }
+}
+
+/// Resource replaying the frames of an [`InputRecording`], inserted by [`InputRecordingPlugin::replay`].
+#[derive(Resource)]
+pub struct InputReplayer {
+    recording: InputRecording,
+    frame: usize,
+}
+
+impl InputReplayer {
+    fn new(recording: InputRecording) -> Self {
+        Self {
+            recording,
+            frame: 0,
+        }
+    }
+
+    /// Returns the index of the next frame to replay.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Returns `true` once every recorded frame has been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.frame >= self.recording.frames.len()
+    }
+}
+
+fn record_events(world: &mut World) {
+    world.resource_scope(|world, mut recorder: Mut<InputRecorder>| {
+        if !recorder.is_recording {
+            return;
+        }
+        let recorder = &mut *recorder;
+        let delta = world
+            .get_resource::<Time<Real>>()
+            .map(Time::delta)
+            .unwrap_or_default();
+        let mut events = Vec::new();
+        recorder.cursors.read(world, &mut events);
+        recorder
+            .recording
+            .frames
+            .push(RecordedFrame { delta, events });
+
+        let exiting = world
+            .get_resource::<Events<AppExit>>()
+            .is_some_and(|exits| recorder.exit_cursor.read(exits).next().is_some());
+        recorder.since_save += delta;
+        if exiting {
+            match recorder.stop() {
+                Ok(()) => info!(
+                    "Recorded {} frames of input to {}",
+                    recorder.recording.frames.len(),
+                    recorder.path.display()
+                ),
+                Err(err) => warn!(
+                    "Failed to write input recording to {}: {err}",
+                    recorder.path.display()
+                ),
+            }
+        } else if recorder.since_save >= recorder.save_interval {
+            recorder.since_save = Duration::ZERO;
+            if let Err(err) = recorder.save() {
+                warn!(
+                    "Failed to write input recording to {}: {err}",
+                    recorder.path.display()
+                );
+            }
+        }
+    });
+}
+
+fn replay_events(world: &mut World) {
+    world.resource_scope(|world, mut replayer: Mut<InputReplayer>| {
+        let Some(frame) = replayer.recording.frames.get(replayer.frame).cloned() else {
+            return;
+        };
+        replayer.frame += 1;
+
+        world.insert_resource(TimeUpdateStrategy::ManualDuration(frame.delta));
+        RecordedEvent::clear_input(world);
+        for event in frame.events {
+            event.send(world);
+        }
+
+        if replayer.is_finished() {
+            info!("Finished replaying {} frames of input", replayer.frame);
+            world.insert_resource(TimeUpdateStrategy::Automatic);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_input::{keyboard::KeyCode, ButtonInput, ButtonState, InputPlugin};
+    use bevy_time::TimePlugin;
+
+    fn key(key_code: KeyCode, state: ButtonState) -> KeyboardInput {
+        KeyboardInput {
+            key_code,
+            logical_key: bevy_input::keyboard::Key::Unidentified(
+                bevy_input::keyboard::NativeKey::Unidentified,
+            ),
+            state,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        }
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let path =
+            std::env::temp_dir().join(format!("bevy_input_recording_{}.ron", std::process::id()));
+
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, InputPlugin, InputRecordingPlugin::record(&path)))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                16,
+            )));
+        app.update();
+        app.world_mut()
+            .send_event(key(KeyCode::Space, ButtonState::Pressed));
+        app.update();
+        app.world_mut()
+            .send_event(key(KeyCode::Space, ButtonState::Released));
+        app.world_mut().send_event(AppExit::Success);
+        app.update();
+
+        let recording = InputRecording::load(&path).unwrap();
+        assert_eq!(
+            &recording,
+            app.world().resource::<InputRecorder>().recording()
+        );
+        assert_eq!(recording.frames.len(), 3);
+        assert_eq!(recording.frames[1].delta, Duration::from_millis(16));
+        assert_eq!(
+            recording.frames[1].events,
+            vec![RecordedEvent::from(key(
+                KeyCode::Space,
+                ButtonState::Pressed
+            ))]
+        );
+
+        let mut replay = App::new();
+        replay
+            .add_plugins((TimePlugin, InputPlugin, InputRecordingPlugin::replay(&path)))
+            .add_event::<WindowCloseRequested>();
+        std::fs::remove_file(&path).unwrap();
+
+        replay.update();
+        // Live input is ignored while replaying, but live window events are kept.
+        replay
+            .world_mut()
+            .send_event(key(KeyCode::Enter, ButtonState::Pressed));
+        replay.world_mut().send_event(WindowCloseRequested {
+            window: Entity::PLACEHOLDER,
+        });
+        replay.update();
+        let keys = replay.world().resource::<ButtonInput<KeyCode>>();
+        assert!(keys.just_pressed(KeyCode::Space));
+        assert!(!keys.pressed(KeyCode::Enter));
+        assert!(!replay
+            .world()
+            .resource::<Events<WindowCloseRequested>>()
+            .is_empty());
+        assert_eq!(
+            replay.world().resource::<Time<Real>>().delta(),
+            Duration::from_millis(16)
+        );
+
+        replay.update();
+        let keys = replay.world().resource::<ButtonInput<KeyCode>>();
+        assert!(keys.just_released(KeyCode::Space));
+        assert!(replay.world().resource::<InputReplayer>().is_finished());
+        assert!(matches!(
+            replay.world().resource::<TimeUpdateStrategy>(),
+            TimeUpdateStrategy::Automatic
+        ));
+    }
+
+    #[test]
+    fn saves_periodically_and_on_stop() {
+        let path = std::env::temp_dir().join(format!(
+            "bevy_input_recording_periodic_{}.ron",
+            std::process::id()
+        ));
+
+        let mut app = App::new();
+        app.add_plugins((
+            TimePlugin,
+            InputPlugin,
+            InputRecordingPlugin::record(&path).with_save_interval(Duration::from_millis(32)),
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            16,
+        )));
+        // The first frame has no delta, so the interval elapses on the third one.
+        app.update();
+        app.update();
+        assert!(!path.exists());
+        app.update();
+        assert_eq!(InputRecording::load(&path).unwrap().frames.len(), 3);
+        assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 3);
+
+        app.update();
+        app.world_mut()
+            .resource_mut::<InputRecorder>()
+            .stop()
+            .unwrap();
+        assert_eq!(InputRecording::load(&path).unwrap().frames.len(), 4);
+
+        app.update();
+        let recorder = app.world().resource::<InputRecorder>();
+        assert!(!recorder.is_recording());
+        assert_eq!(recorder.recording().frames.len(), 4);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -14,6 +14,10 @@ use bevy_app::prelude::*;
 pub mod ci_testing;
 
 pub mod fps_overlay;
+#[cfg(feature = "bevy_input_recording")]
+pub mod input_recording;
+pub mod perf_hud;
+pub mod rewind;
 
 #[cfg(feature = "bevy_ui_debug")]
 pub mod ui_debug_overlay;
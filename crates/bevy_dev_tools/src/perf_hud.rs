@@ -0,0 +1,529 @@
+//! Module containing logic for the performance HUD.
+
+use bevy_app::{Plugin, Startup, Update};
+use bevy_asset::Handle;
+use bevy_color::Color;
+use bevy_diagnostic::{
+    Diagnostic, DiagnosticPath, DiagnosticsStore, FrameTimeDiagnosticsPlugin,
+    DEFAULT_MAX_HISTORY_LENGTH,
+};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    query::{Changed, With},
+    schedule::{common_conditions::resource_changed, IntoSystemConfigs},
+    system::{Commands, Query, Res, ResMut, Resource},
+};
+use bevy_hierarchy::{BuildChildren, ChildBuild, ChildBuilder, Children, DespawnRecursiveExt};
+use bevy_input::{keyboard::KeyCode, ButtonInput};
+use bevy_math::Vec2;
+use bevy_text::{Font, Text, TextStyle};
+use bevy_ui::{
+    node_bundles::{ButtonBundle, NodeBundle, TextBundle},
+    AlignItems, BackgroundColor, Display, FlexDirection, Interaction, PositionType, Style, UiRect,
+    Val, ZIndex,
+};
+use bevy_utils::default;
+
+/// Global [`ZIndex`] used to render the performance HUD.
+///
+/// This is just under the [`FPS_OVERLAY_ZINDEX`](crate::fps_overlay::FPS_OVERLAY_ZINDEX), so both can be used together.
+pub const PERF_HUD_ZINDEX: i32 = i32::MAX - 64;
+
+/// A plugin that adds a performance HUD to the Bevy application.
+///
+/// The HUD plots the recent history of the [`Diagnostic`]s selected in [`PerfHudConfig::graphs`],
+/// each with its [`DiagnosticStats`]. Pressing [`PerfHudConfig::panel_key`] opens a panel listing every
+/// registered diagnostic, where clicking one adds or removes its graph.
+///
+/// This plugin will add the [`FrameTimeDiagnosticsPlugin`] if it wasn't added before.
+/// Add other diagnostics plugins, such as the `ScheduleDiagnosticsPlugin` for system timings,
+/// to be able to plot their diagnostics.
+#[derive(Default)]
+pub struct PerfHudPlugin {
+    /// Starting configuration of the HUD, this can be later be changed through [`PerfHudConfig`] resource.
+    pub config: PerfHudConfig,
+}
+
+impl Plugin for PerfHudPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        // TODO: Use plugin dependencies, see https://github.com/bevyengine/bevy/issues/69
+        if !app.is_plugin_added::<FrameTimeDiagnosticsPlugin>() {
+            app.add_plugins(FrameTimeDiagnosticsPlugin);
+        }
+        app.insert_resource(self.config.clone())
+            .add_systems(Startup, setup)
+            .add_systems(
+                Update,
+                (
+                    toggle_panel,
+                    select_graphs,
+                    spawn_graphs.run_if(resource_changed::<PerfHudConfig>),
+                    update_graphs,
+                    update_panel,
+                )
+                    .chain(),
+            );
+    }
+}
+
+/// Configuration options for the performance HUD.
+#[derive(Resource, Clone)]
+pub struct PerfHudConfig {
+    /// The diagnostics plotted as graphs, from top to bottom.
+    pub graphs: Vec<DiagnosticPath>,
+    /// The key opening and closing the panel listing all diagnostics.
+    pub panel_key: KeyCode,
+    /// The size of each graph, in logical pixels.
+    pub graph_size: Vec2,
+    /// The number of most recent measurements plotted by each graph.
+    pub samples: usize,
+    /// The color of the graphs.
+    pub graph_color: Color,
+    /// Configuration of text in the HUD.
+    pub text_config: TextStyle,
+}
+
+impl Default for PerfHudConfig {
+    fn default() -> Self {
+        PerfHudConfig {
+            graphs: vec![FrameTimeDiagnosticsPlugin::FRAME_TIME],
+            panel_key: KeyCode::F2,
+            graph_size: Vec2::new(240.0, 48.0),
+            samples: DEFAULT_MAX_HISTORY_LENGTH,
+            graph_color: Color::srgb(0.3, 0.8, 0.4),
+            text_config: TextStyle {
+                font: Handle::<Font>::default(),
+                font_size: 14.0,
+                color: Color::WHITE,
+            },
+        }
+    }
+}
+
+/// Statistics of the values in the history of a [`Diagnostic`], as shown by the [`PerfHudPlugin`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiagnosticStats {
+    /// The smallest value.
+    pub min: f64,
+    /// The mean of the values.
+    pub avg: f64,
+    /// The largest value.
+    pub max: f64,
+    /// The 99th percentile, using the nearest-rank method.
+    pub p99: f64,
+}
+
+impl DiagnosticStats {
+    /// Computes the statistics of the finite `values`, or returns `None` if there are none.
+    pub fn from_values(values: impl IntoIterator<Item = f64>) -> Option<Self> {
+        let mut values: Vec<f64> = values.into_iter().filter(|v| v.is_finite()).collect();
+        if values.is_empty() {
+            return None;
+        }
+        values.sort_by(f64::total_cmp);
+        let rank = (values.len() as f64 * 0.99).ceil() as usize;
+        Some(Self {
+            min: values[0],
+            avg: values.iter().sum::<f64>() / values.len() as f64,
+            max: values[values.len() - 1],
+            p99: values[rank.saturating_sub(1)],
+        })
+    }
+
+    /// Computes the statistics of the history of `diagnostic`.
+    pub fn from_diagnostic(diagnostic: &Diagnostic) -> Option<Self> {
+        Self::from_values(diagnostic.values().copied())
+    }
+}
+
+#[derive(Component)]
+struct PerfHudGraphs;
+
+#[derive(Component)]
+struct PerfHudGraphText(DiagnosticPath);
+
+#[derive(Component)]
+struct PerfHudGraphBars(DiagnosticPath);
+
+#[derive(Component)]
+struct PerfHudBar;
+
+#[derive(Component)]
+struct PerfHudPanel;
+
+#[derive(Component)]
+struct PerfHudPanelRow(DiagnosticPath);
+
+#[derive(Component)]
+struct PerfHudPanelText(DiagnosticPath);
+
+fn setup(mut commands: Commands) {
+    commands
+        .spawn(NodeBundle {
+            style: Style {
+                // We need to make sure the HUD doesn't affect the position of other UI nodes
+                position_type: PositionType::Absolute,
+                top: Val::Px(0.0),
+                right: Val::Px(0.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(4.0)),
+                row_gap: Val::Px(4.0),
+                ..default()
+            },
+            background_color: Color::srgba(0.0, 0.0, 0.0, 0.6).into(),
+            // Render the HUD on top of everything
+            z_index: ZIndex::Global(PERF_HUD_ZINDEX),
+            ..default()
+        })
+        .with_children(|hud| {
+            hud.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Column,
+                        row_gap: Val::Px(4.0),
+                        ..default()
+                    },
+                    ..default()
+                },
+                PerfHudGraphs,
+            ));
+            hud.spawn((
+                NodeBundle {
+                    style: Style {
+                        display: Display::None,
+                        flex_direction: FlexDirection::Column,
+                        ..default()
+                    },
+                    ..default()
+                },
+                PerfHudPanel,
+            ));
+        });
+}
+
+fn spawn_graphs(
+    mut commands: Commands,
+    config: Res<PerfHudConfig>,
+    containers: Query<Entity, With<PerfHudGraphs>>,
+) {
+    for container in &containers {
+        commands
+            .entity(container)
+            .despawn_descendants()
+            .with_children(|graphs| {
+                for path in &config.graphs {
+                    spawn_graph(graphs, path, &config);
+                }
+            });
+    }
+}
+
+fn spawn_graph(graphs: &mut ChildBuilder, path: &DiagnosticPath, config: &PerfHudConfig) {
+    graphs
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|graph| {
+            graph.spawn((
+                TextBundle::from_section(path.to_string(), config.text_config.clone()),
+                PerfHudGraphText(path.clone()),
+            ));
+            graph
+                .spawn((
+                    NodeBundle {
+                        style: Style {
+                            width: Val::Px(config.graph_size.x),
+                            height: Val::Px(config.graph_size.y),
+                            align_items: AlignItems::FlexEnd,
+                            ..default()
+                        },
+                        background_color: Color::srgba(1.0, 1.0, 1.0, 0.1).into(),
+                        ..default()
+                    },
+                    PerfHudGraphBars(path.clone()),
+                ))
+                .with_children(|bars| {
+                    let width = 100.0 / config.samples.max(1) as f32;
+                    for _ in 0..config.samples {
+                        bars.spawn((
+                            NodeBundle {
+                                style: Style {
+                                    width: Val::Percent(width),
+                                    height: Val::Percent(0.0),
+                                    ..default()
+                                },
+                                background_color: config.graph_color.into(),
+                                ..default()
+                            },
+                            PerfHudBar,
+                        ));
+                    }
+                });
+        });
+}
+
+fn update_graphs(
+    diagnostics: Res<DiagnosticsStore>,
+    mut texts: Query<(&PerfHudGraphText, &mut Text)>,
+    graphs: Query<(&PerfHudGraphBars, &Children)>,
+    mut bars: Query<&mut Style, With<PerfHudBar>>,
+) {
+    // Only write changed values, so that unchanged text and bars aren't laid out again.
+    for (PerfHudGraphText(path), mut text) in &mut texts {
+        let label = match diagnostics.get(path) {
+            Some(diagnostic) => graph_label(diagnostic),
+            None => format!("{path}: not registered"),
+        };
+        if text.sections[0].value != label {
+            text.sections[0].value = label;
+        }
+    }
+
+    for (PerfHudGraphBars(path), children) in &graphs {
+        let values: Vec<f64> = diagnostics
+            .get(path)
+            .map(|diagnostic| diagnostic.values().copied().collect())
+            .unwrap_or_default();
+        // The most recent values are plotted, with the latest one on the right.
+        let values = &values[values.len().saturating_sub(children.len())..];
+        let offset = children.len() - values.len();
+        let max = values
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .fold(0.0, f64::max);
+
+        for (index, &child) in children.iter().enumerate() {
+            let Ok(mut style) = bars.get_mut(child) else {
+                continue;
+            };
+            let value = index.checked_sub(offset).map_or(0.0, |index| values[index]);
+            let height = Val::Percent(if max > 0.0 && value.is_finite() {
+                (value / max * 100.0).max(0.0) as f32
+            } else {
+                0.0
+            });
+            if style.height != height {
+                style.height = height;
+            }
+        }
+    }
+}
+
+fn graph_label(diagnostic: &Diagnostic) -> String {
+    let path = diagnostic.path();
+    let suffix = &diagnostic.suffix;
+    let Some(value) = diagnostic.value() else {
+        return format!("{path}: no measurements");
+    };
+    match DiagnosticStats::from_diagnostic(diagnostic) {
+        Some(DiagnosticStats { min, avg, max, p99 }) => format!(
+            "{path}: {value:.2}{suffix} (min {min:.2}, avg {avg:.2}, max {max:.2}, p99 {p99:.2})"
+        ),
+        None => format!("{path}: {value:.2}{suffix}"),
+    }
+}
+
+fn toggle_panel(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    config: Res<PerfHudConfig>,
+    diagnostics: Res<DiagnosticsStore>,
+    mut panels: Query<(Entity, &mut Style), With<PerfHudPanel>>,
+) {
+    if !keys.just_pressed(config.panel_key) {
+        return;
+    }
+    for (panel, mut style) in &mut panels {
+        if style.display != Display::None {
+            style.display = Display::None;
+            continue;
+        }
+        style.display = Display::Flex;
+
+        // List the diagnostics registered by now.
+        let mut paths: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.path().clone())
+            .collect();
+        paths.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        commands
+            .entity(panel)
+            .despawn_descendants()
+            .with_children(|rows| {
+                for path in paths {
+                    rows.spawn((
+                        ButtonBundle {
+                            background_color: BackgroundColor(Color::NONE),
+                            ..default()
+                        },
+                        PerfHudPanelRow(path.clone()),
+                    ))
+                    .with_children(|row| {
+                        row.spawn((
+                            TextBundle::from_section(path.to_string(), config.text_config.clone()),
+                            PerfHudPanelText(path),
+                        ));
+                    });
+                }
+            });
+    }
+}
+
+fn select_graphs(
+    mut config: ResMut<PerfHudConfig>,
+    rows: Query<(&Interaction, &PerfHudPanelRow), Changed<Interaction>>,
+) {
+    for (interaction, PerfHudPanelRow(path)) in &rows {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        if let Some(index) = config.graphs.iter().position(|graph| graph == path) {
+            config.graphs.remove(index);
+        } else {
+            config.graphs.push(path.clone());
+        }
+    }
+}
+
+fn update_panel(
+    config: Res<PerfHudConfig>,
+    diagnostics: Res<DiagnosticsStore>,
+    mut texts: Query<(&PerfHudPanelText, &mut Text)>,
+) {
+    for (PerfHudPanelText(path), mut text) in &mut texts {
+        let selected = if config.graphs.contains(path) {
+            "[x]"
+        } else {
+            "[ ]"
+        };
+        let value = diagnostics
+            .get(path)
+            .and_then(|diagnostic| {
+                let value = diagnostic.smoothed()?;
+                Some(format!("{value:.2}{}", diagnostic.suffix))
+            })
+            .unwrap_or_else(|| "-".to_string());
+        text.sections[0].value = format!("{selected} {path}: {value}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_core::FrameCount;
+    use bevy_diagnostic::{Diagnostic, DiagnosticPath, RegisterDiagnostic};
+    use bevy_ecs::{change_detection::DetectChanges, query::With, world::Ref};
+    use bevy_input::{keyboard::KeyCode, ButtonInput};
+    use bevy_time::{Real, Time};
+    use bevy_ui::Interaction;
+
+    use super::*;
+
+    const ENTITIES: DiagnosticPath = DiagnosticPath::const_new("entity_count");
+
+    #[test]
+    fn panel_selects_graphs() {
+        let mut app = App::new();
+        app.init_resource::<Time<Real>>()
+            .init_resource::<FrameCount>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_plugins(PerfHudPlugin::default())
+            .register_diagnostic(Diagnostic::new(ENTITIES));
+        app.update();
+        let bars = |app: &mut App| {
+            app.world_mut()
+                .query_filtered::<&PerfHudGraphBars, ()>()
+                .iter(app.world())
+                .map(|PerfHudGraphBars(path)| path.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(bars(&mut app), [FrameTimeDiagnosticsPlugin::FRAME_TIME]);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::F2);
+        app.update();
+        let row = app
+            .world_mut()
+            .query::<(Entity, &PerfHudPanelRow)>()
+            .iter(app.world())
+            .find(|(_, PerfHudPanelRow(path))| *path == ENTITIES)
+            .map(|(row, _)| row)
+            .unwrap();
+
+        app.world_mut().entity_mut(row).insert(Interaction::Pressed);
+        app.update();
+        assert_eq!(
+            app.world().resource::<PerfHudConfig>().graphs,
+            [FrameTimeDiagnosticsPlugin::FRAME_TIME, ENTITIES]
+        );
+        assert_eq!(bars(&mut app).len(), 2);
+        let panel_text = app
+            .world_mut()
+            .query_filtered::<&Text, With<PerfHudPanelText>>()
+            .iter(app.world())
+            .any(|text| text.sections[0].value == "[x] entity_count: -");
+        assert!(panel_text);
+    }
+
+    #[test]
+    fn unchanged_graphs_are_not_rewritten() {
+        let mut app = App::new();
+        app.init_resource::<Time<Real>>()
+            .init_resource::<FrameCount>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_plugins(PerfHudPlugin {
+                config: PerfHudConfig {
+                    graphs: vec![ENTITIES],
+                    ..Default::default()
+                },
+            })
+            .register_diagnostic(Diagnostic::new(ENTITIES));
+        app.update();
+        app.update();
+        let last_changed = |app: &mut App| {
+            let bars = app
+                .world_mut()
+                .query_filtered::<Ref<Style>, With<PerfHudBar>>()
+                .iter(app.world())
+                .map(|style| style.last_changed())
+                .collect::<Vec<_>>();
+            let texts = app
+                .world_mut()
+                .query_filtered::<Ref<Text>, With<PerfHudGraphText>>()
+                .iter(app.world())
+                .map(|text| text.last_changed())
+                .collect::<Vec<_>>();
+            (bars, texts)
+        };
+        let before = last_changed(&mut app);
+        assert!(!before.0.is_empty());
+
+        app.update();
+        assert_eq!(last_changed(&mut app), before);
+    }
+
+    #[test]
+    fn diagnostic_stats() {
+        assert_eq!(DiagnosticStats::from_values([]), None);
+        assert_eq!(DiagnosticStats::from_values([f64::NAN]), None);
+
+        let stats = DiagnosticStats::from_values((1..=200).rev().map(f64::from)).unwrap();
+        assert_eq!(stats.min, 1.0);
+        assert_eq!(stats.max, 200.0);
+        assert_eq!(stats.avg, 100.5);
+        assert_eq!(stats.p99, 198.0);
+
+        let stats = DiagnosticStats::from_values([4.0, f64::INFINITY, 2.0]).unwrap();
+        assert_eq!(
+            (stats.min, stats.avg, stats.max, stats.p99),
+            (2.0, 3.0, 4.0, 4.0)
+        );
+    }
+}
@@ -0,0 +1,543 @@
+//! Snapshots of selected components and resources taken every fixed tick, to rewind gameplay while debugging.
+//!
+//! [`Time<Virtual>`] can be paused and slowed down, but not reversed. The [`RewindPlugin`] records the
+//! reflected values of the components and resources registered with [`RewindAppExt`] at the end of each
+//! [`FixedUpdate`] tick into a bounded ring buffer, and the [`Rewind`] resource restores the world to any
+//! tick still in that buffer.
+//!
+//! ```no_run
+//! # use bevy_app::prelude::*;
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::reflect::ReflectComponent;
+//! # use bevy_reflect::Reflect;
+//! # use bevy_dev_tools::rewind::{Rewind, RewindAppExt, RewindPlugin};
+//! #[derive(Component, Reflect)]
+//! #[reflect(Component)]
+//! struct Velocity(f32);
+//!
+//! fn debug_controls(mut rewind: ResMut<Rewind>) {
+//!     // Bind these to keys or UI buttons.
+//!     rewind.step_back();
+//!     rewind.step_forward();
+//!     rewind.resume();
+//! }
+//!
+//! App::new()
+//!     .add_plugins(RewindPlugin::default())
+//!     .rewind_component::<Velocity>()
+//!     .add_systems(Update, debug_controls);
+//! ```
+
+use std::{any::TypeId, collections::VecDeque};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    archetype::ArchetypeEntity,
+    prelude::*,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    schedule::Stepping,
+};
+use bevy_reflect::{GetTypeRegistration, Reflect, TypeRegistry};
+use bevy_time::{Fixed, Time, TimeSystem, Virtual};
+use bevy_utils::{tracing::warn, HashSet};
+
+/// Adds the [`Rewind`] resource to an [`App`], which snapshots the tracked components and resources
+/// at the end of every [`FixedUpdate`] tick, in [`FixedLast`].
+///
+/// Use [`RewindAppExt`] to choose what is tracked.
+pub struct RewindPlugin {
+    /// The number of ticks kept in the ring buffer.
+    pub capacity: usize,
+}
+
+impl RewindPlugin {
+    /// The default number of ticks kept, 10 seconds at the default [`Time<Fixed>`] rate of 64 Hz.
+    pub const DEFAULT_CAPACITY: usize = 640;
+}
+
+impl Default for RewindPlugin {
+    fn default() -> Self {
+        Self {
+            capacity: Self::DEFAULT_CAPACITY,
+        }
+    }
+}
+
+impl Plugin for RewindPlugin {
+    fn build(&self, app: &mut App) {
+        app.world_mut()
+            .get_resource_or_insert_with(Rewind::default)
+            .set_capacity(self.capacity);
+        app.add_systems(FixedLast, record_snapshot).add_systems(
+            First,
+            (
+                apply_rewind_actions.before(TimeSystem),
+                step_forward.after(TimeSystem),
+            ),
+        );
+    }
+}
+
+/// Extension trait to select the components and resources snapshotted by the [`RewindPlugin`].
+pub trait RewindAppExt {
+    /// Tracks the component `C`, which must reflect [`Component`](ReflectComponent).
+    fn rewind_component<C: Component + GetTypeRegistration>(&mut self) -> &mut Self;
+
+    /// Tracks the resource `R`, which must reflect [`Resource`](ReflectResource).
+    fn rewind_resource<R: Resource + GetTypeRegistration>(&mut self) -> &mut Self;
+}
+
+impl RewindAppExt for App {
+    fn rewind_component<C: Component + GetTypeRegistration>(&mut self) -> &mut Self {
+        self.register_type::<C>();
+        let registry = self.world().resource::<AppTypeRegistry>().read();
+        assert!(
+            registry
+                .get_type_data::<ReflectComponent>(TypeId::of::<C>())
+                .is_some(),
+            "{} must reflect Component to be rewound",
+            C::get_type_registration().type_info().type_path()
+        );
+        drop(registry);
+        self.world_mut()
+            .get_resource_or_insert_with(Rewind::default)
+            .components
+            .push(TypeId::of::<C>());
+        self
+    }
+
+    fn rewind_resource<R: Resource + GetTypeRegistration>(&mut self) -> &mut Self {
+        self.register_type::<R>();
+        let registry = self.world().resource::<AppTypeRegistry>().read();
+        assert!(
+            registry
+                .get_type_data::<ReflectResource>(TypeId::of::<R>())
+                .is_some(),
+            "{} must reflect Resource to be rewound",
+            R::get_type_registration().type_info().type_path()
+        );
+        drop(registry);
+        self.world_mut()
+            .get_resource_or_insert_with(Rewind::default)
+            .resources
+            .push(TypeId::of::<R>());
+        self
+    }
+}
+
+/// Resource holding the recorded snapshots, and controlling pausing, rewinding and stepping.
+///
+/// Requests are applied at the start of the next frame, in [`First`] around [`TimeSystem`].
+/// While paused, [`Time<Virtual>`] is paused so that no [`FixedUpdate`] tick runs, and if a [`Stepping`]
+/// resource exists, stepping is enabled so that the systems of its schedules can be single-stepped too.
+///
+/// Restoring a tick applies the snapshotted values to the entities that still exist, removes tracked
+/// components the entities didn't have at that tick, and discards the snapshots of later ticks.
+/// Entities despawned since are not respawned, and entities spawned since are not despawned.
+#[derive(Resource)]
+pub struct Rewind {
+    capacity: usize,
+    components: Vec<TypeId>,
+    resources: Vec<TypeId>,
+    snapshots: VecDeque<WorldSnapshot>,
+    tick: u64,
+    paused: bool,
+    step_pending: bool,
+    step_next_frame: bool,
+    actions: Vec<RewindAction>,
+}
+
+impl Default for Rewind {
+    fn default() -> Self {
+        Self {
+            capacity: RewindPlugin::DEFAULT_CAPACITY,
+            components: Vec::new(),
+            resources: Vec::new(),
+            snapshots: VecDeque::new(),
+            tick: 0,
+            paused: false,
+            step_pending: false,
+            step_next_frame: false,
+            actions: Vec::new(),
+        }
+    }
+}
+
+struct WorldSnapshot {
+    tick: u64,
+    /// For each tracked component type, its value on each entity that had it.
+    components: Vec<Vec<(Entity, Box<dyn Reflect>)>>,
+    /// For each tracked resource type, its value if it existed.
+    resources: Vec<Option<Box<dyn Reflect>>>,
+}
+
+enum RewindAction {
+    Pause,
+    Resume,
+    RewindTo(u64),
+    StepBack,
+    StepForward,
+}
+
+impl Rewind {
+    /// Returns the maximum number of ticks kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Sets the maximum number of ticks kept, discarding the oldest snapshots if needed.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.snapshots.len() > capacity {
+            self.snapshots.pop_front();
+        }
+    }
+
+    /// Returns the number of fixed ticks recorded since startup, or the tick that was last restored.
+    pub fn current_tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Returns the oldest tick that can be restored.
+    pub fn oldest_tick(&self) -> Option<u64> {
+        self.snapshots.front().map(|snapshot| snapshot.tick)
+    }
+
+    /// Returns the latest tick that can be restored.
+    pub fn latest_tick(&self) -> Option<u64> {
+        self.snapshots.back().map(|snapshot| snapshot.tick)
+    }
+
+    /// Returns the number of snapshots kept.
+    pub fn len(&self) -> usize {
+        self.snapshots.len()
+    }
+
+    /// Returns `true` if no snapshot is kept.
+    pub fn is_empty(&self) -> bool {
+        self.snapshots.is_empty()
+    }
+
+    /// Returns `true` if the simulation is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Pauses the simulation.
+    pub fn pause(&mut self) {
+        self.actions.push(RewindAction::Pause);
+    }
+
+    /// Resumes the simulation from the current tick.
+    pub fn resume(&mut self) {
+        self.actions.push(RewindAction::Resume);
+    }
+
+    /// Pauses the simulation and restores the world to `tick`, if it is still recorded.
+    pub fn rewind_to(&mut self, tick: u64) {
+        self.actions.push(RewindAction::RewindTo(tick));
+    }
+
+    /// Pauses the simulation and restores the world to the tick before the current one.
+    pub fn step_back(&mut self) {
+        self.actions.push(RewindAction::StepBack);
+    }
+
+    /// Runs a single [`FixedUpdate`] tick, if the simulation is paused.
+    ///
+    /// If a [`Stepping`] resource exists, [`Stepping::step_frame`] is called too, and the tick runs
+    /// the frame after, once the step has been applied.
+    pub fn step_forward(&mut self) {
+        self.actions.push(RewindAction::StepForward);
+    }
+
+    fn record(&mut self, world: &World, registry: &TypeRegistry) {
+        self.tick += 1;
+        let components = self
+            .components
+            .iter()
+            .map(|&type_id| {
+                let reflect_component =
+                    registry.get_type_data::<ReflectComponent>(type_id).unwrap();
+                entities_with(world, type_id)
+                    .into_iter()
+                    .filter_map(|entity| {
+                        let value = reflect_component.reflect(world.entity(entity))?;
+                        Some((entity, value.clone_value()))
+                    })
+                    .collect()
+            })
+            .collect();
+        let resources = self
+            .resources
+            .iter()
+            .map(|&type_id| {
+                let reflect_resource = registry.get_type_data::<ReflectResource>(type_id).unwrap();
+                reflect_resource.reflect(world).map(Reflect::clone_value)
+            })
+            .collect();
+
+        if self.snapshots.len() >= self.capacity {
+            self.snapshots.pop_front();
+        }
+        if self.capacity > 0 {
+            self.snapshots.push_back(WorldSnapshot {
+                tick: self.tick,
+                components,
+                resources,
+            });
+        }
+    }
+
+    fn restore(&mut self, world: &mut World, registry: &TypeRegistry, tick: u64) -> bool {
+        let Some(index) = self
+            .snapshots
+            .iter()
+            .position(|snapshot| snapshot.tick == tick)
+        else {
+            return false;
+        };
+        self.snapshots.truncate(index + 1);
+        self.tick = tick;
+        let snapshot = &self.snapshots[index];
+
+        for (&type_id, values) in self.components.iter().zip(&snapshot.components) {
+            let reflect_component = registry.get_type_data::<ReflectComponent>(type_id).unwrap();
+            let recorded = values
+                .iter()
+                .map(|(entity, _)| *entity)
+                .collect::<HashSet<_>>();
+            for entity in entities_with(world, type_id) {
+                if !recorded.contains(&entity) {
+                    reflect_component.remove(&mut world.entity_mut(entity));
+                }
+            }
+            for (entity, value) in values {
+                if let Some(mut entity) = world.get_entity_mut(*entity) {
+                    reflect_component.apply_or_insert(&mut entity, &**value, registry);
+                }
+            }
+        }
+        for (&type_id, value) in self.resources.iter().zip(&snapshot.resources) {
+            let reflect_resource = registry.get_type_data::<ReflectResource>(type_id).unwrap();
+            match value {
+                Some(value) => reflect_resource.apply_or_insert(world, &**value, registry),
+                None => reflect_resource.remove(world),
+            }
+        }
+        true
+    }
+
+    fn set_paused(&mut self, world: &mut World, paused: bool) {
+        self.paused = paused;
+        let mut time = world.resource_mut::<Time<Virtual>>();
+        if paused {
+            time.pause();
+        } else {
+            time.unpause();
+        }
+        if let Some(mut stepping) = world.get_resource_mut::<Stepping>() {
+            if paused {
+                stepping.enable();
+            } else {
+                stepping.disable();
+            }
+        }
+    }
+}
+
+/// Returns the entities that have the component with the given [`TypeId`].
+fn entities_with(world: &World, type_id: TypeId) -> Vec<Entity> {
+    let Some(component_id) = world.components().get_id(type_id) else {
+        return Vec::new();
+    };
+    world
+        .archetypes()
+        .iter()
+        .filter(|archetype| archetype.contains(component_id))
+        .flat_map(|archetype| archetype.entities().iter().map(ArchetypeEntity::id))
+        .collect()
+}
+
+fn record_snapshot(world: &mut World) {
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    world.resource_scope(|world, mut rewind: Mut<Rewind>| rewind.record(world, &registry));
+}
+
+fn apply_rewind_actions(world: &mut World) {
+    let mut rewind = world.resource_mut::<Rewind>();
+    // `Stepping` applies the step requested last frame at the start of this one.
+    rewind.step_pending = std::mem::take(&mut rewind.step_next_frame);
+    if rewind.actions.is_empty() {
+        return;
+    }
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+    world.resource_scope(|world, mut rewind: Mut<Rewind>| {
+        for action in std::mem::take(&mut rewind.actions) {
+            match action {
+                RewindAction::Pause => rewind.set_paused(world, true),
+                RewindAction::Resume => rewind.set_paused(world, false),
+                RewindAction::RewindTo(tick) => {
+                    rewind.set_paused(world, true);
+                    if !rewind.restore(world, &registry, tick) {
+                        warn!("Cannot rewind to tick {tick}, it is not recorded");
+                    }
+                }
+                RewindAction::StepBack => {
+                    rewind.set_paused(world, true);
+                    let tick = rewind.tick.saturating_sub(1);
+                    if !rewind.restore(world, &registry, tick) {
+                        warn!("Cannot step back to tick {tick}, it is not recorded");
+                    }
+                }
+                RewindAction::StepForward if rewind.paused => {
+                    match world.get_resource_mut::<Stepping>() {
+                        Some(mut stepping) => {
+                            stepping.step_frame();
+                            rewind.step_next_frame = true;
+                        }
+                        None => rewind.step_pending = true,
+                    }
+                }
+                RewindAction::StepForward => {}
+            }
+        }
+    });
+}
+
+fn step_forward(world: &mut World) {
+    if !std::mem::take(&mut world.resource_mut::<Rewind>().step_pending) {
+        return;
+    }
+    // Advance the paused virtual clock by exactly what the fixed clock needs to run once.
+    let fixed = world.resource::<Time<Fixed>>();
+    let delta = fixed.timestep().saturating_sub(fixed.overstep());
+    let mut virt = world.resource_mut::<Time<Virtual>>();
+    virt.advance_by(delta);
+    let generic = virt.as_generic();
+    *world.resource_mut::<Time>() = generic;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::reflect::ReflectComponent;
+    use bevy_time::{TimePlugin, TimeUpdateStrategy};
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Position(i32);
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Boosted;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Ticks(u64);
+
+    fn simulate(
+        mut commands: Commands,
+        mut ticks: ResMut<Ticks>,
+        mut positions: Query<(Entity, &mut Position)>,
+    ) {
+        ticks.0 += 1;
+        for (entity, mut position) in &mut positions {
+            position.0 += 1;
+            if ticks.0 == 3 {
+                commands.entity(entity).insert(Boosted);
+            }
+        }
+    }
+
+    #[test]
+    fn rewind_and_step() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, RewindPlugin { capacity: 4 }))
+            .rewind_component::<Position>()
+            .rewind_component::<Boosted>()
+            .rewind_resource::<Ticks>()
+            .init_resource::<Ticks>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                Time::<Fixed>::default().timestep(),
+            ))
+            .add_systems(FixedUpdate, simulate);
+        let entity = app.world_mut().spawn(Position(0)).id();
+
+        while app.world().resource::<Ticks>().0 < 5 {
+            app.update();
+        }
+        let rewind = app.world().resource::<Rewind>();
+        assert_eq!(rewind.current_tick(), 5);
+        assert_eq!(rewind.len(), 4);
+        assert_eq!(rewind.oldest_tick(), Some(2));
+
+        app.world_mut().resource_mut::<Rewind>().rewind_to(2);
+        app.update();
+        assert!(app.world().resource::<Rewind>().is_paused());
+        assert!(app.world().resource::<Time<Virtual>>().is_paused());
+        assert_eq!(app.world().resource::<Ticks>().0, 2);
+        assert_eq!(app.world().get::<Position>(entity).unwrap().0, 2);
+        assert!(app.world().get::<Boosted>(entity).is_none());
+        assert_eq!(app.world().resource::<Rewind>().latest_tick(), Some(2));
+
+        // Nothing runs while paused.
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 2);
+
+        app.world_mut().resource_mut::<Rewind>().step_forward();
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 3);
+        assert_eq!(app.world().get::<Position>(entity).unwrap().0, 3);
+        assert!(app.world().get::<Boosted>(entity).is_some());
+        assert_eq!(app.world().resource::<Rewind>().current_tick(), 3);
+
+        app.world_mut().resource_mut::<Rewind>().step_back();
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 2);
+        assert!(app.world().get::<Boosted>(entity).is_none());
+
+        app.world_mut().resource_mut::<Rewind>().resume();
+        app.update();
+        app.update();
+        assert!(!app.world().resource::<Rewind>().is_paused());
+        assert!(app.world().resource::<Ticks>().0 > 2);
+    }
+
+    #[test]
+    fn step_forward_with_stepping() {
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, RewindPlugin::default()))
+            .rewind_resource::<Ticks>()
+            .init_resource::<Ticks>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                Time::<Fixed>::default().timestep(),
+            ))
+            .add_systems(FixedUpdate, simulate);
+        let mut stepping = Stepping::new();
+        stepping.add_schedule(FixedUpdate);
+        app.insert_resource(stepping);
+
+        while app.world().resource::<Ticks>().0 < 2 {
+            app.update();
+        }
+        app.world_mut().resource_mut::<Rewind>().pause();
+        app.update();
+        app.update();
+        assert!(app.world().resource::<Stepping>().is_enabled());
+        assert_eq!(app.world().resource::<Ticks>().0, 2);
+
+        app.world_mut().resource_mut::<Rewind>().step_forward();
+        // The step is requested from `Stepping`, which applies it the next frame.
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 2);
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 3);
+        assert_eq!(app.world().resource::<Rewind>().current_tick(), 3);
+
+        app.update();
+        assert_eq!(app.world().resource::<Ticks>().0, 3);
+    }
+}
@@ -78,6 +78,15 @@ impl DiagnosticPath {
     pub fn components(&self) -> impl Iterator<Item = &str> + '_ {
         self.path.split('/')
     }
+
+    /// Returns `true` if this path is `prefix` or one of its descendants.
+    ///
+    /// Whole components are compared, so `foo/bar` starts with `foo` but not with `fo`.
+    pub fn starts_with(&self, prefix: &DiagnosticPath) -> bool {
+        self.path
+            .strip_prefix(prefix.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    }
 }
 
 impl From<DiagnosticPath> for String {
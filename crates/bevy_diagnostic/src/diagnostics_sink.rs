@@ -0,0 +1,618 @@
+use std::{
+    fmt::Write as _,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::Path,
+};
+
+use bevy_app::{prelude::*, SubApp};
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time, Timer, TimerMode};
+use bevy_utils::{tracing::warn, Duration};
+
+use crate::{Diagnostic, DiagnosticPath, DiagnosticsStore};
+
+/// A destination for periodic snapshots of the [`DiagnosticsStore`], such as a file or a metrics endpoint.
+///
+/// Sinks are added to an App with [`AddDiagnosticsSink::add_diagnostics_sink`].
+/// Bevy provides [`CsvDiagnosticsSink`], [`JsonLinesDiagnosticsSink`] and, outside of Wasm,
+/// [`PrometheusDiagnosticsSink`].
+pub trait DiagnosticsSink: Send + Sync + 'static {
+    /// Writes a snapshot of the diagnostics.
+    ///
+    /// Errors are logged, and the sink keeps receiving the following snapshots.
+    fn write(&mut self, snapshot: &DiagnosticsSnapshot) -> io::Result<()>;
+}
+
+/// The enabled diagnostics of a [`DiagnosticsStore`] at a point in time, passed to [`DiagnosticsSink`]s.
+pub struct DiagnosticsSnapshot<'a> {
+    /// The time since the app started, according to [`Time<Real>`].
+    pub elapsed: Duration,
+    /// The diagnostics matching the sink's filter, sorted by path.
+    pub diagnostics: Vec<&'a Diagnostic>,
+}
+
+/// Configures how often a [`DiagnosticsSink`] receives snapshots, and of which diagnostics.
+#[derive(Debug, Clone)]
+pub struct DiagnosticsSinkSettings {
+    /// The time between snapshots.
+    pub wait_duration: Duration,
+    /// If set, only diagnostics whose path starts with one of these prefixes are included.
+    ///
+    /// Prefixes match whole path components, so `systems/Update` matches `systems/Update/my_system`
+    /// but not `systems/UpdateUi`.
+    pub filter: Option<Vec<DiagnosticPath>>,
+}
+
+impl Default for DiagnosticsSinkSettings {
+    fn default() -> Self {
+        Self {
+            wait_duration: Duration::from_secs(1),
+            filter: None,
+        }
+    }
+}
+
+impl DiagnosticsSinkSettings {
+    /// Creates settings sending a snapshot every `wait_duration`.
+    pub fn every(wait_duration: Duration) -> Self {
+        Self {
+            wait_duration,
+            ..Default::default()
+        }
+    }
+
+    /// Only includes diagnostics whose path starts with one of `prefixes`.
+    pub fn with_filter(mut self, prefixes: Vec<DiagnosticPath>) -> Self {
+        self.filter = Some(prefixes);
+        self
+    }
+}
+
+struct SinkEntry {
+    sink: Box<dyn DiagnosticsSink>,
+    timer: Timer,
+    filter: Option<Vec<DiagnosticPath>>,
+}
+
+/// The [`DiagnosticsSink`]s of an App, with their settings.
+#[derive(Resource, Default)]
+pub struct DiagnosticsSinks {
+    sinks: Vec<SinkEntry>,
+}
+
+impl DiagnosticsSinks {
+    /// Adds a sink.
+    ///
+    /// If possible, prefer calling [`App::add_diagnostics_sink`](AddDiagnosticsSink::add_diagnostics_sink).
+    pub fn add(&mut self, sink: impl DiagnosticsSink, settings: DiagnosticsSinkSettings) {
+        self.sinks.push(SinkEntry {
+            sink: Box::new(sink),
+            timer: Timer::new(settings.wait_duration, TimerMode::Repeating),
+            filter: settings.filter,
+        });
+    }
+
+    /// Returns the number of sinks.
+    pub fn len(&self) -> usize {
+        self.sinks.len()
+    }
+
+    /// Returns `true` if there are no sinks.
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+
+    /// Sends a snapshot to each sink whose wait duration has elapsed.
+    pub fn write_system(
+        mut sinks: ResMut<Self>,
+        time: Res<Time<Real>>,
+        diagnostics: Res<DiagnosticsStore>,
+    ) {
+        for entry in &mut sinks.sinks {
+            if !entry.timer.tick(time.delta()).finished() {
+                continue;
+            }
+            let mut snapshot = DiagnosticsSnapshot {
+                elapsed: time.elapsed(),
+                diagnostics: diagnostics
+                    .iter()
+                    .filter(|diagnostic| diagnostic.is_enabled)
+                    .filter(|diagnostic| match &entry.filter {
+                        Some(prefixes) => prefixes
+                            .iter()
+                            .any(|prefix| diagnostic.path().starts_with(prefix)),
+                        None => true,
+                    })
+                    .collect(),
+            };
+            snapshot
+                .diagnostics
+                .sort_by(|a, b| a.path().as_str().cmp(b.path().as_str()));
+            if let Err(error) = entry.sink.write(&snapshot) {
+                warn!("Failed to write diagnostics snapshot: {error}");
+            }
+        }
+    }
+}
+
+/// Extend [`App`] with new `add_diagnostics_sink` function.
+pub trait AddDiagnosticsSink {
+    /// Adds a [`DiagnosticsSink`] receiving snapshots of the [`DiagnosticsStore`] as configured by `settings`.
+    ///
+    /// ```no_run
+    /// use bevy_app::App;
+    /// use bevy_diagnostic::{
+    ///     AddDiagnosticsSink, CsvDiagnosticsSink, DiagnosticPath, DiagnosticsSinkSettings,
+    ///     FrameTimeDiagnosticsPlugin,
+    /// };
+    /// use bevy_utils::Duration;
+    ///
+    /// App::new()
+    ///     .add_plugins(FrameTimeDiagnosticsPlugin)
+    ///     .add_diagnostics_sink(
+    ///         CsvDiagnosticsSink::create("diagnostics.csv").unwrap(),
+    ///         DiagnosticsSinkSettings::every(Duration::from_millis(100))
+    ///             .with_filter(vec![FrameTimeDiagnosticsPlugin::FRAME_TIME]),
+    ///     )
+    ///     .run();
+    /// ```
+    fn add_diagnostics_sink(
+        &mut self,
+        sink: impl DiagnosticsSink,
+        settings: DiagnosticsSinkSettings,
+    ) -> &mut Self;
+}
+
+impl AddDiagnosticsSink for SubApp {
+    fn add_diagnostics_sink(
+        &mut self,
+        sink: impl DiagnosticsSink,
+        settings: DiagnosticsSinkSettings,
+    ) -> &mut Self {
+        if !self.world().contains_resource::<DiagnosticsSinks>() {
+            self.init_resource::<DiagnosticsStore>()
+                .init_resource::<DiagnosticsSinks>()
+                .add_systems(PostUpdate, DiagnosticsSinks::write_system);
+        }
+        self.world_mut()
+            .resource_mut::<DiagnosticsSinks>()
+            .add(sink, settings);
+        self
+    }
+}
+
+impl AddDiagnosticsSink for App {
+    fn add_diagnostics_sink(
+        &mut self,
+        sink: impl DiagnosticsSink,
+        settings: DiagnosticsSinkSettings,
+    ) -> &mut Self {
+        SubApp::add_diagnostics_sink(self.main_mut(), sink, settings);
+        self
+    }
+}
+
+/// A [`DiagnosticsSink`] writing one CSV row per diagnostic and snapshot, with the columns
+/// `time,path,value,average,suffix`.
+///
+/// `time` is in seconds since the app started, and missing values are left empty.
+pub struct CsvDiagnosticsSink<W: Write + Send + Sync + 'static = BufWriter<File>> {
+    writer: W,
+    wrote_header: bool,
+}
+
+impl CsvDiagnosticsSink {
+    /// Creates a sink writing to a new file at `path`, truncating it if it exists.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write + Send + Sync + 'static> CsvDiagnosticsSink<W> {
+    /// Creates a sink writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            wrote_header: false,
+        }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write + Send + Sync + 'static> DiagnosticsSink for CsvDiagnosticsSink<W> {
+    fn write(&mut self, snapshot: &DiagnosticsSnapshot) -> io::Result<()> {
+        if !self.wrote_header {
+            writeln!(self.writer, "time,path,value,average,suffix")?;
+            self.wrote_header = true;
+        }
+        let time = snapshot.elapsed.as_secs_f64();
+        for diagnostic in &snapshot.diagnostics {
+            writeln!(
+                self.writer,
+                "{time},{},{},{},{}",
+                csv_field(diagnostic.path().as_str()),
+                optional_number(diagnostic.value()),
+                optional_number(diagnostic.average()),
+                csv_field(&diagnostic.suffix),
+            )?;
+        }
+        self.writer.flush()
+    }
+}
+
+/// A [`DiagnosticsSink`] writing one JSON object per snapshot and line, such as
+/// `{"time":1.5,"diagnostics":{"fps":{"value":60.0,"average":59.5,"suffix":""}}}`.
+///
+/// `time` is in seconds since the app started, and missing or non-finite values are `null`.
+pub struct JsonLinesDiagnosticsSink<W: Write + Send + Sync + 'static = BufWriter<File>> {
+    writer: W,
+}
+
+impl JsonLinesDiagnosticsSink {
+    /// Creates a sink writing to a new file at `path`, truncating it if it exists.
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::new(BufWriter::new(File::create(path)?)))
+    }
+}
+
+impl<W: Write + Send + Sync + 'static> JsonLinesDiagnosticsSink<W> {
+    /// Creates a sink writing to `writer`.
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    /// Returns the underlying writer.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write + Send + Sync + 'static> DiagnosticsSink for JsonLinesDiagnosticsSink<W> {
+    fn write(&mut self, snapshot: &DiagnosticsSnapshot) -> io::Result<()> {
+        let mut line = format!(
+            "{{\"time\":{},\"diagnostics\":{{",
+            json_number(Some(snapshot.elapsed.as_secs_f64()))
+        );
+        for (i, diagnostic) in snapshot.diagnostics.iter().enumerate() {
+            if i > 0 {
+                line.push(',');
+            }
+            let _ = write!(
+                line,
+                "{}:{{\"value\":{},\"average\":{},\"suffix\":{}}}",
+                json_string(diagnostic.path().as_str()),
+                json_number(diagnostic.value()),
+                json_number(diagnostic.average()),
+                json_string(&diagnostic.suffix),
+            );
+        }
+        line.push_str("}}");
+        writeln!(self.writer, "{line}")?;
+        self.writer.flush()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use prometheus::PrometheusDiagnosticsSink;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod prometheus {
+    use std::{
+        fmt::Write as _,
+        io::{self, Read, Write},
+        net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs},
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc, Mutex, PoisonError,
+        },
+        thread::JoinHandle,
+    };
+
+    use bevy_utils::{tracing::debug, Duration};
+
+    use super::{optional_number, DiagnosticsSink, DiagnosticsSnapshot};
+    use crate::Diagnostic;
+
+    /// How long the server waits on a client before moving on to the next one.
+    const CLIENT_TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// A [`DiagnosticsSink`] serving the latest snapshot over HTTP in the
+    /// [Prometheus text exposition format](https://prometheus.io/docs/instrumenting/exposition_formats/).
+    ///
+    /// Every diagnostic is a sample of the `bevy_diagnostic` and `bevy_diagnostic_average` gauges,
+    /// labeled with its `path`. Any request to the bound address gets the metrics, which are empty
+    /// until the first snapshot is written.
+    ///
+    /// The server runs on its own thread until the sink is dropped. Clients are served one at a time,
+    /// and a client that doesn't send its request or read the response within a second is answered
+    /// or dropped, so it can't stall the server.
+    pub struct PrometheusDiagnosticsSink {
+        metrics: Arc<Mutex<String>>,
+        address: SocketAddr,
+        stop: Arc<AtomicBool>,
+        handle: Option<JoinHandle<()>>,
+    }
+
+    impl PrometheusDiagnosticsSink {
+        /// Starts serving metrics on `address`, such as `127.0.0.1:9091`.
+        ///
+        /// Use port 0 to let the OS pick a free port, and [`local_addr`](Self::local_addr) to get it.
+        pub fn bind(address: impl ToSocketAddrs) -> io::Result<Self> {
+            let listener = TcpListener::bind(address)?;
+            let address = listener.local_addr()?;
+            let metrics = Arc::new(Mutex::new(String::new()));
+            let stop = Arc::new(AtomicBool::new(false));
+
+            let thread_metrics = metrics.clone();
+            let thread_stop = stop.clone();
+            let handle = std::thread::Builder::new()
+                .name("prometheus diagnostics".to_string())
+                .spawn(move || {
+                    for stream in listener.incoming() {
+                        if thread_stop.load(Ordering::Acquire) {
+                            break;
+                        }
+                        let result = stream.and_then(|stream| {
+                            let body = thread_metrics
+                                .lock()
+                                .unwrap_or_else(PoisonError::into_inner)
+                                .clone();
+                            respond(stream, &body)
+                        });
+                        if let Err(error) = result {
+                            debug!("Failed to serve diagnostics: {error}");
+                        }
+                    }
+                })?;
+
+            Ok(Self {
+                metrics,
+                address,
+                stop,
+                handle: Some(handle),
+            })
+        }
+
+        /// Returns the address metrics are served on.
+        pub fn local_addr(&self) -> SocketAddr {
+            self.address
+        }
+    }
+
+    impl DiagnosticsSink for PrometheusDiagnosticsSink {
+        fn write(&mut self, snapshot: &DiagnosticsSnapshot) -> io::Result<()> {
+            let mut metrics = String::new();
+            write_gauge(
+                &mut metrics,
+                "bevy_diagnostic",
+                "Latest value of a Bevy diagnostic.",
+                snapshot,
+                Diagnostic::value,
+            );
+            write_gauge(
+                &mut metrics,
+                "bevy_diagnostic_average",
+                "Average of the recent values of a Bevy diagnostic.",
+                snapshot,
+                Diagnostic::average,
+            );
+            *self.metrics.lock().unwrap_or_else(PoisonError::into_inner) = metrics;
+            Ok(())
+        }
+    }
+
+    impl Drop for PrometheusDiagnosticsSink {
+        fn drop(&mut self) {
+            self.stop.store(true, Ordering::Release);
+            // Wake the server up so it notices it should stop.
+            let _ = TcpStream::connect_timeout(&self.address, CLIENT_TIMEOUT);
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+
+    fn write_gauge(
+        metrics: &mut String,
+        name: &str,
+        help: &str,
+        snapshot: &DiagnosticsSnapshot,
+        value: impl Fn(&Diagnostic) -> Option<f64>,
+    ) {
+        let _ = writeln!(metrics, "# HELP {name} {help}");
+        let _ = writeln!(metrics, "# TYPE {name} gauge");
+        for diagnostic in &snapshot.diagnostics {
+            if let Some(value) = value(diagnostic) {
+                let _ = writeln!(
+                    metrics,
+                    "{name}{{path=\"{}\"}} {}",
+                    label_value(diagnostic.path().as_str()),
+                    prometheus_number(value),
+                );
+            }
+        }
+    }
+
+    fn respond(mut stream: TcpStream, body: &str) -> io::Result<()> {
+        stream.set_read_timeout(Some(CLIENT_TIMEOUT))?;
+        stream.set_write_timeout(Some(CLIENT_TIMEOUT))?;
+        // The request doesn't matter, but read it so the client doesn't see a reset connection.
+        // A client that doesn't send it in time gets the metrics anyway.
+        let mut request = [0; 1024];
+        let _ = stream.read(&mut request);
+        write!(
+            stream,
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )?;
+        stream.flush()
+    }
+
+    fn label_value(value: &str) -> String {
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+    }
+
+    fn prometheus_number(value: f64) -> String {
+        if value.is_nan() {
+            "NaN".to_string()
+        } else if value.is_infinite() {
+            if value > 0.0 { "+Inf" } else { "-Inf" }.to_string()
+        } else {
+            optional_number(Some(value))
+        }
+    }
+}
+
+fn optional_number(value: Option<f64>) -> String {
+    value.map(|value| value.to_string()).unwrap_or_default()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn json_number(value: Option<f64>) -> String {
+    match value {
+        Some(value) if value.is_finite() => format!("{value:?}"),
+        _ => "null".to_string(),
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_utils::Instant;
+
+    use super::*;
+    use crate::DiagnosticMeasurement;
+
+    fn diagnostics() -> Vec<Diagnostic> {
+        let mut fps = Diagnostic::new(DiagnosticPath::const_new("fps"));
+        let mut frame_time =
+            Diagnostic::new(DiagnosticPath::const_new("frame_time")).with_suffix("ms");
+        for (diagnostic, values) in [(&mut fps, [60.0, 30.0]), (&mut frame_time, [16.0, 32.0])] {
+            for value in values {
+                diagnostic.add_measurement(DiagnosticMeasurement {
+                    time: Instant::now(),
+                    value,
+                });
+            }
+        }
+        let empty = Diagnostic::new(DiagnosticPath::const_new("a,b"));
+        vec![empty, fps, frame_time]
+    }
+
+    fn write(sink: &mut impl DiagnosticsSink, diagnostics: &[Diagnostic]) {
+        let snapshot = DiagnosticsSnapshot {
+            elapsed: Duration::from_millis(1500),
+            diagnostics: diagnostics.iter().collect(),
+        };
+        sink.write(&snapshot).unwrap();
+    }
+
+    #[test]
+    fn csv_sink() {
+        let mut sink = CsvDiagnosticsSink::new(Vec::new());
+        let diagnostics = diagnostics();
+        write(&mut sink, &diagnostics);
+        write(&mut sink, &diagnostics[1..2]);
+        assert_eq!(
+            String::from_utf8(sink.into_inner()).unwrap(),
+            "time,path,value,average,suffix\n\
+             1.5,\"a,b\",,,\n\
+             1.5,fps,30,45,\n\
+             1.5,frame_time,32,24,ms\n\
+             1.5,fps,30,45,\n"
+        );
+    }
+
+    #[test]
+    fn json_lines_sink() {
+        let mut sink = JsonLinesDiagnosticsSink::new(Vec::new());
+        write(&mut sink, &diagnostics()[..2]);
+        assert_eq!(
+            String::from_utf8(sink.into_inner()).unwrap(),
+            "{\"time\":1.5,\"diagnostics\":{\
+             \"a,b\":{\"value\":null,\"average\":null,\"suffix\":\"\"},\
+             \"fps\":{\"value\":30.0,\"average\":45.0,\"suffix\":\"\"}}}\n"
+        );
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn prometheus_sink() {
+        use std::{io::Read, net::TcpStream};
+
+        let mut sink = PrometheusDiagnosticsSink::bind("127.0.0.1:0").unwrap();
+        write(&mut sink, &diagnostics());
+
+        let mut stream = TcpStream::connect(sink.local_addr()).unwrap();
+        stream.write_all(b"GET /metrics HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("bevy_diagnostic{path=\"fps\"} 30\n"));
+        assert!(response.contains("bevy_diagnostic_average{path=\"frame_time\"} 24\n"));
+        assert!(!response.contains("a,b"));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn prometheus_sink_with_idle_client() {
+        use std::{io::Read, net::TcpStream};
+
+        let sink = PrometheusDiagnosticsSink::bind("127.0.0.1:0").unwrap();
+        // Connect without sending a request.
+        let _idle = TcpStream::connect(sink.local_addr()).unwrap();
+
+        let mut stream = TcpStream::connect(sink.local_addr()).unwrap();
+        stream.write_all(b"GET /metrics HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+
+        // Dropping the sink while a client is idle doesn't wait on it forever.
+        let _idle = TcpStream::connect(sink.local_addr()).unwrap();
+        drop(sink);
+    }
+
+    #[test]
+    fn filters_by_path_prefix() {
+        assert!(DiagnosticPath::new("systems/Update/foo")
+            .starts_with(&DiagnosticPath::new("systems/Update")));
+        assert!(DiagnosticPath::new("fps").starts_with(&DiagnosticPath::new("fps")));
+        assert!(!DiagnosticPath::new("systems/UpdateUi/foo")
+            .starts_with(&DiagnosticPath::new("systems/Update")));
+    }
+}
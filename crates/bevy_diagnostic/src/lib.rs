@@ -12,19 +12,25 @@
 //! their ability to monitor and optimize their game's.
 
 mod diagnostic;
+mod diagnostics_sink;
 mod entity_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
+mod schedule_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
+mod task_pool_diagnostics_plugin;
 
 pub use diagnostic::*;
+pub use diagnostics_sink::*;
 
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
+pub use schedule_diagnostics_plugin::ScheduleDiagnosticsPlugin;
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
+pub use task_pool_diagnostics_plugin::TaskPoolDiagnosticsPlugin;
 
 use bevy_app::prelude::*;
 
@@ -0,0 +1,163 @@
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{InternedScheduleLabel, ScheduleLabel, ScheduleStats},
+};
+use bevy_utils::{get_short_name, Duration, HashMap, Instant};
+
+use crate::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+
+/// Adds per-schedule and per-system diagnostics to an App, measured by the schedule executors.
+///
+/// Every frame, for each schedule that ran:
+/// - `schedules/<schedule>` is the time spent running it, in milliseconds,
+///   and `schedules/<schedule>/runs` the number of times it ran.
+/// - `systems/<schedule>/<system>` is the time spent running each system, in milliseconds,
+///   `systems/<schedule>/<system>/runs` the number of times it ran, and
+///   `systems/<schedule>/<system>/skipped` the number of times its run conditions prevented it from running.
+///
+/// Systems are named by their [short name](get_short_name), and systems of a schedule with the same
+/// short name, such as the [`apply_deferred`](bevy_ecs::schedule::apply_deferred) sync points, are measured together.
+/// Use [`schedule_path`](Self::schedule_path) and [`system_path`](Self::system_path) to look them up.
+///
+/// Diagnostics are registered the first time their schedule or system runs. Measurements of the
+/// [`Last`] schedule, and of schedules running it like [`Main`], are reported the next frame.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct ScheduleDiagnosticsPlugin;
+
+impl Plugin for ScheduleDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<DiagnosticsStore>()
+            .init_resource::<ScheduleStats>()
+            .add_systems(Last, Self::diagnostic_system);
+    }
+}
+
+impl ScheduleDiagnosticsPlugin {
+    pub const SCHEDULES: DiagnosticPath = DiagnosticPath::const_new("schedules");
+    pub const SYSTEMS: DiagnosticPath = DiagnosticPath::const_new("systems");
+
+    /// Returns the path of the run time diagnostic of a schedule.
+    pub fn schedule_path(schedule: impl ScheduleLabel) -> DiagnosticPath {
+        Self::schedule_path_interned(schedule.intern())
+    }
+
+    /// Returns the path of the run time diagnostic of a system, by its short name.
+    pub fn system_path(schedule: impl ScheduleLabel, system: &str) -> DiagnosticPath {
+        Self::system_path_interned(schedule.intern(), system)
+    }
+
+    fn schedule_path_interned(schedule: InternedScheduleLabel) -> DiagnosticPath {
+        DiagnosticPath::new(format!("{}/{schedule:?}", Self::SCHEDULES))
+    }
+
+    fn system_path_interned(schedule: InternedScheduleLabel, system: &str) -> DiagnosticPath {
+        DiagnosticPath::new(format!("{}/{schedule:?}/{system}", Self::SYSTEMS))
+    }
+
+    pub fn diagnostic_system(
+        mut store: ResMut<DiagnosticsStore>,
+        mut stats: ResMut<ScheduleStats>,
+    ) {
+        let time = Instant::now();
+        for (label, schedule) in stats.iter() {
+            let path = Self::schedule_path_interned(label);
+            add_measurement(&mut store, &path, "ms", time, millis(schedule.run_time));
+            let runs = DiagnosticPath::new(format!("{path}/runs"));
+            add_measurement(&mut store, &runs, "", time, schedule.run_count as f64);
+
+            let mut systems = HashMap::<_, (Duration, u32, u32)>::default();
+            for system in &schedule.systems {
+                let (run_time, run_count, skipped_count) =
+                    systems.entry(get_short_name(&system.name)).or_default();
+                *run_time += system.run_time;
+                *run_count += system.run_count;
+                *skipped_count += system.skipped_count;
+            }
+            for (name, (run_time, run_count, skipped_count)) in systems {
+                let path = Self::system_path_interned(label, &name);
+                add_measurement(&mut store, &path, "ms", time, millis(run_time));
+                let runs = DiagnosticPath::new(format!("{path}/runs"));
+                add_measurement(&mut store, &runs, "", time, run_count as f64);
+                let skipped = DiagnosticPath::new(format!("{path}/skipped"));
+                add_measurement(&mut store, &skipped, "", time, skipped_count as f64);
+            }
+        }
+        stats.clear();
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Adds a measurement to the diagnostic at `path` if it is enabled, registering it first if needed.
+fn add_measurement(
+    store: &mut DiagnosticsStore,
+    path: &DiagnosticPath,
+    suffix: &'static str,
+    time: Instant,
+    value: f64,
+) {
+    if store.get(path).is_none() {
+        store.add(Diagnostic::new(path.clone()).with_suffix(suffix));
+    }
+    if let Some(diagnostic) = store
+        .get_mut(path)
+        .filter(|diagnostic| diagnostic.is_enabled)
+    {
+        diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn busy() {
+        std::thread::sleep(Duration::from_millis(1));
+    }
+
+    fn never_runs() {}
+
+    #[test]
+    fn measures_systems_of_schedules() {
+        let mut app = App::new();
+        app.add_plugins(ScheduleDiagnosticsPlugin)
+            .add_systems(Update, (busy, never_runs.run_if(|| false)));
+        app.update();
+        app.update();
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        let value = |path: DiagnosticPath| {
+            store
+                .get(&path)
+                .unwrap_or_else(|| panic!("`{path}` is not registered"))
+                .value()
+                .unwrap_or_else(|| panic!("`{path}` is not measured"))
+        };
+
+        let busy = ScheduleDiagnosticsPlugin::system_path(Update, "busy");
+        assert!(value(busy.clone()) >= 1.0);
+        assert_eq!(value(DiagnosticPath::new(format!("{busy}/runs"))), 1.0);
+        assert_eq!(value(DiagnosticPath::new(format!("{busy}/skipped"))), 0.0);
+
+        let never_runs = ScheduleDiagnosticsPlugin::system_path(Update, "never_runs");
+        assert_eq!(
+            value(DiagnosticPath::new(format!("{never_runs}/runs"))),
+            0.0
+        );
+        assert_eq!(
+            value(DiagnosticPath::new(format!("{never_runs}/skipped"))),
+            1.0
+        );
+
+        let update = ScheduleDiagnosticsPlugin::schedule_path(Update);
+        assert!(value(update.clone()) >= 1.0);
+        assert_eq!(value(DiagnosticPath::new(format!("{update}/runs"))), 1.0);
+    }
+}
@@ -0,0 +1,91 @@
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_tasks::{AsyncComputeTaskPool, ComputeTaskPool, IoTaskPool, TaskPoolStats};
+use bevy_utils::Instant;
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds diagnostics of the tasks of the [`ComputeTaskPool`], [`AsyncComputeTaskPool`] and [`IoTaskPool`] to an App.
+///
+/// Every frame, for each pool named `compute`, `async_compute` or `io`:
+/// - `task_pools/<pool>/queued` is the number of tasks waiting to start running,
+/// - `task_pools/<pool>/running` the number of started tasks that haven't completed yet,
+/// - `task_pools/<pool>/completed` the number of tasks that completed since the previous frame,
+/// - `task_pools/<pool>/utilization` the percentage of the time the threads of the pool spent running
+///   tasks since the previous frame.
+///
+/// Use [`path`](Self::path) to look them up. Pools that aren't initialized are skipped.
+///
+/// Pools only count their tasks if built with [`TaskPoolBuilder::track_stats`], which the default pools
+/// are when [`TaskPoolOptions::track_stats`] is set. Otherwise, like for the single threaded task pool,
+/// all their diagnostics stay at zero.
+///
+/// [`TaskPoolBuilder::track_stats`]: bevy_tasks::TaskPoolBuilder::track_stats
+/// [`TaskPoolOptions::track_stats`]: bevy_core::TaskPoolOptions::track_stats
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+#[derive(Default)]
+pub struct TaskPoolDiagnosticsPlugin;
+
+impl Plugin for TaskPoolDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        for pool in Self::POOLS {
+            app.register_diagnostic(Diagnostic::new(Self::path(pool, "queued")))
+                .register_diagnostic(Diagnostic::new(Self::path(pool, "running")))
+                .register_diagnostic(Diagnostic::new(Self::path(pool, "completed")))
+                .register_diagnostic(
+                    Diagnostic::new(Self::path(pool, "utilization")).with_suffix("%"),
+                );
+        }
+        app.add_systems(Update, Self::diagnostic_system);
+    }
+}
+
+impl TaskPoolDiagnosticsPlugin {
+    pub const TASK_POOLS: DiagnosticPath = DiagnosticPath::const_new("task_pools");
+
+    const POOLS: [&'static str; 3] = ["compute", "async_compute", "io"];
+
+    /// Returns the path of the diagnostic `stat` of the pool named `pool`.
+    ///
+    /// ```
+    /// # use bevy_diagnostic::TaskPoolDiagnosticsPlugin;
+    /// let path = TaskPoolDiagnosticsPlugin::path("compute", "utilization");
+    /// assert_eq!(path.as_str(), "task_pools/compute/utilization");
+    /// ```
+    pub fn path(pool: &str, stat: &str) -> DiagnosticPath {
+        DiagnosticPath::new(format!("{}/{pool}/{stat}", Self::TASK_POOLS))
+    }
+
+    pub fn diagnostic_system(
+        mut diagnostics: Diagnostics,
+        mut previous: Local<Option<(Instant, [TaskPoolStats; 3])>>,
+    ) {
+        let now = Instant::now();
+        let stats = [
+            ComputeTaskPool::try_get().map(|pool| pool.stats()),
+            AsyncComputeTaskPool::try_get().map(|pool| pool.stats()),
+            IoTaskPool::try_get().map(|pool| pool.stats()),
+        ];
+
+        for (i, pool) in Self::POOLS.into_iter().enumerate() {
+            let Some(stats) = stats[i] else {
+                continue;
+            };
+            diagnostics.add_measurement(&Self::path(pool, "queued"), || stats.queued as f64);
+            diagnostics.add_measurement(&Self::path(pool, "running"), || stats.running as f64);
+            if let Some((time, previous)) = previous.as_ref() {
+                let previous = &previous[i];
+                diagnostics.add_measurement(&Self::path(pool, "completed"), || {
+                    stats.completed_since(previous) as f64
+                });
+                diagnostics.add_measurement(&Self::path(pool, "utilization"), || {
+                    stats.utilization(previous, now - *time) * 100.0
+                });
+            }
+        }
+        *previous = Some((now, stats.map(Option::unwrap_or_default)));
+    }
+}
@@ -9,9 +9,17 @@
 //! This crate allows loading dynamic libraries (`.dylib`, `.so`) that export a single
 //! [`Plugin`](bevy_app::Plugin). For usage, see [`dynamically_load_plugin`].
 //!
+//! # Reloading
+//!
+//! Plugins loaded with [`ReloadablePluginExt::load_reloadable_plugin`] can be replaced by a rebuilt
+//! library with [`ReloadablePluginExt::reload_plugin`]. The components and resources of the types
+//! registered by the plugin are serialized through reflection before the old library is unloaded,
+//! and restored once the new one is loaded. Plugins holding values that can't be serialized are not unloaded,
+//! and a new library that changed the [`PluginLayout`] of these types is refused, leaving the old plugin running.
+//!
 //! # Deprecation
 //!
-//! The current dynamic plugin system is unsound and will be removed in 0.15. You may be interested
+//! [`dynamically_load_plugin`] and [`DynamicPluginExt`] are unsound and will be removed in 0.15. You may be interested
 //! in the [Alternatives](#alternatives) listed below. If your use-case is not supported, please
 //! consider commenting on [#13080](https://github.com/bevyengine/bevy/pull/13080) describing how
 //! you use dynamic plugins in your project.
@@ -37,5 +45,7 @@
 //! [`stabby`]: https://github.com/ZettaScaleLabs/stabby
 
 mod loader;
+mod reload;
 
 pub use loader::*;
+pub use reload::*;
@@ -0,0 +1,978 @@
+#![allow(unsafe_code)]
+
+use std::{
+    any::TypeId,
+    ffi::OsStr,
+    hash::{DefaultHasher, Hash, Hasher},
+    mem::ManuallyDrop,
+};
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::{
+    component::ComponentId,
+    entity::Entity,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    schedule::{ScheduleLabel, Schedules},
+    storage::ResourceData,
+    system::Resource,
+    world::World,
+};
+use bevy_reflect::{
+    serde::{TypedReflectDeserializer, TypedReflectSerializer},
+    TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
+};
+use bevy_utils::{tracing::warn, HashMap, HashSet};
+use libloading::{Library, Symbol};
+use serde::de::DeserializeSeed;
+use thiserror::Error;
+
+/// Errors that can occur when loading, reloading or unloading a reloadable dynamic plugin.
+#[derive(Debug, Error)]
+pub enum DynamicPluginReloadError {
+    /// An error occurred when loading the dynamic library.
+    #[error("cannot load library for dynamic plugin: {0}")]
+    Library(#[source] libloading::Error),
+    /// The dynamic library does not export a `_bevy_create_plugin` function.
+    #[error("dynamic library does not contain a valid Bevy dynamic plugin")]
+    Plugin(#[source] libloading::Error),
+    /// A plugin with the same name is already loaded.
+    #[error("dynamic plugin `{0}` is already loaded")]
+    AlreadyLoaded(String),
+    /// No plugin with the given name is loaded.
+    #[error("dynamic plugin `{0}` is not loaded")]
+    NotLoaded(String),
+    /// The reloaded library contains a different plugin than the one being reloaded.
+    #[error("expected dynamic plugin `{expected}`, but the library contains `{found}`")]
+    NameMismatch {
+        /// The name of the plugin being reloaded.
+        expected: String,
+        /// The name of the plugin in the new library.
+        found: String,
+    },
+    /// The layout of a plugin-owned type changed, so its state can't be restored.
+    #[error("the layout of `{0}` changed, refusing to reload")]
+    IncompatibleLayout(String),
+    /// A value of a component or resource registered by the plugin is still stored in the world,
+    /// and can't be taken by a [`PluginSnapshot`].
+    #[error("`{0}` is still in use, refusing to unload")]
+    InUse(String),
+    /// The new plugin was built, but the state of the old one could not be restored.
+    ///
+    /// The state is returned, so that it can be [restored](PluginSnapshot::restore) manually.
+    #[error("reloaded dynamic plugin `{name}`, but failed to restore its state: {error}")]
+    Restore {
+        /// The name of the reloaded plugin.
+        name: String,
+        /// The state of the old plugin.
+        snapshot: PluginSnapshot,
+        /// The reason the state could not be restored.
+        #[source]
+        error: Box<DynamicPluginReloadError>,
+    },
+    /// A plugin-owned value could not be serialized.
+    #[error("failed to serialize `{type_path}`: {error}")]
+    Serialize {
+        /// The type path of the value.
+        type_path: String,
+        /// The underlying error.
+        error: ron::Error,
+    },
+    /// A plugin-owned value could not be deserialized.
+    #[error("failed to deserialize `{type_path}`: {error}")]
+    Deserialize {
+        /// The type path of the value.
+        type_path: String,
+        /// The underlying error.
+        error: ron::Error,
+    },
+}
+
+/// The [`Schedule`](bevy_ecs::schedule::Schedule) holding the systems of a reloadable dynamic plugin,
+/// run during [`Update`].
+///
+/// Reloadable plugins must add their systems to the schedule matching their [`Plugin::name`](bevy_app::Plugin::name),
+/// as the schedule is removed before the plugin's library is unloaded.
+///
+/// ```
+/// # use bevy_app::{App, Plugin};
+/// # use bevy_dynamic_plugin::DynamicPluginUpdate;
+/// struct MyPlugin;
+///
+/// impl Plugin for MyPlugin {
+///     fn build(&self, app: &mut App) {
+///         app.add_systems(DynamicPluginUpdate::new(self.name()), || println!("hello"));
+///     }
+/// }
+/// ```
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct DynamicPluginUpdate(pub String);
+
+impl DynamicPluginUpdate {
+    /// Creates the schedule label for the plugin with the given name.
+    pub fn new(plugin_name: impl Into<String>) -> Self {
+        Self(plugin_name.into())
+    }
+}
+
+/// A hash of the reflected layout of each type owned by a dynamic plugin.
+///
+/// The layout of a type covers its [type path], kind, and the names and type paths of its
+/// fields and variants. Two versions of a type with the same layout can be converted into
+/// each other through serialization.
+///
+/// [type path]: bevy_reflect::TypePath::type_path
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PluginLayout {
+    hashes: HashMap<String, u64>,
+}
+
+impl PluginLayout {
+    /// Computes the layout of the given types from their registrations in `registry`.
+    ///
+    /// Types without a registration are ignored.
+    pub fn new(registry: &TypeRegistry, type_ids: impl IntoIterator<Item = TypeId>) -> Self {
+        let hashes = type_ids
+            .into_iter()
+            .filter_map(|type_id| registry.get_type_info(type_id))
+            .map(|info| (info.type_path().to_string(), layout_hash(info)))
+            .collect();
+        Self { hashes }
+    }
+
+    /// Returns the layout hash of the type with the given type path, if it is part of this layout.
+    pub fn get(&self, type_path: &str) -> Option<u64> {
+        self.hashes.get(type_path).copied()
+    }
+
+    /// Returns an iterator over the type paths and layout hashes of the types in this layout.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.hashes
+            .iter()
+            .map(|(type_path, hash)| (type_path.as_str(), *hash))
+    }
+
+    /// Checks that every type present in both layouts has the same layout hash.
+    ///
+    /// Types that were added or removed are considered compatible.
+    pub fn check_compatible(&self, new: &PluginLayout) -> Result<(), DynamicPluginReloadError> {
+        for (type_path, hash) in self.iter() {
+            if new.get(type_path).is_some_and(|new_hash| new_hash != hash) {
+                return Err(DynamicPluginReloadError::IncompatibleLayout(
+                    type_path.to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn layout_hash(info: &TypeInfo) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    info.type_path().hash(&mut hasher);
+    match info {
+        TypeInfo::Struct(info) => {
+            "struct".hash(&mut hasher);
+            for field in info.iter() {
+                (field.name(), field.type_path()).hash(&mut hasher);
+            }
+        }
+        TypeInfo::TupleStruct(info) => {
+            "tuple struct".hash(&mut hasher);
+            for field in info.iter() {
+                field.type_path().hash(&mut hasher);
+            }
+        }
+        TypeInfo::Enum(info) => {
+            "enum".hash(&mut hasher);
+            for variant in info.iter() {
+                variant.name().hash(&mut hasher);
+                match variant {
+                    VariantInfo::Struct(variant) => {
+                        for field in variant.iter() {
+                            (field.name(), field.type_path()).hash(&mut hasher);
+                        }
+                    }
+                    VariantInfo::Tuple(variant) => {
+                        for field in variant.iter() {
+                            field.type_path().hash(&mut hasher);
+                        }
+                    }
+                    VariantInfo::Unit(_) => {}
+                }
+            }
+        }
+        // The type paths of these kinds already describe their layout.
+        TypeInfo::Tuple(_)
+        | TypeInfo::List(_)
+        | TypeInfo::Array(_)
+        | TypeInfo::Map(_)
+        | TypeInfo::Value(_) => {}
+    }
+    hasher.finish()
+}
+
+/// The serialized components and resources of the types owned by a dynamic plugin.
+///
+/// A snapshot is [taken](Self::take) before the plugin's library is unloaded,
+/// and [restored](Self::restore) once the new library has been loaded.
+/// Values are stored as RON, so they don't reference any code from the library that created them.
+#[derive(Debug, Clone, Default)]
+pub struct PluginSnapshot {
+    components: Vec<(Entity, String, String)>,
+    resources: Vec<(String, String)>,
+}
+
+impl PluginSnapshot {
+    /// Serializes every component and resource of the given types using reflection,
+    /// then removes them from the world.
+    ///
+    /// The types must be registered with [`ReflectComponent`] or [`ReflectResource`]
+    /// in the world's [`AppTypeRegistry`], other types are ignored.
+    /// If any value fails to serialize, the world is left unchanged.
+    pub fn take(
+        world: &mut World,
+        type_ids: impl IntoIterator<Item = TypeId>,
+    ) -> Result<Self, DynamicPluginReloadError> {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let mut snapshot = Self::default();
+        let mut components = Vec::new();
+        let mut resources = Vec::new();
+        for type_id in type_ids {
+            let Some(registration) = registry.get(type_id) else {
+                continue;
+            };
+            let type_path = registration.type_info().type_path();
+            if let Some(reflect_component) = registration.data::<ReflectComponent>() {
+                for entity in world.iter_entities() {
+                    if let Some(value) = reflect_component.reflect(entity) {
+                        let value = serialize(value, type_path, &registry)?;
+                        snapshot
+                            .components
+                            .push((entity.id(), type_path.to_string(), value));
+                    }
+                }
+                components.push(reflect_component.clone());
+            }
+            if let Some(reflect_resource) = registration.data::<ReflectResource>() {
+                if let Some(value) = reflect_resource.reflect(world) {
+                    let value = serialize(value, type_path, &registry)?;
+                    snapshot.resources.push((type_path.to_string(), value));
+                    resources.push(reflect_resource.clone());
+                }
+            }
+        }
+
+        let entities: HashSet<Entity> = snapshot
+            .components
+            .iter()
+            .map(|(entity, ..)| *entity)
+            .collect();
+        for entity in entities {
+            let mut entity = world.entity_mut(entity);
+            for reflect_component in &components {
+                reflect_component.remove(&mut entity);
+            }
+        }
+        for reflect_resource in &resources {
+            reflect_resource.remove(world);
+        }
+        Ok(snapshot)
+    }
+
+    /// Deserializes the stored values using the current registrations of their types,
+    /// and inserts them back into the world.
+    ///
+    /// Values whose type is no longer registered, or whose entity was despawned, are dropped with a warning.
+    /// If any value fails to deserialize, the world is left unchanged.
+    pub fn restore(&self, world: &mut World) -> Result<(), DynamicPluginReloadError> {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        let mut components = Vec::new();
+        for (entity, type_path, value) in &self.components {
+            let Some(registration) = registry.get_with_type_path(type_path) else {
+                warn!(
+                    "Dropping component `{type_path}` of {entity:?}, as it is no longer registered"
+                );
+                continue;
+            };
+            let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+                warn!("Dropping `{type_path}` of {entity:?}, as it is no longer a component");
+                continue;
+            };
+            if world.get_entity(*entity).is_none() {
+                warn!("Dropping component `{type_path}` of despawned entity {entity:?}");
+                continue;
+            }
+            let value = deserialize(value, registration, &registry)?;
+            components.push((*entity, reflect_component, value));
+        }
+        let mut resources = Vec::new();
+        for (type_path, value) in &self.resources {
+            let Some(registration) = registry.get_with_type_path(type_path) else {
+                warn!("Dropping resource `{type_path}`, as it is no longer registered");
+                continue;
+            };
+            let Some(reflect_resource) = registration.data::<ReflectResource>() else {
+                warn!("Dropping `{type_path}`, as it is no longer a resource");
+                continue;
+            };
+            let value = deserialize(value, registration, &registry)?;
+            resources.push((reflect_resource, value));
+        }
+
+        for (entity, reflect_component, value) in components {
+            reflect_component.insert(&mut world.entity_mut(entity), value.as_ref(), &registry);
+        }
+        for (reflect_resource, value) in resources {
+            reflect_resource.insert(world, value.as_ref(), &registry);
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if the snapshot doesn't contain any components or resources.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty() && self.resources.is_empty()
+    }
+}
+
+fn serialize(
+    value: &dyn bevy_reflect::Reflect,
+    type_path: &str,
+    registry: &TypeRegistry,
+) -> Result<String, DynamicPluginReloadError> {
+    ron::to_string(&TypedReflectSerializer::new(value, registry)).map_err(|error| {
+        DynamicPluginReloadError::Serialize {
+            type_path: type_path.to_string(),
+            error,
+        }
+    })
+}
+
+fn deserialize(
+    value: &str,
+    registration: &TypeRegistration,
+    registry: &TypeRegistry,
+) -> Result<Box<dyn bevy_reflect::Reflect>, DynamicPluginReloadError> {
+    let error = |error| DynamicPluginReloadError::Deserialize {
+        type_path: registration.type_info().type_path().to_string(),
+        error,
+    };
+    let mut deserializer =
+        ron::Deserializer::from_str(value).map_err(|spanned| error(spanned.code))?;
+    TypedReflectDeserializer::new(registration, registry)
+        .deserialize(&mut deserializer)
+        .map_err(error)
+}
+
+struct LoadedDynamicPlugin {
+    /// Never dropped implicitly: the library is only unloaded by [`unload`](ReloadablePluginExt::unload_plugin)
+    /// and [`reload`](ReloadablePluginExt::reload_plugin), once everything referencing its code is gone.
+    library: ManuallyDrop<Library>,
+    owned_types: Vec<TypeId>,
+    owned_components: Vec<ComponentId>,
+}
+
+/// The reloadable dynamic plugins loaded in the app, by name.
+#[derive(Resource, Default)]
+pub struct DynamicPlugins {
+    plugins: HashMap<String, LoadedDynamicPlugin>,
+}
+
+impl DynamicPlugins {
+    /// Returns `true` if a plugin with the given name is loaded.
+    pub fn contains(&self, name: &str) -> bool {
+        self.plugins.contains_key(name)
+    }
+
+    /// Returns an iterator over the names of the loaded plugins.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.plugins.keys().map(String::as_str)
+    }
+
+    /// Returns the [`TypeId`]s of the types registered by the plugin with the given name.
+    pub fn owned_types(&self, name: &str) -> Option<&[TypeId]> {
+        self.plugins
+            .get(name)
+            .map(|plugin| plugin.owned_types.as_slice())
+    }
+
+    /// Returns the [`ComponentId`]s of the components and resources initialized while building
+    /// the plugin with the given name.
+    pub fn owned_components(&self, name: &str) -> Option<&[ComponentId]> {
+        self.plugins
+            .get(name)
+            .map(|plugin| plugin.owned_components.as_slice())
+    }
+}
+
+/// Runs the [`DynamicPluginUpdate`] schedule of every loaded plugin.
+fn run_dynamic_plugin_schedules(world: &mut World) {
+    let names: Vec<String> = world
+        .resource::<DynamicPlugins>()
+        .names()
+        .map(ToString::to_string)
+        .collect();
+    for name in names {
+        let _ = world.try_run_schedule(DynamicPluginUpdate(name));
+    }
+}
+
+fn registered_types(world: &World) -> HashSet<TypeId> {
+    world
+        .get_resource::<AppTypeRegistry>()
+        .map(|registry| {
+            registry
+                .read()
+                .iter()
+                .map(TypeRegistration::type_id)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns `true` if a value of the component or resource `id` is stored in the world.
+fn is_stored(world: &World, id: ComponentId) -> bool {
+    let storages = world.storages();
+    world
+        .archetypes()
+        .iter()
+        .any(|archetype| !archetype.is_empty() && archetype.contains(id))
+        || storages
+            .resources
+            .get(id)
+            .is_some_and(ResourceData::is_present)
+        || storages
+            .non_send_resources
+            .get(id)
+            .is_some_and(ResourceData::is_present)
+}
+
+/// Loads the library at `path` and creates the plugin it exports.
+///
+/// # Safety
+///
+/// See [`ReloadablePluginExt::load_reloadable_plugin`]'s safety section.
+unsafe fn load_library<P: AsRef<OsStr>>(
+    path: P,
+) -> Result<(Library, Box<dyn Plugin>), DynamicPluginReloadError> {
+    // SAFETY: The caller guarantees that the initialization routines of the library are sound.
+    let library = unsafe { Library::new(path).map_err(DynamicPluginReloadError::Library)? };
+
+    // SAFETY: The caller guarantees that `_bevy_create_plugin` was generated by `DynamicPlugin`.
+    let create_plugin: Symbol<unsafe extern "C" fn() -> *mut dyn Plugin> = unsafe {
+        library
+            .get(b"_bevy_create_plugin")
+            .map_err(DynamicPluginReloadError::Plugin)?
+    };
+
+    // SAFETY: `_bevy_create_plugin` returns a pointer created using `Box::into_raw`.
+    let plugin = unsafe { Box::from_raw(create_plugin()) };
+    Ok((library, plugin))
+}
+
+/// Builds `plugin` into `app`, and records it as a loaded plugin along with `library`.
+fn build_plugin(app: &mut App, name: String, library: Library, plugin: Box<dyn Plugin>) {
+    let registered = registered_types(app.world());
+    let first_component = app.world().components().len();
+    plugin.build(app);
+    plugin.finish(app);
+    plugin.cleanup(app);
+    drop(plugin);
+    let owned_types = registered_types(app.world())
+        .difference(&registered)
+        .copied()
+        .collect();
+    let owned_components = (first_component..app.world().components().len())
+        .map(ComponentId::new)
+        .collect();
+
+    app.world_mut()
+        .resource_mut::<DynamicPlugins>()
+        .plugins
+        .insert(
+            name,
+            LoadedDynamicPlugin {
+                library: ManuallyDrop::new(library),
+                owned_types,
+                owned_components,
+            },
+        );
+}
+
+/// Returns the [`PluginLayout`] of the types `plugin` registers, by building it into a scratch [`App`].
+fn plugin_layout(plugin: &dyn Plugin) -> PluginLayout {
+    let baseline = registered_types(App::new().world());
+    let mut scratch = App::new();
+    plugin.build(&mut scratch);
+    let new_types = registered_types(scratch.world())
+        .into_iter()
+        .filter(|type_id| !baseline.contains(type_id));
+    let registry = scratch.world().resource::<AppTypeRegistry>().read();
+    PluginLayout::new(&registry, new_types)
+}
+
+/// Replaces the loaded plugin named `name` with `plugin`, loaded from `library`.
+///
+/// # Safety
+///
+/// See [`ReloadablePluginExt::unload_plugin`]'s safety section.
+unsafe fn replace_plugin(
+    app: &mut App,
+    name: &str,
+    library: Library,
+    plugin: Box<dyn Plugin>,
+) -> Result<(), DynamicPluginReloadError> {
+    let found = plugin.name().to_string();
+    if found != name {
+        drop(plugin);
+        return Err(DynamicPluginReloadError::NameMismatch {
+            expected: name.to_string(),
+            found,
+        });
+    }
+
+    // Check the layout of the new types before touching the state of the old plugin.
+    let owned_types = app
+        .world()
+        .resource::<DynamicPlugins>()
+        .owned_types(name)
+        .unwrap_or_default();
+    let old_layout = PluginLayout::new(
+        &app.world().resource::<AppTypeRegistry>().read(),
+        owned_types.iter().copied(),
+    );
+    // The plugin is dropped before its library on every error path, as it references code from it.
+    if let Err(error) = old_layout.check_compatible(&plugin_layout(plugin.as_ref())) {
+        drop(plugin);
+        return Err(error);
+    }
+
+    // SAFETY: The caller upholds the safety requirements of `unload_plugin`.
+    let snapshot = match unsafe { app.unload_plugin(name) } {
+        Ok(snapshot) => snapshot,
+        Err(error) => {
+            drop(plugin);
+            return Err(error);
+        }
+    };
+    build_plugin(app, name.to_string(), library, plugin);
+    if let Err(error) = snapshot.restore(app.world_mut()) {
+        return Err(DynamicPluginReloadError::Restore {
+            name: name.to_string(),
+            snapshot,
+            error: Box::new(error),
+        });
+    }
+    Ok(())
+}
+
+/// An extension trait for [`App`] that allows loading, reloading and unloading dynamic plugins
+/// while preserving their state.
+///
+/// Types registered in the [`AppTypeRegistry`] while building a plugin are owned by it,
+/// as are the components and resources initialized while building it.
+/// On reload, the components and resources of the owned types are serialized through reflection,
+/// the old library is unloaded, and the values are deserialized with the types of the new library.
+///
+/// The systems of a reloadable plugin must be added to its [`DynamicPluginUpdate`] schedule.
+pub trait ReloadablePluginExt {
+    /// Dynamically links a reloadable plugin at the given path, building it into the app.
+    ///
+    /// # Safety
+    ///
+    /// The plugin must be linked against the exact same `libbevy.so` as this program,
+    /// and export a `_bevy_create_plugin` function generated by deriving `DynamicPlugin`.
+    ///
+    /// Loading a library may run its initialization routines, which the caller is responsible for.
+    /// For more information, see the safety section of [`Library::new`].
+    unsafe fn load_reloadable_plugin<P: AsRef<OsStr>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, DynamicPluginReloadError>;
+
+    /// Replaces the loaded plugin named `name` with the plugin from the library at `path`,
+    /// preserving the values of its components and resources.
+    ///
+    /// Most platforms don't load a library again from a path that is already loaded,
+    /// so `path` should point to a fresh copy of the rebuilt library.
+    ///
+    /// The new plugin is first built into a scratch [`App`] to find the [`PluginLayout`] of its types,
+    /// so its [`Plugin::build`] must not have side effects outside of the app it is given.
+    ///
+    /// If the new library can't be loaded, contains a different plugin, changed the layout of an owned type,
+    /// or the old plugin can't be [unloaded](Self::unload_plugin), the old plugin is left untouched.
+    /// Otherwise the new plugin is built into the app. If the values of the old plugin can't be deserialized,
+    /// a [`DynamicPluginReloadError::Restore`] error holding the state of the old plugin is returned.
+    ///
+    /// # Safety
+    ///
+    /// See [`load_reloadable_plugin`](Self::load_reloadable_plugin) and [`unload_plugin`](Self::unload_plugin).
+    unsafe fn reload_plugin<P: AsRef<OsStr>>(
+        &mut self,
+        name: &str,
+        path: P,
+    ) -> Result<&mut Self, DynamicPluginReloadError>;
+
+    /// Removes the loaded plugin named `name` with its schedule, components, resources and type registrations,
+    /// and unloads its library.
+    ///
+    /// The values of the owned components and resources registered with [`ReflectComponent`] or
+    /// [`ReflectResource`] are taken by the returned [`PluginSnapshot`], which can be
+    /// [restored](PluginSnapshot::restore) after loading the plugin again. Other owned components and
+    /// resources are [forgotten](World::forget_component_types), so their [`ComponentInfo`](bevy_ecs::component::ComponentInfo)
+    /// no longer references the library. If a value of one of them is still stored in the world,
+    /// a [`DynamicPluginReloadError::InUse`] error is returned and nothing is unloaded.
+    ///
+    /// # Safety
+    ///
+    /// Nothing other than the plugin's [`DynamicPluginUpdate`] schedule, owned components, owned resources
+    /// and type registrations may reference code from the library, as it is unloaded.
+    /// This includes systems and observers added outside of the plugin's schedule, plugins added by the plugin,
+    /// components and resources of the plugin's types initialized after it was built, and type data
+    /// registered for types the plugin doesn't own.
+    /// The [`ComponentId`]s of the owned components and resources must not be used afterwards.
+    unsafe fn unload_plugin(
+        &mut self,
+        name: &str,
+    ) -> Result<PluginSnapshot, DynamicPluginReloadError>;
+}
+
+impl ReloadablePluginExt for App {
+    unsafe fn load_reloadable_plugin<P: AsRef<OsStr>>(
+        &mut self,
+        path: P,
+    ) -> Result<&mut Self, DynamicPluginReloadError> {
+        // SAFETY: The caller upholds the safety requirements of `load_library`.
+        let (library, plugin) = unsafe { load_library(path)? };
+        let name = plugin.name().to_string();
+        if self
+            .world()
+            .get_resource::<DynamicPlugins>()
+            .is_some_and(|plugins| plugins.contains(&name))
+        {
+            drop(plugin);
+            return Err(DynamicPluginReloadError::AlreadyLoaded(name));
+        }
+        if !self.world().contains_resource::<DynamicPlugins>() {
+            self.init_resource::<DynamicPlugins>()
+                .add_systems(Update, run_dynamic_plugin_schedules);
+        }
+
+        build_plugin(self, name, library, plugin);
+        Ok(self)
+    }
+
+    unsafe fn reload_plugin<P: AsRef<OsStr>>(
+        &mut self,
+        name: &str,
+        path: P,
+    ) -> Result<&mut Self, DynamicPluginReloadError> {
+        if !self
+            .world()
+            .get_resource::<DynamicPlugins>()
+            .is_some_and(|plugins| plugins.contains(name))
+        {
+            return Err(DynamicPluginReloadError::NotLoaded(name.to_string()));
+        }
+
+        // SAFETY: The caller upholds the safety requirements of `load_library`.
+        let (library, plugin) = unsafe { load_library(path)? };
+        // SAFETY: The caller upholds the safety requirements of `unload_plugin`.
+        unsafe { replace_plugin(self, name, library, plugin)? };
+        Ok(self)
+    }
+
+    unsafe fn unload_plugin(
+        &mut self,
+        name: &str,
+    ) -> Result<PluginSnapshot, DynamicPluginReloadError> {
+        let Some(plugins) = self.world().get_resource::<DynamicPlugins>() else {
+            return Err(DynamicPluginReloadError::NotLoaded(name.to_string()));
+        };
+        let (Some(owned_types), Some(owned_components)) =
+            (plugins.owned_types(name), plugins.owned_components(name))
+        else {
+            return Err(DynamicPluginReloadError::NotLoaded(name.to_string()));
+        };
+        let owned_types = owned_types.to_vec();
+
+        // Find the components and resources the snapshot takes the values of,
+        // and make sure no other owned component or resource has a value left.
+        let world = self.world();
+        let mut taken = HashSet::new();
+        let mut components: HashSet<ComponentId> = owned_components.iter().copied().collect();
+        {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            for &type_id in &owned_types {
+                let component_id = world.components().get_id(type_id);
+                let resource_id = world.components().get_resource_id(type_id);
+                components.extend(component_id.into_iter().chain(resource_id));
+                let Some(registration) = registry.get(type_id) else {
+                    continue;
+                };
+                if registration.data::<ReflectComponent>().is_some() {
+                    taken.extend(component_id);
+                }
+                if registration.data::<ReflectResource>().is_some() {
+                    taken.extend(resource_id);
+                }
+            }
+        }
+        if let Some(id) = components
+            .iter()
+            .find(|&&id| !taken.contains(&id) && is_stored(world, id))
+        {
+            let name = world.components().get_name(*id).unwrap_or_default();
+            return Err(DynamicPluginReloadError::InUse(name.to_string()));
+        }
+
+        let snapshot = PluginSnapshot::take(self.world_mut(), owned_types.iter().copied())?;
+        self.world_mut()
+            .resource_mut::<Schedules>()
+            .remove(DynamicPluginUpdate::new(name));
+        let components: Vec<ComponentId> = components.into_iter().collect();
+        let forgotten = self.world_mut().forget_component_types(&components);
+        debug_assert!(
+            forgotten.is_ok(),
+            "the values of owned components were taken"
+        );
+        {
+            let mut registry = self.world().resource::<AppTypeRegistry>().write();
+            for type_id in owned_types {
+                registry.unregister(type_id);
+            }
+        }
+
+        let mut loaded = self
+            .world_mut()
+            .resource_mut::<DynamicPlugins>()
+            .plugins
+            .remove(name)
+            .expect("the plugin was checked to be loaded");
+        // SAFETY: Everything referencing code from the library was removed above,
+        // and the caller guarantees nothing else does.
+        unsafe { ManuallyDrop::drop(&mut loaded.library) };
+        Ok(snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        component::Component,
+        reflect::ReflectResource,
+        system::{Query, Resource},
+    };
+    use bevy_reflect::Reflect;
+
+    use super::*;
+
+    #[derive(Component, Reflect, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        current: u32,
+        max: u32,
+    }
+
+    #[derive(Resource, Reflect, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u64);
+
+    mod changed {
+        use bevy_reflect::Reflect;
+
+        /// A new version of [`Health`](super::Health), with the same type path.
+        #[derive(Reflect)]
+        #[type_path = "bevy_dynamic_plugin::reload::tests"]
+        pub struct Health {
+            pub current: u32,
+            pub max: u32,
+            pub regeneration: f32,
+        }
+    }
+
+    #[test]
+    fn layout_changes_are_incompatible() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Health>();
+        registry.register::<changed::Health>();
+        registry.register::<Score>();
+
+        let old = PluginLayout::new(&registry, [TypeId::of::<Health>(), TypeId::of::<Score>()]);
+        assert!(old.check_compatible(&old.clone()).is_ok());
+        assert!(old.check_compatible(&PluginLayout::default()).is_ok());
+
+        let health_path = <Health as bevy_reflect::TypePath>::type_path();
+        let new = PluginLayout::new(&registry, [TypeId::of::<changed::Health>()]);
+        assert!(new.get(health_path).is_some());
+        assert!(matches!(
+            old.check_compatible(&new),
+            Err(DynamicPluginReloadError::IncompatibleLayout(type_path)) if type_path == health_path
+        ));
+    }
+
+    #[test]
+    fn snapshot_round_trips_components_and_resources() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Health>();
+            registry.register::<Score>();
+        }
+        let player = world
+            .spawn(Health {
+                current: 7,
+                max: 10,
+            })
+            .id();
+        let other = world.spawn_empty().id();
+        world.insert_resource(Score(42));
+
+        let snapshot =
+            PluginSnapshot::take(&mut world, [TypeId::of::<Health>(), TypeId::of::<Score>()])
+                .unwrap();
+        assert!(!snapshot.is_empty());
+        assert!(world.get::<Health>(player).is_none());
+        assert!(!world.contains_resource::<Score>());
+
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(
+            world.get::<Health>(player),
+            Some(&Health {
+                current: 7,
+                max: 10
+            })
+        );
+        assert!(world.get::<Health>(other).is_none());
+        assert_eq!(world.resource::<Score>(), &Score(42));
+    }
+
+    #[derive(Component)]
+    struct Marker;
+
+    struct TestPlugin;
+
+    impl Plugin for TestPlugin {
+        fn build(&self, app: &mut App) {
+            app.register_type::<Health>()
+                .add_systems(DynamicPluginUpdate::new(self.name()), heal)
+                .world_mut()
+                .init_component::<Marker>();
+        }
+    }
+
+    fn heal(mut query: Query<&mut Health>) {
+        for mut health in &mut query {
+            health.current = health.max.min(health.current + 1);
+        }
+    }
+
+    /// A new version of [`TestPlugin`], changing the layout of [`Health`].
+    struct ChangedTestPlugin;
+
+    impl Plugin for ChangedTestPlugin {
+        fn build(&self, app: &mut App) {
+            app.register_type::<changed::Health>();
+        }
+
+        fn name(&self) -> &str {
+            TestPlugin.name()
+        }
+    }
+
+    /// Builds [`TestPlugin`] into a new app, with the program itself standing in for its library.
+    #[cfg(unix)]
+    fn app_with_test_plugin() -> App {
+        let mut app = App::new();
+        app.init_resource::<DynamicPlugins>()
+            .add_systems(Update, run_dynamic_plugin_schedules);
+        let library = libloading::os::unix::Library::this().into();
+        let name = TestPlugin.name().to_string();
+        build_plugin(&mut app, name, library, Box::new(TestPlugin));
+        app
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn incompatible_reloads_are_refused() {
+        let mut app = app_with_test_plugin();
+        let name = TestPlugin.name().to_string();
+        let entity = app
+            .world_mut()
+            .spawn(Health {
+                current: 7,
+                max: 10,
+            })
+            .id();
+
+        let library = libloading::os::unix::Library::this().into();
+        // SAFETY: The library is the program itself, which is never unloaded.
+        let result =
+            unsafe { replace_plugin(&mut app, &name, library, Box::new(ChangedTestPlugin)) };
+        assert!(matches!(
+            result,
+            Err(DynamicPluginReloadError::IncompatibleLayout(_))
+        ));
+
+        // The old plugin is still loaded, with its state, and its systems still run.
+        assert!(app.world().resource::<DynamicPlugins>().contains(&name));
+        app.update();
+        assert_eq!(
+            app.world().get::<Health>(entity),
+            Some(&Health {
+                current: 8,
+                max: 10
+            })
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unloading_refuses_values_it_cannot_take() {
+        let mut app = app_with_test_plugin();
+        let name = TestPlugin.name().to_string();
+
+        let health = Health {
+            current: 7,
+            max: 10,
+        };
+        let marked = app.world_mut().spawn((health, Marker)).id();
+        // SAFETY: The library is the program itself, which is never unloaded.
+        let result = unsafe { app.unload_plugin(&name) };
+        assert!(matches!(result, Err(DynamicPluginReloadError::InUse(_))));
+        assert!(app.world().get::<Health>(marked).is_some());
+
+        app.world_mut().entity_mut(marked).remove::<Marker>();
+        // SAFETY: The library is the program itself, which is never unloaded.
+        let snapshot = unsafe { app.unload_plugin(&name) }.unwrap();
+        assert!(!snapshot.is_empty());
+        assert!(!app.world().resource::<DynamicPlugins>().contains(&name));
+        assert_eq!(app.world().component_id::<Health>(), None);
+        assert_eq!(app.world().component_id::<Marker>(), None);
+
+        app.register_type::<Health>();
+        snapshot.restore(app.world_mut()).unwrap();
+        assert_eq!(
+            app.world().get::<Health>(marked),
+            Some(&Health {
+                current: 7,
+                max: 10
+            })
+        );
+    }
+
+    #[test]
+    fn unloading_unknown_plugin_fails() {
+        let mut app = App::new();
+        // SAFETY: No library is loaded.
+        let result = unsafe { app.unload_plugin("missing") };
+        assert!(matches!(
+            result,
+            Err(DynamicPluginReloadError::NotLoaded(_))
+        ));
+    }
+}
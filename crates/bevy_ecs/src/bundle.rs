@@ -1095,6 +1095,19 @@ impl Bundles {
         *bundle_id
     }
 
+    /// Removes the cached [`BundleId`]s of the bundles containing the component `id`,
+    /// so that initializing them again creates new [`BundleInfo`]s.
+    #[cfg(feature = "component_unloading")]
+    pub(crate) fn forget_component(&mut self, id: ComponentId) {
+        let bundle_infos = &self.bundle_infos;
+        let contains =
+            |bundle_id: &BundleId| bundle_infos[bundle_id.index()].component_ids.contains(&id);
+        self.bundle_ids.retain(|_, bundle_id| !contains(bundle_id));
+        self.dynamic_bundle_ids
+            .retain(|component_ids, _| !component_ids.contains(&id));
+        self.dynamic_component_bundle_ids.remove(&id);
+    }
+
     /// Initializes a new [`BundleInfo`] for a dynamic [`Bundle`] with single component.
     ///
     /// # Panics
@@ -915,6 +915,28 @@ impl Components {
     pub fn iter(&self) -> impl Iterator<Item = &ComponentInfo> + '_ {
         self.components.iter()
     }
+
+    /// Disassociates the component or resource `id` from its Rust type.
+    ///
+    /// The [`ComponentInfo`] is kept, but its name is copied, and its type, drop function and hooks are cleared.
+    /// Initializing the type again creates a new [`ComponentId`].
+    #[cfg(feature = "component_unloading")]
+    pub(crate) fn forget_type(&mut self, id: ComponentId) {
+        let Some(info) = self.components.get_mut(id.0) else {
+            return;
+        };
+        if let Some(type_id) = info.descriptor.type_id.take() {
+            if self.indices.get(&type_id) == Some(&id) {
+                self.indices.remove(&type_id);
+            }
+            if self.resource_indices.get(&type_id) == Some(&id) {
+                self.resource_indices.remove(&type_id);
+            }
+        }
+        info.descriptor.name = Cow::Owned(info.descriptor.name.to_string());
+        info.descriptor.drop = None;
+        info.hooks = ComponentHooks::default();
+    }
 }
 
 /// A value that tracks when a system ran relative to other systems.
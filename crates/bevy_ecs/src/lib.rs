@@ -36,6 +36,9 @@ pub use bevy_ptr as ptr;
 
 /// Most commonly used re-exported types.
 pub mod prelude {
+    #[doc(hidden)]
+    #[cfg(feature = "reflect_functions")]
+    pub use crate::reflect::AppFunctionRegistry;
     #[doc(hidden)]
     #[cfg(feature = "bevy_reflect")]
     pub use crate::reflect::{
@@ -72,6 +75,7 @@ mod tests {
     use crate as bevy_ecs;
     use crate::prelude::Or;
     use crate::{
+        batching::BatchingStrategy,
         bundle::Bundle,
         change_detection::Ref,
         component::{Component, ComponentId},
@@ -81,6 +85,7 @@ mod tests {
         world::{EntityRef, Mut, World},
     };
     use bevy_tasks::{ComputeTaskPool, TaskPool};
+    use bevy_utils::Parallel;
     use std::num::NonZeroU32;
     use std::{
         any::TypeId,
@@ -454,6 +459,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn par_fold_in_query_order() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        for i in 0..100 {
+            match i % 3 {
+                0 => world.spawn(A(i)),
+                1 => world.spawn((A(i), B(1))),
+                _ => world.spawn((A(i), SparseStored(1))),
+            };
+        }
+        let mut query = world.query::<&A>();
+        let expected: Vec<_> = query.iter(&world).map(|&A(i)| i).collect();
+
+        // Concatenating isn't commutative, so the values must be reduced in query iteration order.
+        let results = query
+            .par_iter(&world)
+            .batching_strategy(BatchingStrategy::fixed(7))
+            .map_reduce(
+                |&A(i)| vec![i],
+                |mut a, b| {
+                    a.extend(b);
+                    a
+                },
+            );
+        assert_eq!(results, Some(expected.clone()));
+
+        let results = query
+            .par_iter(&world)
+            .batching_strategy(BatchingStrategy::fixed(7))
+            .fold(
+                Vec::new,
+                |mut results, &A(i)| {
+                    results.push(i);
+                    results
+                },
+                |mut a, b| {
+                    a.extend(b);
+                    a
+                },
+            );
+        assert_eq!(results, expected);
+
+        let mut counts = Parallel::<usize>::default();
+        query
+            .par_iter(&world)
+            .for_each_local(&counts, |count, _| *count += 1);
+        assert_eq!(counts.iter_mut().map(|count| *count).sum::<usize>(), 100);
+
+        let mut empty = world.query_filtered::<&A, With<C>>();
+        assert_eq!(
+            empty.par_iter(&world).map_reduce(|a| a.0, |a, b| a + b),
+            None
+        );
+        assert_eq!(empty.par_iter(&world).fold(|| 1, |a, _| a, |a, b| a + b), 1);
+    }
+
     #[test]
     fn query_missing_component() {
         let mut world = World::new();
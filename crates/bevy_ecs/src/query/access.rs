@@ -723,8 +723,8 @@ mod tests {
         let mut access_d = Access::<usize>::default();
         access_d.add_read(0);
 
-        assert_eq!(access_d.get_conflicts(&access_a), vec![]);
-        assert_eq!(access_d.get_conflicts(&access_b), vec![]);
+        assert_eq!(access_d.get_conflicts(&access_a), Vec::<usize>::new());
+        assert_eq!(access_d.get_conflicts(&access_b), Vec::<usize>::new());
         assert_eq!(access_d.get_conflicts(&access_c), vec![0]);
     }
 
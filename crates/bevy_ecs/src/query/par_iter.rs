@@ -1,3 +1,7 @@
+use std::sync::Mutex;
+
+use bevy_utils::Parallel;
+
 use crate::{
     batching::BatchingStrategy, component::Tick, world::unsafe_world_cell::UnsafeWorldCell,
 };
@@ -78,6 +82,179 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryParIter<'w, 's, D, F> {
             func(&mut init, item);
             init
         };
+        self.fold_init(init, func, |_, _| {});
+    }
+
+    /// Runs `func` on each query result in parallel, with the value of `parallel` local to the thread
+    /// running it.
+    ///
+    /// The values can then be collected with [`Parallel::iter_mut`] or [`Parallel::drain`], but their
+    /// order isn't deterministic. Use [`fold`](Self::fold) or [`map_reduce`](Self::map_reduce) if it matters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_utils::Parallel;
+    /// use bevy_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn find_dead(query: Query<(Entity, &Health)>, mut dead: Local<Parallel<Vec<Entity>>>) {
+    ///     query.par_iter().for_each_local(&dead, |dead, (entity, health)| {
+    ///         if health.0 == 0 {
+    ///             dead.push(entity);
+    ///         }
+    ///     });
+    ///     for entity in dead.drain() {
+    ///         // ...
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(find_dead);
+    /// ```
+    ///
+    /// # Panics
+    /// If the [`ComputeTaskPool`] is not initialized. If using this from a query that is being
+    /// initialized and run from the ECS scheduler, this should never panic.
+    ///
+    /// [`ComputeTaskPool`]: bevy_tasks::ComputeTaskPool
+    #[inline]
+    pub fn for_each_local<FN, T>(self, parallel: &Parallel<T>, func: FN)
+    where
+        FN: Fn(&mut T, QueryItem<'w, D>) + Send + Sync + Clone,
+        T: Default + Send,
+    {
+        self.for_each_init(
+            || parallel.borrow_local_mut(),
+            |local, item| func(local, item),
+        );
+    }
+
+    /// Folds the query results in parallel, then reduces the values of the batches in query iteration order.
+    ///
+    /// The query results are split in batches, like with [`for_each`](Self::for_each). The results of
+    /// each batch are folded with `func` in query iteration order, starting from a value returned by `init`,
+    /// then the values of all batches are combined with `reduce`, in query iteration order too.
+    ///
+    /// The batches only depend on the query results, the [`BatchingStrategy`] and the number of threads of
+    /// the [`ComputeTaskPool`], so, unlike accumulating into a [`Parallel`], the same query results are
+    /// always reduced in the same order. This makes the result reproducible even for operations that
+    /// aren't associative, like adding floating point numbers.
+    ///
+    /// Returns a value returned by `init` if there are no query results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Mass(f32);
+    ///
+    /// fn total_mass(query: Query<&Mass>) -> f32 {
+    ///     query
+    ///         .par_iter()
+    ///         .fold(|| 0.0, |total, mass| total + mass.0, |a, b| a + b)
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// If the [`ComputeTaskPool`] is not initialized. If using this from a query that is being
+    /// initialized and run from the ECS scheduler, this should never panic.
+    ///
+    /// [`ComputeTaskPool`]: bevy_tasks::ComputeTaskPool
+    #[inline]
+    pub fn fold<T, INIT, FN, R>(self, init: INIT, func: FN, reduce: R) -> T
+    where
+        T: Send,
+        INIT: Fn() -> T + Sync + Send + Clone,
+        FN: Fn(T, QueryItem<'w, D>) -> T + Send + Sync + Clone,
+        R: FnMut(T, T) -> T,
+    {
+        self.fold_batches(init.clone(), func)
+            .into_iter()
+            .reduce(reduce)
+            .unwrap_or_else(init)
+    }
+
+    /// Maps each query result in parallel with `map`, then reduces the values in query iteration order
+    /// with `reduce`.
+    ///
+    /// Like with [`fold`](Self::fold), the same query results are always reduced in the same order, see
+    /// its documentation for more details.
+    ///
+    /// Returns [`None`] if there are no query results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bevy_ecs::prelude::*;
+    ///
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// fn lowest_health(query: Query<(Entity, &Health)>) -> Option<Entity> {
+    ///     query
+    ///         .par_iter()
+    ///         .map_reduce(
+    ///             |(entity, health)| (entity, health.0),
+    ///             |a, b| if b.1 < a.1 { b } else { a },
+    ///         )
+    ///         .map(|(entity, _)| entity)
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    /// If the [`ComputeTaskPool`] is not initialized. If using this from a query that is being
+    /// initialized and run from the ECS scheduler, this should never panic.
+    ///
+    /// [`ComputeTaskPool`]: bevy_tasks::ComputeTaskPool
+    #[inline]
+    pub fn map_reduce<T, M, R>(self, map: M, reduce: R) -> Option<T>
+    where
+        T: Send,
+        M: Fn(QueryItem<'w, D>) -> T + Send + Sync + Clone,
+        R: Fn(T, T) -> T + Send + Sync + Clone,
+    {
+        let reduce_item = reduce.clone();
+        let func = move |accum: Option<T>, item| {
+            let value = map(item);
+            Some(match accum {
+                Some(accum) => reduce_item(accum, value),
+                None => value,
+            })
+        };
+        self.fold_batches(|| None, func)
+            .into_iter()
+            .flatten()
+            .reduce(reduce)
+    }
+
+    /// Folds each batch of query results in parallel, and returns the values of the batches in query
+    /// iteration order.
+    fn fold_batches<T, INIT, FN>(self, init: INIT, func: FN) -> Vec<T>
+    where
+        T: Send,
+        INIT: Fn() -> T + Sync + Send + Clone,
+        FN: Fn(T, QueryItem<'w, D>) -> T + Send + Sync + Clone,
+    {
+        let batches = Mutex::new(Vec::new());
+        self.fold_init(init, func, |batch, accum| {
+            batches.lock().unwrap().push((batch, accum));
+        });
+        let mut batches = batches.into_inner().unwrap();
+        batches.sort_unstable_by_key(|(batch, _)| *batch);
+        batches.into_iter().map(|(_, accum)| accum).collect()
+    }
+
+    /// Folds each batch of query results in parallel, starting from a value returned by `init`, and calls
+    /// `done` with the index of the batch in query iteration order and its final value.
+    fn fold_init<T, INIT, FN, DONE>(self, init: INIT, func: FN, done: DONE)
+    where
+        INIT: Fn() -> T + Sync + Send + Clone,
+        FN: Fn(T, QueryItem<'w, D>) -> T + Send + Sync + Clone,
+        DONE: Fn(usize, T) + Send + Sync + Clone,
+    {
         #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
         {
             let init = init();
@@ -87,11 +264,12 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryParIter<'w, 's, D, F> {
             // Mutable instances of QueryParIter can only be created via an exclusive borrow of a
             // Query or a World, which ensures that multiple aliasing QueryParIters cannot exist
             // at the same time.
-            unsafe {
+            let accum = unsafe {
                 self.state
                     .iter_unchecked_manual(self.world, self.last_run, self.this_run)
-                    .fold(init, func);
-            }
+                    .fold(init, func)
+            };
+            done(0, accum);
         }
         #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
         {
@@ -99,11 +277,12 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryParIter<'w, 's, D, F> {
             if thread_count <= 1 {
                 let init = init();
                 // SAFETY: See the safety comment above.
-                unsafe {
+                let accum = unsafe {
                     self.state
                         .iter_unchecked_manual(self.world, self.last_run, self.this_run)
-                        .fold(init, func);
-                }
+                        .fold(init, func)
+                };
+                done(0, accum);
             } else {
                 // Need a batch size of at least 1.
                 let batch_size = self.get_batch_size(thread_count).max(1);
@@ -114,6 +293,7 @@ impl<'w, 's, D: QueryData, F: QueryFilter> QueryParIter<'w, 's, D, F> {
                         self.world,
                         batch_size,
                         func,
+                        done,
                         self.last_run,
                         self.this_run,
                     );
@@ -1381,6 +1381,10 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     /// the current change tick are given. This is faster than the equivalent
     /// `iter()` method, but cannot be chained like a normal [`Iterator`].
     ///
+    /// The query results are split in batches, each folded with `func` from a value returned by `init_accum`
+    /// in a separate task. Batches are numbered in query iteration order, and `done` is called with the
+    /// index and final value of each batch once it completes.
+    ///
     /// # Panics
     /// The [`ComputeTaskPool`] is not initialized. If using this from a query that is being
     /// initialized and run from the ECS scheduler, this should never panic.
@@ -1394,17 +1398,20 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     ///
     /// [`ComputeTaskPool`]: bevy_tasks::ComputeTaskPool
     #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
-    pub(crate) unsafe fn par_fold_init_unchecked_manual<'w, T, FN, INIT>(
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) unsafe fn par_fold_init_unchecked_manual<'w, T, FN, INIT, DONE>(
         &self,
         init_accum: INIT,
         world: UnsafeWorldCell<'w>,
         batch_size: usize,
         func: FN,
+        done: DONE,
         last_run: Tick,
         this_run: Tick,
     ) where
         FN: Fn(T, D::Item<'w>) -> T + Send + Sync + Clone,
         INIT: Fn() -> T + Sync + Send + Clone,
+        DONE: Fn(usize, T) + Send + Sync + Clone,
     {
         // NOTE: If you are changing query iteration code, remember to update the following places, where relevant:
         // QueryIter, QueryIterationCursor, QueryManyIter, QueryCombinationIter,QueryState::par_fold_init_unchecked_manual
@@ -1416,6 +1423,12 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             let archetypes = world.archetypes();
             let mut batch_queue = ArrayVec::new();
             let mut queue_entity_count = 0;
+            let batch_count = std::cell::Cell::new(0);
+            let next_batch = || {
+                let batch = batch_count.get();
+                batch_count.set(batch + 1);
+                batch
+            };
 
             // submit a list of storages which smaller than batch_size as single task
             let submit_batch_queue = |queue: &mut ArrayVec<StorageId, 128>| {
@@ -1425,6 +1438,8 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                 let queue = std::mem::take(queue);
                 let mut func = func.clone();
                 let init_accum = init_accum.clone();
+                let done = done.clone();
+                let batch = next_batch();
                 scope.spawn(async move {
                     #[cfg(feature = "trace")]
                     let _span = self.par_iter_span.enter();
@@ -1451,6 +1466,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                             );
                         }
                     }
+                    done(batch, accum);
                 });
             };
 
@@ -1459,23 +1475,26 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                 for offset in (0..count).step_by(batch_size) {
                     let mut func = func.clone();
                     let init_accum = init_accum.clone();
+                    let done = done.clone();
                     let len = batch_size.min(count - offset);
-                    let batch = offset..offset + len;
+                    let range = offset..offset + len;
+                    let batch = next_batch();
                     scope.spawn(async move {
                         #[cfg(feature = "trace")]
                         let _span = self.par_iter_span.enter();
                         let accum = init_accum();
-                        if D::IS_DENSE && F::IS_DENSE {
+                        let accum = if D::IS_DENSE && F::IS_DENSE {
                             let id = storage_id.table_id;
                             let table = world.storages().tables.get(id).debug_checked_unwrap();
                             self.iter_unchecked_manual(world, last_run, this_run)
-                                .fold_over_table_range(accum, &mut func, table, batch);
+                                .fold_over_table_range(accum, &mut func, table, range)
                         } else {
                             let id = storage_id.archetype_id;
                             let archetype = world.archetypes().get(id).debug_checked_unwrap();
                             self.iter_unchecked_manual(world, last_run, this_run)
-                                .fold_over_archetype_range(accum, &mut func, archetype, batch);
-                        }
+                                .fold_over_archetype_range(accum, &mut func, archetype, range)
+                        };
+                        done(batch, accum);
                     });
                 }
             };
@@ -1495,8 +1514,11 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                 if count == 0 {
                     continue;
                 }
-                // immediately submit large storage
+                // immediately submit large storage, after the small storages queued before it,
+                // so that batches are numbered in query iteration order
                 if count >= batch_size {
+                    submit_batch_queue(&mut batch_queue);
+                    queue_entity_count = 0;
                     submit_single(count, *storage_id);
                     continue;
                 }
@@ -2032,4 +2054,61 @@ mod tests {
         let query_2 = QueryState::<&B, Without<C>>::new(&mut world);
         let _: QueryState<Entity, Changed<C>> = query_1.join_filtered(world.components(), &query_2);
     }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+    #[test]
+    fn par_fold_numbers_batches_in_query_order() {
+        use bevy_tasks::{ComputeTaskPool, TaskPool};
+        use std::sync::Mutex;
+
+        ComputeTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        // Tables smaller than the batch size are merged, larger ones are split, in between each other.
+        let mut i = 0;
+        for (count, b, c) in [
+            (3, false, false),
+            (20, true, false),
+            (2, false, true),
+            (9, true, true),
+        ] {
+            for _ in 0..count {
+                let mut entity = world.spawn(A(i));
+                if b {
+                    entity.insert(B(1));
+                }
+                if c {
+                    entity.insert(C(1));
+                }
+                i += 1;
+            }
+        }
+        let mut query_state = world.query::<&A>();
+        let expected: Vec<_> = query_state.iter(&world).map(|&A(i)| i).collect();
+
+        // It's best to test par_fold_init_unchecked_manual directly, as `par_iter` doesn't batch
+        // results when the `ComputeTaskPool` only has one thread.
+        let batches = Mutex::new(Vec::new());
+        // SAFETY: The query is read-only and run on the world it was created for.
+        unsafe {
+            query_state.par_fold_init_unchecked_manual(
+                Vec::new,
+                world.as_unsafe_world_cell_readonly(),
+                7,
+                |mut results, &A(i)| {
+                    results.push(i);
+                    results
+                },
+                |batch, results| batches.lock().unwrap().push((batch, results)),
+                world.last_change_tick(),
+                world.read_change_tick(),
+            );
+        }
+        let mut batches = batches.into_inner().unwrap();
+        batches.sort_unstable_by_key(|(batch, _)| *batch);
+        let results: Vec<_> = batches
+            .into_iter()
+            .flat_map(|(_, results)| results)
+            .collect();
+        assert_eq!(results, expected);
+    }
 }
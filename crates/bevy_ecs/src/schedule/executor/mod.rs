@@ -9,7 +9,7 @@ pub use self::single_threaded::SingleThreadedExecutor;
 use fixedbitset::FixedBitSet;
 
 use crate::{
-    schedule::{BoxedCondition, NodeId},
+    schedule::{BoxedCondition, NodeId, SystemRunStats},
     system::BoxedSystem,
     world::World,
 };
@@ -79,6 +79,11 @@ pub struct SystemSchedule {
     ///
     /// If a set doesn't run because of its conditions, this is used to skip all systems in it.
     pub(super) systems_in_sets_with_conditions: Vec<FixedBitSet>,
+    /// Indexed by system node id.
+    /// Statistics of the current run, recorded by the executor if not empty.
+    ///
+    /// See [`ScheduleStats`](super::ScheduleStats).
+    pub(super) system_stats: Vec<SystemRunStats>,
 }
 
 impl SystemSchedule {
@@ -94,6 +99,7 @@ impl SystemSchedule {
             system_dependents: Vec::new(),
             sets_with_conditions_of_systems: Vec::new(),
             systems_in_sets_with_conditions: Vec::new(),
+            system_stats: Vec::new(),
         }
     }
 }
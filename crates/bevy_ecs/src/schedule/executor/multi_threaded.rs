@@ -4,10 +4,10 @@ use std::{
 };
 
 use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
-use bevy_utils::default;
 use bevy_utils::syncunsafecell::SyncUnsafeCell;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::{info_span, Span};
+use bevy_utils::{default, Duration, Instant};
 use std::panic::AssertUnwindSafe;
 
 use concurrent_queue::ConcurrentQueue;
@@ -17,7 +17,10 @@ use crate::{
     archetype::ArchetypeComponentId,
     prelude::Resource,
     query::Access,
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    schedule::{
+        is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemRunStats,
+        SystemSchedule,
+    },
     system::BoxedSystem,
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
@@ -32,6 +35,8 @@ struct Environment<'env, 'sys> {
     systems: &'sys [SyncUnsafeCell<BoxedSystem>],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    /// Is `true` if system run times should be measured.
+    record_stats: bool,
 }
 
 struct Conditions<'a> {
@@ -39,6 +44,8 @@ struct Conditions<'a> {
     set_conditions: &'a mut [Vec<BoxedCondition>],
     sets_with_conditions_of_systems: &'a [FixedBitSet],
     systems_in_sets_with_conditions: &'a [FixedBitSet],
+    /// Also only accessed while holding the lock on the executor state.
+    system_stats: &'a mut [SystemRunStats],
 }
 
 impl<'env, 'sys> Environment<'env, 'sys> {
@@ -47,6 +54,7 @@ impl<'env, 'sys> Environment<'env, 'sys> {
         schedule: &'sys mut SystemSchedule,
         world: &'env mut World,
     ) -> Self {
+        let record_stats = !schedule.system_stats.is_empty();
         Environment {
             executor,
             systems: SyncUnsafeCell::from_mut(schedule.systems.as_mut_slice()).as_slice_of_cells(),
@@ -55,7 +63,9 @@ impl<'env, 'sys> Environment<'env, 'sys> {
                 set_conditions: &mut schedule.set_conditions,
                 sets_with_conditions_of_systems: &schedule.sets_with_conditions_of_systems,
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
+                system_stats: &mut schedule.system_stats,
             }),
+            record_stats,
             world_cell: world.as_unsafe_world_cell(),
         }
     }
@@ -77,6 +87,8 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// How long the system ran, if stats are recorded.
+    run_time: Option<Duration>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -270,12 +282,16 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         system_index: usize,
         res: Result<(), Box<dyn Any + Send>>,
         system: &BoxedSystem,
+        start: Option<Instant>,
     ) {
         // tell the executor that the system finished
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(SystemResult {
+                system_index,
+                run_time: start.map(|start| start.elapsed()),
+            })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             eprintln!("Encountered a panic in system `{}`!", &*system.name());
@@ -357,6 +373,9 @@ impl ExecutorState {
         let _span = context.environment.executor.executor_span.enter();
 
         for result in context.environment.executor.system_completion.try_iter() {
+            if let Some(run_time) = result.run_time {
+                conditions.system_stats[result.system_index].record_run(run_time);
+            }
             self.finish_system_and_handle_dependents(result);
         }
 
@@ -423,6 +442,9 @@ impl ExecutorState {
                         context.environment.world_cell,
                     )
                 } {
+                    if context.environment.record_stats {
+                        conditions.system_stats[system_index].skipped_count += 1;
+                    }
                     self.skip_system_and_signal_dependents(system_index);
                     // signal_dependents may have set more systems to ready.
                     check_for_new_ready_systems = true;
@@ -584,6 +606,7 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            let start = context.environment.record_stats.then(Instant::now);
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
@@ -596,7 +619,7 @@ impl ExecutorState {
                     );
                 };
             }));
-            context.system_completed(system_index, res, system);
+            context.system_completed(system_index, res, system, start);
         };
 
         self.active_access
@@ -626,17 +649,19 @@ impl ExecutorState {
             let unapplied_systems = self.unapplied_systems.clone();
             self.unapplied_systems.clear();
             let task = async move {
+                let start = context.environment.record_stats.then(Instant::now);
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, res, system, start);
             };
 
             context.scope.spawn_on_scope(task);
         } else {
             let task = async move {
+                let start = context.environment.record_stats.then(Instant::now);
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                     __rust_begin_short_backtrace::run(&mut **system, world);
                 }));
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, res, system, start);
             };
 
             context.scope.spawn_on_scope(task);
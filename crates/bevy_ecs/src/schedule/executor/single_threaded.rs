@@ -1,5 +1,6 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+use bevy_utils::Instant;
 use fixedbitset::FixedBitSet;
 use std::panic::AssertUnwindSafe;
 
@@ -44,16 +45,17 @@ impl SystemExecutor for SingleThreadedExecutor {
         &mut self,
         schedule: &mut SystemSchedule,
         world: &mut World,
-        _skip_systems: Option<&FixedBitSet>,
+        skip_systems: Option<&FixedBitSet>,
     ) {
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
         #[cfg(feature = "bevy_debug_stepping")]
-        if let Some(skipped_systems) = _skip_systems {
+        if let Some(skipped_systems) = skip_systems {
             // mark skipped systems as completed
             self.completed_systems |= skipped_systems;
         }
 
+        let record_stats = !schedule.system_stats.is_empty();
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -92,12 +94,20 @@ impl SystemExecutor for SingleThreadedExecutor {
             self.completed_systems.insert(system_index);
 
             if !should_run {
+                // Systems skipped by stepping didn't have their conditions fail.
+                if record_stats && !skip_systems.is_some_and(|skip| skip.contains(system_index)) {
+                    schedule.system_stats[system_index].skipped_count += 1;
+                }
                 continue;
             }
 
             let system = &mut schedule.systems[system_index];
+            let start = record_stats.then(Instant::now);
             if is_apply_deferred(system) {
                 self.apply_deferred(schedule, world);
+                if let Some(start) = start {
+                    schedule.system_stats[system_index].record_run(start.elapsed());
+                }
                 continue;
             }
 
@@ -117,6 +127,9 @@ impl SystemExecutor for SingleThreadedExecutor {
                 eprintln!("Encountered a panic in system `{}`!", &*system.name());
                 std::panic::resume_unwind(payload);
             }
+            if let Some(start) = start {
+                schedule.system_stats[system_index].record_run(start.elapsed());
+            }
             self.unapplied_systems.insert(system_index);
         }
 
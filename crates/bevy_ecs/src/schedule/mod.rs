@@ -7,6 +7,7 @@ mod graph_utils;
 #[allow(clippy::module_inception)]
 mod schedule;
 mod set;
+mod stats;
 mod stepping;
 
 pub use self::condition::*;
@@ -15,6 +16,7 @@ pub use self::executor::*;
 use self::graph_utils::*;
 pub use self::schedule::*;
 pub use self::set::*;
+pub use self::stats::*;
 
 pub use self::graph_utils::NodeId;
 
@@ -141,7 +143,7 @@ mod tests {
 
             world.insert_resource(SystemOrder::default());
 
-            assert_eq!(world.resource::<SystemOrder>().0, vec![]);
+            assert_eq!(world.resource::<SystemOrder>().0, Vec::<u32>::new());
 
             // modify the schedule after it's been initialized and test ordering with sets
             schedule.configure_sets(TestSet::A.after(named_system));
@@ -260,7 +262,7 @@ mod tests {
             );
 
             schedule.run(&mut world);
-            assert_eq!(world.resource::<SystemOrder>().0, vec![]);
+            assert_eq!(world.resource::<SystemOrder>().0, Vec::<u32>::new());
 
             world.resource_mut::<RunConditionBool>().0 = true;
             schedule.run(&mut world);
@@ -306,7 +308,7 @@ mod tests {
             );
 
             schedule.run(&mut world);
-            assert_eq!(world.resource::<SystemOrder>().0, vec![]);
+            assert_eq!(world.resource::<SystemOrder>().0, Vec::<u32>::new());
 
             world.resource_mut::<RunConditionBool>().0 = true;
             schedule.run(&mut world);
@@ -8,7 +8,7 @@ use bevy_utils::tracing::info_span;
 use bevy_utils::{default, tracing::info};
 use bevy_utils::{
     tracing::{error, warn},
-    HashMap, HashSet,
+    HashMap, HashSet, Instant,
 };
 use fixedbitset::FixedBitSet;
 use petgraph::{algo::TarjanScc, prelude::*};
@@ -382,6 +382,21 @@ impl Schedule {
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
+        let start = if world.contains_resource::<ScheduleStats>() {
+            if self.executable.system_stats.len() != self.executable.systems.len() {
+                self.executable.system_stats = self
+                    .executable
+                    .systems
+                    .iter()
+                    .map(|system| SystemRunStats::new(system.name()))
+                    .collect();
+            }
+            Some(Instant::now())
+        } else {
+            self.executable.system_stats.clear();
+            None
+        };
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
         self.executor.run(&mut self.executable, world, None);
 
@@ -395,6 +410,16 @@ impl Schedule {
             self.executor
                 .run(&mut self.executable, world, skip_systems.as_ref());
         }
+
+        if let Some(start) = start {
+            if let Some(mut stats) = world.get_resource_mut::<ScheduleStats>() {
+                stats.record(
+                    self.label,
+                    start.elapsed(),
+                    &mut self.executable.system_stats,
+                );
+            }
+        }
     }
 
     /// Initializes any newly-added systems and conditions, rebuilds the executable schedule,
@@ -1476,6 +1501,7 @@ impl ScheduleGraph {
             system_dependents,
             sets_with_conditions_of_systems,
             systems_in_sets_with_conditions,
+            system_stats: Vec::new(),
         }
     }
 
@@ -0,0 +1,208 @@
+use std::borrow::Cow;
+
+use bevy_utils::{Duration, HashMap};
+
+use crate::{
+    self as bevy_ecs,
+    schedule::{InternedScheduleLabel, ScheduleLabel},
+    system::Resource,
+};
+
+/// Resource that enables recording how long each [`Schedule`](super::Schedule) and each of its systems
+/// take to run, and how often they run.
+///
+/// Schedules only measure their systems while this resource exists, so that the timing overhead is opt-in.
+/// Statistics accumulate across runs until they are [cleared](Self::clear), which lets a consumer
+/// such as a diagnostics plugin read them once per frame even if a schedule ran several times.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::{ScheduleLabel, ScheduleStats};
+/// #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+/// struct Update;
+///
+/// fn my_system() {}
+///
+/// let mut world = World::new();
+/// world.init_resource::<ScheduleStats>();
+///
+/// let mut schedule = Schedule::new(Update);
+/// schedule.add_systems(my_system);
+/// schedule.run(&mut world);
+///
+/// let stats = world.resource::<ScheduleStats>();
+/// let update = stats.get(Update).unwrap();
+/// assert_eq!(update.run_count, 1);
+/// assert_eq!(update.systems[0].run_count, 1);
+/// ```
+#[derive(Resource, Debug, Default)]
+pub struct ScheduleStats {
+    schedules: HashMap<InternedScheduleLabel, ScheduleRunStats>,
+}
+
+impl ScheduleStats {
+    /// Returns the statistics of the schedule with the given label, if it ran since the last [`clear`](Self::clear).
+    pub fn get(&self, label: impl ScheduleLabel) -> Option<&ScheduleRunStats> {
+        self.schedules.get(&label.intern())
+    }
+
+    /// Returns an iterator over the statistics of each schedule that ran since the last [`clear`](Self::clear).
+    pub fn iter(&self) -> impl Iterator<Item = (InternedScheduleLabel, &ScheduleRunStats)> {
+        self.schedules.iter().map(|(label, stats)| (*label, stats))
+    }
+
+    /// Resets the statistics of every schedule.
+    pub fn clear(&mut self) {
+        self.schedules.clear();
+    }
+
+    /// Adds the statistics of a single schedule run, and resets `systems` for the next one.
+    pub(super) fn record(
+        &mut self,
+        label: InternedScheduleLabel,
+        run_time: Duration,
+        systems: &mut [SystemRunStats],
+    ) {
+        let stats = self.schedules.entry(label).or_default();
+        stats.run_count += 1;
+        stats.run_time += run_time;
+
+        // The schedule may have been rebuilt with different systems since the last run.
+        if stats.systems.len() != systems.len()
+            || stats
+                .systems
+                .iter()
+                .zip(systems.iter())
+                .any(|(total, run)| total.name != run.name)
+        {
+            stats.systems = systems
+                .iter()
+                .map(|run| SystemRunStats::new(run.name.clone()))
+                .collect();
+        }
+        for (total, run) in stats.systems.iter_mut().zip(systems) {
+            total.run_count += run.run_count;
+            total.skipped_count += run.skipped_count;
+            total.run_time += run.run_time;
+            run.reset();
+        }
+    }
+}
+
+/// Statistics of a schedule, accumulated in [`ScheduleStats`].
+#[derive(Debug, Clone, Default)]
+pub struct ScheduleRunStats {
+    /// The number of times the schedule ran.
+    pub run_count: u32,
+    /// The total time spent running the schedule, including its executor's overhead.
+    pub run_time: Duration,
+    /// The statistics of each system of the schedule, in the order the executor considers them.
+    ///
+    /// This includes the [`apply_deferred`](super::apply_deferred) systems inserted as sync points.
+    pub systems: Vec<SystemRunStats>,
+}
+
+/// Statistics of a system within a schedule, accumulated in [`ScheduleStats`].
+#[derive(Debug, Clone, Default)]
+pub struct SystemRunStats {
+    /// The name of the system.
+    pub name: Cow<'static, str>,
+    /// The number of times the system ran.
+    pub run_count: u32,
+    /// The number of times the system didn't run because of its or its sets' run conditions.
+    pub skipped_count: u32,
+    /// The total time spent running the system.
+    pub run_time: Duration,
+}
+
+impl SystemRunStats {
+    pub(super) fn new(name: Cow<'static, str>) -> Self {
+        Self {
+            name,
+            ..Default::default()
+        }
+    }
+
+    pub(super) fn record_run(&mut self, run_time: Duration) {
+        self.run_count += 1;
+        self.run_time += run_time;
+    }
+
+    fn reset(&mut self) {
+        self.run_count = 0;
+        self.skipped_count = 0;
+        self.run_time = Duration::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        prelude::*,
+        schedule::{ExecutorKind, ScheduleLabel, ScheduleStats},
+    };
+
+    #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+    struct TestSchedule;
+
+    #[derive(Resource)]
+    struct Enabled(bool);
+
+    fn always() {}
+
+    fn sometimes() {}
+
+    fn run_with(kind: ExecutorKind) {
+        let mut world = World::new();
+        world.insert_resource(Enabled(false));
+
+        let mut schedule = Schedule::new(TestSchedule);
+        schedule.set_executor_kind(kind);
+        schedule.add_systems((always, sometimes.run_if(|enabled: Res<Enabled>| enabled.0)));
+
+        // Nothing is recorded without the resource.
+        schedule.run(&mut world);
+        world.init_resource::<ScheduleStats>();
+
+        schedule.run(&mut world);
+        world.resource_mut::<Enabled>().0 = true;
+        schedule.run(&mut world);
+
+        let stats = world.resource::<ScheduleStats>();
+        let run = stats.get(TestSchedule).unwrap();
+        assert_eq!(run.run_count, 2);
+        let system = |name: &str| {
+            run.systems
+                .iter()
+                .find(|system| system.name.ends_with(name))
+                .unwrap()
+        };
+        assert_eq!(system("always").run_count, 2);
+        assert_eq!(system("always").skipped_count, 0);
+        assert_eq!(system("sometimes").run_count, 1);
+        assert_eq!(system("sometimes").skipped_count, 1);
+        assert!(run.run_time >= system("always").run_time);
+
+        world.resource_mut::<ScheduleStats>().clear();
+        assert!(world
+            .resource::<ScheduleStats>()
+            .get(TestSchedule)
+            .is_none());
+    }
+
+    #[test]
+    fn single_threaded_executor_records_stats() {
+        run_with(ExecutorKind::SingleThreaded);
+    }
+
+    #[test]
+    fn simple_executor_records_stats() {
+        run_with(ExecutorKind::Simple);
+    }
+
+    #[test]
+    fn multi_threaded_executor_records_stats() {
+        run_with(ExecutorKind::MultiThreaded);
+    }
+}
@@ -1536,7 +1536,7 @@ mod tests {
             .iter(&world)
             .map(|v| v.0)
             .collect::<Vec<_>>();
-        assert_eq!(results_after_u64, vec![]);
+        assert_eq!(results_after_u64, Vec::<u64>::new());
     }
 
     #[test]
@@ -1589,7 +1589,7 @@ mod tests {
             .iter(&world)
             .map(|v| v.0)
             .collect::<Vec<_>>();
-        assert_eq!(results_after_u64, vec![]);
+        assert_eq!(results_after_u64, Vec::<u64>::new());
     }
 
     #[test]
@@ -2517,7 +2517,7 @@ mod tests {
         let b = vec![1];
         super::sorted_remove(&mut a, &b);
 
-        assert_eq!(a, vec![]);
+        assert_eq!(a, Vec::<i32>::new());
 
         let mut a = vec![1];
         let b = vec![2];
@@ -294,6 +294,58 @@ impl World {
             .init_component_with_descriptor(&mut self.storages, descriptor)
     }
 
+    /// Disassociates the given components and resources from their Rust types, so that the code
+    /// of these types can be unloaded, like when they come from a dynamic library that is about to be closed.
+    ///
+    /// Afterwards, initializing one of the types again creates a new [`ComponentId`], and the bundles
+    /// containing it are created again too. The [`ComponentInfo`]s of `ids` are kept, as archetypes may
+    /// still refer to them, but they no longer refer to the name, drop function or hooks of their type.
+    /// Values of the forgotten components and resources can't be inserted anymore.
+    ///
+    /// Returns the first id that still has a value stored in the world as an error,
+    /// in which case nothing is forgotten.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #[derive(Component)]
+    /// struct Loaded;
+    ///
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Loaded).id();
+    /// let loaded = world.component_id::<Loaded>().unwrap();
+    /// assert_eq!(world.forget_component_types(&[loaded]), Err(loaded));
+    ///
+    /// world.entity_mut(entity).remove::<Loaded>();
+    /// assert_eq!(world.forget_component_types(&[loaded]), Ok(()));
+    /// assert_eq!(world.component_id::<Loaded>(), None);
+    /// ```
+    #[cfg(feature = "component_unloading")]
+    pub fn forget_component_types(&mut self, ids: &[ComponentId]) -> Result<(), ComponentId> {
+        let is_stored = |id: ComponentId| {
+            self.archetypes
+                .iter()
+                .any(|archetype| !archetype.is_empty() && archetype.contains(id))
+                || self
+                    .storages
+                    .resources
+                    .get(id)
+                    .is_some_and(ResourceData::is_present)
+                || self
+                    .storages
+                    .non_send_resources
+                    .get(id)
+                    .is_some_and(ResourceData::is_present)
+        };
+        if let Some(&id) = ids.iter().find(|&&id| is_stored(id)) {
+            return Err(id);
+        }
+        for &id in ids {
+            self.components.forget_type(id);
+            self.bundles.forget_component(id);
+        }
+        Ok(())
+    }
+
     /// Returns the [`ComponentId`] of the given [`Component`] type `T`.
     ///
     /// The returned `ComponentId` is specific to the `World` instance
@@ -3140,6 +3192,43 @@ mod tests {
     #[derive(Component)]
     struct Baz;
 
+    #[cfg(feature = "component_unloading")]
+    #[test]
+    fn forget_component_types() {
+        let mut world = World::new();
+        let entity = world.spawn((Foo, Bar)).id();
+        let foo = world.component_id::<Foo>().unwrap();
+        world.insert_resource(TestResource2("resource".to_string()));
+        let resource = world.components().resource_id::<TestResource2>().unwrap();
+
+        assert_eq!(world.forget_component_types(&[foo]), Err(foo));
+        world.entity_mut(entity).remove::<(Foo, Bar)>();
+        // Nothing is forgotten while one of the values is still stored.
+        assert_eq!(
+            world.forget_component_types(&[foo, resource]),
+            Err(resource)
+        );
+        assert_eq!(world.component_id::<Foo>(), Some(foo));
+
+        world.remove_resource::<TestResource2>();
+        assert_eq!(world.forget_component_types(&[foo, resource]), Ok(()));
+
+        let info = world.components().get_info(foo).unwrap();
+        assert_eq!(info.type_id(), None);
+        assert!(info.name().ends_with("Foo"));
+        let info = world.components().get_info(resource).unwrap();
+        assert!(info.drop().is_none());
+        assert_eq!(world.component_id::<Foo>(), None);
+        assert_eq!(world.components().resource_id::<TestResource2>(), None);
+
+        // The bundles containing `Foo` now use the new component.
+        world.entity_mut(entity).insert((Foo, Bar));
+        let new_foo = world.component_id::<Foo>().unwrap();
+        assert_ne!(new_foo, foo);
+        assert!(world.entity(entity).contains_id(new_foo));
+        assert!(!world.entity(entity).contains_id(foo));
+    }
+
     #[test]
     fn inspect_entity_components() {
         let mut world = World::new();
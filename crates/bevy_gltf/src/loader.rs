@@ -156,6 +156,11 @@ pub struct GltfLoaderSettings {
     pub load_lights: bool,
     /// If true, the loader will include the root of the gltf root node.
     pub include_source: bool,
+    /// If true, a coarse version of each triangle mesh is made available as soon as it has been read,
+    /// while the rest of the gltf file (such as its materials and scenes) is still loading.
+    /// It is replaced by the full mesh once the file is loaded.
+    #[serde(default)]
+    pub progressive_meshes: bool,
 }
 
 impl Default for GltfLoaderSettings {
@@ -166,6 +171,7 @@ impl Default for GltfLoaderSettings {
             load_cameras: true,
             load_lights: true,
             include_source: false,
+            progressive_meshes: false,
         }
     }
 }
@@ -562,6 +568,11 @@ async fn load_gltf<'a, 'b, 'c>(
                 });
             }
 
+            if settings.progressive_meshes && load_context.can_publish_partial() {
+                if let Some(preview) = mesh_preview(&mesh) {
+                    load_context.publish_partial_labeled(primitive_label.to_string(), preview);
+                }
+            }
             let mesh_handle = load_context.add_labeled_asset(primitive_label.to_string(), mesh);
             primitives.push(super::GltfPrimitive::new(
                 &gltf_mesh,
@@ -1607,6 +1618,61 @@ fn texture_address_mode(gltf_address_mode: &WrappingMode) -> ImageAddressMode {
 
 /// Maps the `primitive_topology` form glTF to `wgpu`.
 #[allow(clippy::result_large_err)]
+/// The number of cells along each axis of the grid used by [`mesh_preview`].
+const MESH_PREVIEW_GRID_CELLS: f32 = 16.0;
+
+/// Builds a coarse version of a triangle list `mesh` by merging the vertices falling into the same cell
+/// of a grid over its bounds, and dropping the triangles that collapse.
+///
+/// Returns `None` if the mesh can't be simplified this way, or if it doesn't at least halve its vertex count.
+fn mesh_preview(mesh: &Mesh) -> Option<Mesh> {
+    if mesh.primitive_topology() != PrimitiveTopology::TriangleList || mesh.has_morph_targets() {
+        return None;
+    }
+    let positions = mesh.attribute(Mesh::ATTRIBUTE_POSITION)?.as_float3()?;
+    let (min, max) = positions.iter().map(|&position| Vec3::from(position)).fold(
+        (Vec3::INFINITY, Vec3::NEG_INFINITY),
+        |(min, max), position| (min.min(position), max.max(position)),
+    );
+    let cell_size = ((max - min) / MESH_PREVIEW_GRID_CELLS).max(Vec3::splat(f32::EPSILON));
+
+    // The first vertex of each cell represents all the vertices in it.
+    let mut cells = HashMap::default();
+    let mut representatives = Vec::new();
+    let mut remap = Vec::with_capacity(positions.len());
+    for (index, &position) in positions.iter().enumerate() {
+        let cell = ((Vec3::from(position) - min) / cell_size).as_uvec3();
+        let vertex = *cells.entry(cell).or_insert_with(|| {
+            representatives.push(index as u32);
+            representatives.len() as u32 - 1
+        });
+        remap.push(vertex);
+    }
+    if representatives.len() * 2 > positions.len() {
+        return None;
+    }
+
+    let triangles: Vec<u32> = match mesh.indices() {
+        Some(indices) => indices.iter().map(|index| remap[index]).collect(),
+        None => remap,
+    };
+    let triangles = triangles
+        .chunks_exact(3)
+        .filter(|triangle| {
+            triangle[0] != triangle[1] && triangle[1] != triangle[2] && triangle[0] != triangle[2]
+        })
+        .flatten()
+        .copied()
+        .collect();
+
+    let mut preview = mesh.clone();
+    // Keep only the attributes of the representative vertices, in order.
+    preview.insert_indices(Indices::U32(representatives));
+    preview.duplicate_vertices();
+    preview.insert_indices(Indices::U32(triangles));
+    Some(preview)
+}
+
 fn get_primitive_topology(mode: Mode) -> Result<PrimitiveTopology, GltfError> {
     match mode {
         Mode::Points => Ok(PrimitiveTopology::PointList),
@@ -2010,7 +2076,7 @@ mod test {
             memory::{Dir, MemoryAssetReader},
             AssetSource, AssetSourceId,
         },
-        AssetApp, AssetPlugin, AssetServer, Assets, Handle, LoadState,
+        AssetApp, AssetLoader, AssetPlugin, AssetServer, Assets, Handle, LoadState,
     };
     use bevy_core::TaskPoolPlugin;
     use bevy_ecs::world::World;
@@ -2347,4 +2413,130 @@ mod test {
         let load_state = asset_server.get_load_state(handle_id).unwrap();
         assert!(matches!(load_state, LoadState::Failed(_)));
     }
+
+    /// Loads gltf files like [`GltfLoader`](super::GltfLoader), then waits for the test to release the load.
+    struct GatedGltfLoader {
+        loader: super::GltfLoader,
+        release: std::sync::Arc<async_lock::Semaphore>,
+    }
+
+    impl AssetLoader for GatedGltfLoader {
+        type Asset = Gltf;
+        type Settings = super::GltfLoaderSettings;
+        type Error = super::GltfError;
+
+        async fn load<'a>(
+            &'a self,
+            reader: &'a mut dyn bevy_asset::io::Reader,
+            settings: &'a Self::Settings,
+            load_context: &'a mut bevy_asset::LoadContext<'_>,
+        ) -> Result<Gltf, Self::Error> {
+            let gltf = self.loader.load(reader, settings, load_context).await?;
+            self.release.acquire().await.forget();
+            Ok(gltf)
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["gated_gltf"]
+        }
+    }
+
+    /// Returns a gltf file containing a single mesh: a flat grid of `size` by `size` vertices.
+    fn grid_gltf(size: u32) -> String {
+        use base64::Engine;
+
+        let mut buffer = Vec::new();
+        for z in 0..size {
+            for x in 0..size {
+                for coordinate in [x as f32, 0.0, z as f32] {
+                    buffer.extend(coordinate.to_le_bytes());
+                }
+            }
+        }
+        let positions_length = buffer.len();
+        for z in 0..size - 1 {
+            for x in 0..size - 1 {
+                let i = z * size + x;
+                for index in [i, i + size, i + 1, i + 1, i + size, i + size + 1] {
+                    buffer.extend(index.to_le_bytes());
+                }
+            }
+        }
+        let max = size - 1;
+        let index_count = (size - 1) * (size - 1) * 6;
+        format!(
+            r#"{{
+    "asset": {{ "version": "2.0" }},
+    "buffers": [{{
+        "byteLength": {},
+        "uri": "data:application/octet-stream;base64,{}"
+    }}],
+    "bufferViews": [
+        {{ "buffer": 0, "byteOffset": 0, "byteLength": {positions_length} }},
+        {{ "buffer": 0, "byteOffset": {positions_length}, "byteLength": {} }}
+    ],
+    "accessors": [
+        {{
+            "bufferView": 0, "componentType": 5126, "count": {}, "type": "VEC3",
+            "min": [0, 0, 0], "max": [{max}, 0, {max}]
+        }},
+        {{ "bufferView": 1, "componentType": 5125, "count": {index_count}, "type": "SCALAR" }}
+    ],
+    "meshes": [{{ "primitives": [{{ "attributes": {{ "POSITION": 0 }}, "indices": 1 }}] }}]
+}}"#,
+            buffer.len(),
+            base64::engine::general_purpose::STANDARD.encode(&buffer),
+            buffer.len() - positions_length,
+            size * size,
+        )
+    }
+
+    #[test]
+    fn progressive_meshes() {
+        use bevy_render::{mesh::Mesh, texture::CompressedImageFormats};
+
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("grid.gated_gltf"), &grid_gltf(33));
+        let mut app = test_app(dir);
+        let release = std::sync::Arc::new(async_lock::Semaphore::new(0));
+        app.init_asset::<Mesh>()
+            .register_asset_loader(GatedGltfLoader {
+                loader: super::GltfLoader {
+                    supported_compressed_formats: CompressedImageFormats::NONE,
+                    custom_vertex_attributes: Default::default(),
+                },
+                release: release.clone(),
+            });
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let gltf: Handle<Gltf> = asset_server.load_with_settings(
+            "grid.gated_gltf",
+            |settings: &mut super::GltfLoaderSettings| settings.progressive_meshes = true,
+        );
+        let mesh: Handle<Mesh> = asset_server.load(
+            GltfAssetLabel::Primitive {
+                mesh: 0,
+                primitive: 0,
+            }
+            .from_asset("grid.gated_gltf"),
+        );
+
+        // The coarse mesh is available while the gltf file is still loading.
+        let mut preview_vertices = 0;
+        run_app_until(&mut app, |world| {
+            let preview = world.resource::<Assets<Mesh>>().get(&mesh)?;
+            preview_vertices = preview.count_vertices();
+            assert!(preview.indices().is_some_and(|indices| !indices.is_empty()));
+            Some(())
+        });
+        assert!(preview_vertices * 2 <= 33 * 33);
+        assert_eq!(asset_server.load_state(&gltf), LoadState::Loading);
+
+        release.add_permits(1);
+        run_app_until(&mut app, |_| {
+            (asset_server.load_state(&gltf) == LoadState::Loaded).then_some(())
+        });
+        let meshes = app.world().resource::<Assets<Mesh>>();
+        // The vertices of each triangle are duplicated to compute its flat normals.
+        assert_eq!(meshes.get(&mesh).unwrap().count_vertices(), 32 * 32 * 6);
+    }
 }
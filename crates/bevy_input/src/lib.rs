@@ -19,10 +19,12 @@ pub mod gamepad;
 pub mod gestures;
 pub mod keyboard;
 pub mod mouse;
+mod simulation;
 pub mod touch;
 
 pub use axis::*;
 pub use button_input::*;
+pub use simulation::*;
 
 /// Most commonly used re-exported types.
 pub mod prelude {
@@ -0,0 +1,209 @@
+use bevy_app::App;
+use bevy_ecs::entity::Entity;
+use bevy_math::Vec2;
+
+use crate::{
+    gamepad::{
+        Gamepad, GamepadAxisChangedEvent, GamepadAxisType, GamepadButtonChangedEvent,
+        GamepadButtonType, GamepadConnection, GamepadConnectionEvent, GamepadEvent, GamepadInfo,
+    },
+    keyboard::{Key, KeyCode, KeyboardInput, NativeKey},
+    mouse::{MouseButton, MouseButtonInput, MouseMotion},
+    ButtonState,
+};
+
+/// Extension trait for [`App`] to simulate input, mainly for tests.
+///
+/// Each method sends the same raw input event a windowing or gamepad backend would,
+/// so the [`InputPlugin`](crate::InputPlugin) systems update [`ButtonInput`](crate::ButtonInput)
+/// and [`Axis`](crate::Axis) during the next update, and systems reading the events see them as well.
+/// Simulated events don't belong to any window, so their `window` is [`Entity::PLACEHOLDER`].
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_input::{prelude::*, InputPlugin, InputSimulationExt};
+/// let mut app = App::new();
+/// app.add_plugins(InputPlugin);
+///
+/// app.press_key(KeyCode::Space).update();
+/// assert!(app.world().resource::<ButtonInput<KeyCode>>().just_pressed(KeyCode::Space));
+///
+/// app.release_key(KeyCode::Space).update();
+/// assert!(app.world().resource::<ButtonInput<KeyCode>>().just_released(KeyCode::Space));
+/// ```
+pub trait InputSimulationExt {
+    /// Sends a [`KeyboardInput`] event pressing `key_code`.
+    fn press_key(&mut self, key_code: KeyCode) -> &mut Self;
+
+    /// Sends a [`KeyboardInput`] event releasing `key_code`.
+    fn release_key(&mut self, key_code: KeyCode) -> &mut Self;
+
+    /// Sends a [`MouseButtonInput`] event pressing `button`.
+    fn press_mouse_button(&mut self, button: MouseButton) -> &mut Self;
+
+    /// Sends a [`MouseButtonInput`] event releasing `button`.
+    fn release_mouse_button(&mut self, button: MouseButton) -> &mut Self;
+
+    /// Sends a [`MouseMotion`] event moving the mouse by `delta`.
+    fn move_mouse(&mut self, delta: Vec2) -> &mut Self;
+
+    /// Sends a [`GamepadEvent`] connecting `gamepad`.
+    ///
+    /// Gamepads must be connected before their buttons and axes are tracked.
+    fn connect_gamepad(&mut self, gamepad: Gamepad) -> &mut Self;
+
+    /// Sends a [`GamepadEvent`] disconnecting `gamepad`.
+    fn disconnect_gamepad(&mut self, gamepad: Gamepad) -> &mut Self;
+
+    /// Sends a [`GamepadEvent`] setting the value of `button` on `gamepad`, between `0.0` and `1.0`.
+    fn set_gamepad_button(
+        &mut self,
+        gamepad: Gamepad,
+        button: GamepadButtonType,
+        value: f32,
+    ) -> &mut Self;
+
+    /// Sends a [`GamepadEvent`] setting the value of `axis` on `gamepad`, between `-1.0` and `1.0`.
+    fn set_gamepad_axis(
+        &mut self,
+        gamepad: Gamepad,
+        axis: GamepadAxisType,
+        value: f32,
+    ) -> &mut Self;
+}
+
+impl InputSimulationExt for App {
+    fn press_key(&mut self, key_code: KeyCode) -> &mut Self {
+        send_keyboard_input(self, key_code, ButtonState::Pressed)
+    }
+
+    fn release_key(&mut self, key_code: KeyCode) -> &mut Self {
+        send_keyboard_input(self, key_code, ButtonState::Released)
+    }
+
+    fn press_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        send_mouse_button_input(self, button, ButtonState::Pressed)
+    }
+
+    fn release_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        send_mouse_button_input(self, button, ButtonState::Released)
+    }
+
+    fn move_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.world_mut().send_event(MouseMotion { delta });
+        self
+    }
+
+    fn connect_gamepad(&mut self, gamepad: Gamepad) -> &mut Self {
+        let info = GamepadInfo {
+            name: format!("Simulated gamepad {}", gamepad.id),
+        };
+        self.world_mut()
+            .send_event(GamepadEvent::Connection(GamepadConnectionEvent::new(
+                gamepad,
+                GamepadConnection::Connected(info),
+            )));
+        self
+    }
+
+    fn disconnect_gamepad(&mut self, gamepad: Gamepad) -> &mut Self {
+        self.world_mut()
+            .send_event(GamepadEvent::Connection(GamepadConnectionEvent::new(
+                gamepad,
+                GamepadConnection::Disconnected,
+            )));
+        self
+    }
+
+    fn set_gamepad_button(
+        &mut self,
+        gamepad: Gamepad,
+        button: GamepadButtonType,
+        value: f32,
+    ) -> &mut Self {
+        self.world_mut()
+            .send_event(GamepadEvent::Button(GamepadButtonChangedEvent::new(
+                gamepad, button, value,
+            )));
+        self
+    }
+
+    fn set_gamepad_axis(
+        &mut self,
+        gamepad: Gamepad,
+        axis: GamepadAxisType,
+        value: f32,
+    ) -> &mut Self {
+        self.world_mut()
+            .send_event(GamepadEvent::Axis(GamepadAxisChangedEvent::new(
+                gamepad, axis, value,
+            )));
+        self
+    }
+}
+
+fn send_keyboard_input(app: &mut App, key_code: KeyCode, state: ButtonState) -> &mut App {
+    app.world_mut().send_event(KeyboardInput {
+        key_code,
+        logical_key: Key::Unidentified(NativeKey::Unidentified),
+        state,
+        repeat: false,
+        window: Entity::PLACEHOLDER,
+    });
+    app
+}
+
+fn send_mouse_button_input(app: &mut App, button: MouseButton, state: ButtonState) -> &mut App {
+    app.world_mut().send_event(MouseButtonInput {
+        button,
+        state,
+        window: Entity::PLACEHOLDER,
+    });
+    app
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+
+    use crate::{
+        gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType},
+        mouse::MouseButton,
+        Axis, ButtonInput, InputPlugin, InputSimulationExt,
+    };
+
+    #[test]
+    fn simulated_input_updates_button_input_and_axes() {
+        let mut app = App::new();
+        app.add_plugins(InputPlugin);
+        let gamepad = Gamepad::new(0);
+        let south = GamepadButton::new(gamepad, GamepadButtonType::South);
+        let left_x = GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX);
+
+        app.connect_gamepad(gamepad).update();
+        app.press_mouse_button(MouseButton::Left)
+            .set_gamepad_button(gamepad, GamepadButtonType::South, 1.0)
+            .set_gamepad_axis(gamepad, GamepadAxisType::LeftStickX, 0.5)
+            .update();
+
+        let world = app.world();
+        assert!(world
+            .resource::<ButtonInput<MouseButton>>()
+            .just_pressed(MouseButton::Left));
+        assert!(world
+            .resource::<ButtonInput<GamepadButton>>()
+            .just_pressed(south));
+        assert_eq!(world.resource::<Axis<GamepadAxis>>().get(left_x), Some(0.5));
+
+        app.release_mouse_button(MouseButton::Left)
+            .set_gamepad_button(gamepad, GamepadButtonType::South, 0.0)
+            .update();
+        let world = app.world();
+        assert!(!world
+            .resource::<ButtonInput<MouseButton>>()
+            .pressed(MouseButton::Left));
+        assert!(world
+            .resource::<ButtonInput<GamepadButton>>()
+            .just_released(south));
+    }
+}
@@ -24,6 +24,7 @@ mod kw {
     syn::custom_keyword!(PartialEq);
     syn::custom_keyword!(Hash);
     syn::custom_keyword!(no_field_bounds);
+    syn::custom_keyword!(methods);
 }
 
 // The "special" trait idents that are used internally for reflection.
@@ -189,9 +190,20 @@ pub(crate) struct ContainerAttributes {
     custom_where: Option<WhereClause>,
     no_field_bounds: bool,
     custom_attributes: CustomAttributes,
+    methods: Vec<ReflectedMethod>,
     idents: Vec<Ident>,
 }
 
+/// An inherent method registered with `#[reflect(methods(...))]`.
+#[derive(Clone)]
+pub(crate) struct ReflectedMethod {
+    /// The name the method is registered under.
+    pub name: Ident,
+    /// A custom expression to convert into the method's `DynamicFunction`, if any.
+    #[cfg_attr(not(feature = "functions"), allow(dead_code))]
+    pub function: Option<Expr>,
+}
+
 impl ContainerAttributes {
     /// Parse a comma-separated list of container attributes.
     ///
@@ -239,6 +251,8 @@ impl ContainerAttributes {
             self.parse_type_path(input, trait_)
         } else if lookahead.peek(kw::no_field_bounds) {
             self.parse_no_field_bounds(input)
+        } else if lookahead.peek(kw::methods) {
+            self.parse_methods(input)
         } else if lookahead.peek(kw::Debug) {
             self.parse_debug(input)
         } else if lookahead.peek(kw::PartialEq) {
@@ -347,6 +361,44 @@ impl ContainerAttributes {
         Ok(())
     }
 
+    /// Parse `methods` attribute.
+    ///
+    /// Examples:
+    /// - `#[reflect(methods(length, normalize))]`
+    /// - `#[reflect(methods(looking_at = Self::looking_at as fn(Self, Vec3, Dir3) -> Self))]`
+    fn parse_methods(&mut self, input: ParseStream) -> syn::Result<()> {
+        input.parse::<kw::methods>()?;
+
+        let content;
+        parenthesized!(content in input);
+        let methods = terminated_parser(Token![,], |stream| {
+            let name = stream.parse::<Ident>()?;
+            let function = if stream.peek(Token![=]) {
+                stream.parse::<Token![=]>()?;
+                Some(stream.parse::<Expr>()?)
+            } else {
+                None
+            };
+            Ok(ReflectedMethod { name, function })
+        })(&content)?;
+
+        for method in methods {
+            if self
+                .methods
+                .iter()
+                .any(|existing| existing.name == method.name)
+            {
+                return Err(syn::Error::new(
+                    method.name.span(),
+                    format!("method `{}` is already registered", method.name),
+                ));
+            }
+            self.methods.push(method);
+        }
+
+        Ok(())
+    }
+
     /// Parse `where` attribute.
     ///
     /// Examples:
@@ -433,6 +485,12 @@ impl ContainerAttributes {
         &self.idents
     }
 
+    /// The inherent methods registered with `#[reflect(methods(...))]`.
+    #[cfg_attr(not(feature = "functions"), allow(dead_code))]
+    pub fn methods(&self) -> &[ReflectedMethod] {
+        &self.methods
+    }
+
     /// The `FromReflect` configuration found within `#[reflect(...)]` attributes on this type.
     #[allow(clippy::wrong_self_convention)]
     pub fn from_reflect_attrs(&self) -> &FromReflectAttrs {
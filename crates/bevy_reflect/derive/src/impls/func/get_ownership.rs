@@ -23,12 +23,20 @@ pub(crate) fn impl_get_ownership(
             fn ownership() -> #bevy_reflect::func::args::Ownership {
                 #bevy_reflect::func::args::Ownership::Ref
             }
+
+            fn owned_type_id() -> ::core::any::TypeId {
+                ::core::any::TypeId::of::<#type_path #ty_generics>()
+            }
         }
 
         impl #impl_generics #bevy_reflect::func::args::GetOwnership for &'_ mut #type_path #ty_generics #where_reflect_clause {
             fn ownership() -> #bevy_reflect::func::args::Ownership {
                 #bevy_reflect::func::args::Ownership::Mut
             }
+
+            fn owned_type_id() -> ::core::any::TypeId {
+                ::core::any::TypeId::of::<#type_path #ty_generics>()
+            }
         }
     }
 }
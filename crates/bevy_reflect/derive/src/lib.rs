@@ -304,6 +304,38 @@ fn match_reflect_impls(ast: DeriveInput, source: ReflectImplSource) -> TokenStre
 /// struct Id(u8);
 /// ```
 ///
+/// ## `#[reflect(methods(...))]`
+///
+/// This attribute registers inherent methods of the type as `ReflectMethods` type data,
+/// allowing them to be looked up and called by name (e.g. `Vector::length`).
+///
+/// Each method is converted into a `DynamicFunction` using `IntoFunction`.
+/// Methods that can't be converted directly, such as those with `impl Trait` arguments,
+/// can instead be given an expression to convert, like a cast to a concrete function pointer.
+///
+/// This attribute is ignored unless the `functions` feature is enabled.
+///
+/// ### Example
+///
+/// ```ignore
+/// #[derive(Reflect)]
+/// #[reflect(methods(length, with_x = Self::with_x as fn(Self, f32) -> Self))]
+/// struct Vector {
+///   x: f32,
+///   y: f32,
+/// }
+///
+/// impl Vector {
+///   fn length(&self) -> f32 {
+///     (self.x * self.x + self.y * self.y).sqrt()
+///   }
+///
+///   fn with_x(self, x: impl Into<f32>) -> Self {
+///     Self { x: x.into(), ..self }
+///   }
+/// }
+/// ```
+///
 /// # Field Attributes
 ///
 /// Along with the container attributes, this macro comes with some attributes that may be applied
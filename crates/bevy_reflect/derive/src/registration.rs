@@ -38,6 +38,28 @@ pub(crate) fn impl_get_type_registration<'a>(
         None
     };
 
+    #[cfg(not(feature = "functions"))]
+    let methods_data = None::<proc_macro2::TokenStream>;
+    #[cfg(feature = "functions")]
+    let methods_data = (!meta.attrs().methods().is_empty()).then(|| {
+        let methods = meta.attrs().methods().iter().map(|method| {
+            let name = method.name.to_string();
+            let function = match &method.function {
+                Some(function) => quote!(#function),
+                None => {
+                    let ident = &method.name;
+                    quote!(Self::#ident)
+                }
+            };
+            quote!(.with_method(#name, #function))
+        });
+        quote! {
+            registration.insert::<#bevy_reflect_path::func::ReflectMethods>(
+                #bevy_reflect_path::func::ReflectMethods::new() #(#methods)*
+            );
+        }
+    });
+
     let serialization_data = serialization_data.map(|data| {
         let serialization_data = data.as_serialization_data(bevy_reflect_path);
         quote! {
@@ -53,6 +75,7 @@ pub(crate) fn impl_get_type_registration<'a>(
                 registration.insert::<#bevy_reflect_path::ReflectFromPtr>(#bevy_reflect_path::FromType::<Self>::from_type());
                 #from_reflect_data
                 #serialization_data
+                #methods_data
                 #(registration.insert::<#registration_data>(#bevy_reflect_path::FromType::<Self>::from_type());)*
                 registration
             }
@@ -0,0 +1,180 @@
+use thiserror::Error;
+
+use crate::{ApplyError, ListDiffOp, MapDiffOp, Reflect, ReflectDiff, ReflectKind, ReflectMut};
+
+/// An error that occurs when a [`ReflectDiff`] does not match the value it is applied to.
+#[derive(Error, Debug)]
+pub enum ApplyDiffError {
+    #[error("expected a {expected} but found a {actual}")]
+    /// The diff was computed for a different [kind](ReflectKind) of value.
+    MismatchedKinds {
+        expected: ReflectKind,
+        actual: ReflectKind,
+    },
+
+    #[error("no field named `{0}`")]
+    /// A struct did not have a field that the diff changes.
+    MissingField(Box<str>),
+
+    #[error("no field or element at index {0}")]
+    /// A tuple, tuple struct, array, enum or list did not have an index that the diff changes.
+    MissingIndex(usize),
+
+    #[error("expected enum variant `{expected}` but found `{actual}`")]
+    /// The diff changes the fields of a different enum variant.
+    MismatchedVariant {
+        expected: Box<str>,
+        actual: Box<str>,
+    },
+
+    #[error("no map entry for key `{0}`")]
+    /// A map did not have an entry that the diff changes or removes.
+    MissingKey(Box<str>),
+
+    #[error(transparent)]
+    /// A replaced value could not be applied.
+    Apply(#[from] ApplyError),
+}
+
+/// Applies a [`ReflectDiff`] computed with [`diff`](crate::diff) to `target`.
+///
+/// Applying `diff(a, b)` to a value equal to `a` makes it equal to `b`. The diff can also be
+/// applied to other values of the same type, in which case only the parts of the value that
+/// differ between `a` and `b` are changed.
+///
+/// If an error is returned, `target` may have been partially modified.
+pub fn apply_diff(target: &mut dyn Reflect, diff: &ReflectDiff) -> Result<(), ApplyDiffError> {
+    match diff {
+        ReflectDiff::Unchanged => {}
+        ReflectDiff::Replaced(value) => {
+            // Cloned values are only concrete for value types, everything else is a dynamic
+            // value that needs to be applied instead.
+            if let Err(value) = target.set(value.clone_value()) {
+                target.try_apply(value.as_ref())?;
+            }
+        }
+        ReflectDiff::Struct(fields) => {
+            let ReflectMut::Struct(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::Struct, target));
+            };
+            for (name, diff) in fields {
+                let field = target
+                    .field_mut(name)
+                    .ok_or_else(|| ApplyDiffError::MissingField(name.as_str().into()))?;
+                apply_diff(field, diff)?;
+            }
+        }
+        ReflectDiff::TupleStruct(fields) => {
+            let ReflectMut::TupleStruct(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::TupleStruct, target));
+            };
+            for (index, diff) in fields {
+                let field = target
+                    .field_mut(*index)
+                    .ok_or(ApplyDiffError::MissingIndex(*index))?;
+                apply_diff(field, diff)?;
+            }
+        }
+        ReflectDiff::Tuple(fields) => {
+            let ReflectMut::Tuple(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::Tuple, target));
+            };
+            for (index, diff) in fields {
+                let field = target
+                    .field_mut(*index)
+                    .ok_or(ApplyDiffError::MissingIndex(*index))?;
+                apply_diff(field, diff)?;
+            }
+        }
+        ReflectDiff::Array(elements) => {
+            let ReflectMut::Array(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::Array, target));
+            };
+            for (index, diff) in elements {
+                let element = target
+                    .get_mut(*index)
+                    .ok_or(ApplyDiffError::MissingIndex(*index))?;
+                apply_diff(element, diff)?;
+            }
+        }
+        ReflectDiff::List(ops) => {
+            let ReflectMut::List(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::List, target));
+            };
+            for op in ops {
+                match op {
+                    ListDiffOp::Insert { index, value } => {
+                        if *index > target.len() {
+                            return Err(ApplyDiffError::MissingIndex(*index));
+                        }
+                        target.insert(*index, value.clone_value());
+                    }
+                    ListDiffOp::Remove { index } => {
+                        if *index >= target.len() {
+                            return Err(ApplyDiffError::MissingIndex(*index));
+                        }
+                        target.remove(*index);
+                    }
+                    ListDiffOp::Modify { index, diff } => {
+                        let element = target
+                            .get_mut(*index)
+                            .ok_or(ApplyDiffError::MissingIndex(*index))?;
+                        apply_diff(element, diff)?;
+                    }
+                }
+            }
+        }
+        ReflectDiff::Map(ops) => {
+            let ReflectMut::Map(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::Map, target));
+            };
+            for op in ops {
+                match op {
+                    MapDiffOp::Insert { key, value } => {
+                        target.insert_boxed(key.clone_value(), value.clone_value());
+                    }
+                    MapDiffOp::Remove { key } => {
+                        target
+                            .remove(key.as_ref())
+                            .ok_or_else(|| missing_key(key.as_ref()))?;
+                    }
+                    MapDiffOp::Modify { key, diff } => {
+                        let value = target
+                            .get_mut(key.as_ref())
+                            .ok_or_else(|| missing_key(key.as_ref()))?;
+                        apply_diff(value, diff)?;
+                    }
+                }
+            }
+        }
+        ReflectDiff::Enum { variant, fields } => {
+            let ReflectMut::Enum(target) = target.reflect_mut() else {
+                return Err(mismatched_kinds(ReflectKind::Enum, target));
+            };
+            if target.variant_name() != variant {
+                return Err(ApplyDiffError::MismatchedVariant {
+                    expected: variant.as_str().into(),
+                    actual: target.variant_name().into(),
+                });
+            }
+            for (index, diff) in fields {
+                let field = target
+                    .field_at_mut(*index)
+                    .ok_or(ApplyDiffError::MissingIndex(*index))?;
+                apply_diff(field, diff)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn mismatched_kinds(expected: ReflectKind, actual: &dyn Reflect) -> ApplyDiffError {
+    ApplyDiffError::MismatchedKinds {
+        expected,
+        actual: actual.reflect_kind(),
+    }
+}
+
+fn missing_key(key: &dyn Reflect) -> ApplyDiffError {
+    ApplyDiffError::MissingKey(format!("{key:?}").into())
+}
@@ -0,0 +1,259 @@
+mod apply;
+mod reflect_diff;
+
+pub use apply::*;
+pub use reflect_diff::*;
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_reflect;
+    use crate::{
+        apply_diff, diff,
+        serde::{ReflectDiffDeserializer, ReflectDiffSerializer},
+        ApplyDiffError, ListDiffOp, MapDiffOp, Reflect, ReflectDiff, TypeRegistry,
+    };
+    use bevy_utils::HashMap;
+    use bincode::Options;
+    use serde::de::DeserializeSeed;
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Player {
+        name: String,
+        position: (f32, f32),
+        inventory: Vec<Item>,
+        stats: HashMap<String, u32>,
+        state: PlayerState,
+        slots: [u8; 3],
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Item(String, u32);
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum PlayerState {
+        Idle,
+        Walking { speed: f32 },
+        Attacking(u32),
+    }
+
+    fn player() -> Player {
+        Player {
+            name: "Ferris".to_string(),
+            position: (1.0, 2.0),
+            inventory: vec![
+                Item("sword".to_string(), 1),
+                Item("potion".to_string(), 3),
+                Item("shield".to_string(), 1),
+            ],
+            stats: HashMap::from_iter([("strength".to_string(), 5), ("speed".to_string(), 3)]),
+            state: PlayerState::Walking { speed: 1.0 },
+            slots: [0, 1, 2],
+        }
+    }
+
+    fn changed_player() -> Player {
+        Player {
+            name: "Ferris".to_string(),
+            position: (1.0, 4.0),
+            inventory: vec![
+                Item("sword".to_string(), 1),
+                Item("potion".to_string(), 2),
+                Item("bow".to_string(), 1),
+                Item("arrows".to_string(), 20),
+            ],
+            stats: HashMap::from_iter([("strength".to_string(), 6), ("magic".to_string(), 1)]),
+            state: PlayerState::Walking { speed: 2.0 },
+            slots: [0, 7, 2],
+        }
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Player>();
+        registry.register::<(f32, f32)>();
+        registry.register::<Vec<Item>>();
+        registry.register::<HashMap<String, u32>>();
+        registry.register::<[u8; 3]>();
+        registry
+    }
+
+    #[test]
+    fn should_diff_equal_values_as_unchanged() {
+        assert!(diff(&player(), &player()).is_unchanged());
+    }
+
+    #[test]
+    fn should_only_contain_changed_fields() {
+        let ReflectDiff::Struct(fields) = diff(&player(), &changed_player()) else {
+            panic!("expected a struct diff");
+        };
+        let names: Vec<_> = fields.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            vec!["position", "inventory", "stats", "state", "slots"],
+            names
+        );
+
+        let (_, ReflectDiff::Tuple(position)) = &fields[0] else {
+            panic!("expected a tuple diff");
+        };
+        assert_eq!(1, position.len());
+        assert_eq!(1, position[0].0);
+
+        let (_, ReflectDiff::Enum { variant, fields }) = &fields[3] else {
+            panic!("expected an enum diff");
+        };
+        assert_eq!("Walking", variant);
+        assert_eq!(1, fields.len());
+    }
+
+    #[test]
+    fn should_apply_diff() {
+        let mut value = player();
+        apply_diff(&mut value, &diff(&player(), &changed_player())).unwrap();
+        assert_eq!(changed_player(), value);
+
+        let mut value = changed_player();
+        apply_diff(&mut value, &diff(&changed_player(), &player())).unwrap();
+        assert_eq!(player(), value);
+    }
+
+    #[test]
+    fn should_replace_enum_with_different_variant() {
+        let diff = diff(&PlayerState::Idle, &PlayerState::Attacking(3));
+        assert!(matches!(diff, ReflectDiff::Replaced(_)));
+
+        let mut value = PlayerState::Idle;
+        apply_diff(&mut value, &diff).unwrap();
+        assert_eq!(PlayerState::Attacking(3), value);
+    }
+
+    #[test]
+    fn should_diff_lists_with_insert_and_remove() {
+        let a = vec![1, 2, 3, 4, 5];
+        let b = vec![0, 1, 2, 4, 5, 6];
+
+        let ReflectDiff::List(ops) = diff(&a, &b) else {
+            panic!("expected a list diff");
+        };
+        assert!(matches!(ops[0], ListDiffOp::Insert { index: 0, .. }));
+        assert!(matches!(ops[1], ListDiffOp::Remove { index: 3 }));
+        assert!(matches!(ops[2], ListDiffOp::Insert { index: 5, .. }));
+        assert_eq!(3, ops.len());
+
+        let mut value = a.clone();
+        apply_diff(&mut value, &ReflectDiff::List(ops)).unwrap();
+        assert_eq!(b, value);
+    }
+
+    #[test]
+    fn should_diff_lists_with_modified_elements() {
+        let a = vec![Item("a".to_string(), 1), Item("b".to_string(), 1)];
+        let b = vec![Item("a".to_string(), 1), Item("b".to_string(), 2)];
+
+        let ReflectDiff::List(ops) = diff(&a, &b) else {
+            panic!("expected a list diff");
+        };
+        assert_eq!(1, ops.len());
+        assert!(matches!(ops[0], ListDiffOp::Modify { index: 1, .. }));
+    }
+
+    #[test]
+    fn should_diff_large_lists() {
+        // Too large to compare every pair of elements once the common prefix and suffix are skipped.
+        let a: Vec<u32> = (0..10_000).collect();
+        let mut b = a.clone();
+        b.insert(5_000, 0);
+        b.remove(1);
+        b.push(2);
+        b[2_000] = 3;
+        let mut rotated = a.clone();
+        rotated.rotate_left(1);
+
+        for (a, b) in [(&a, &b), (&b, &a), (&a, &rotated), (&a, &Vec::new())] {
+            let mut value = a.clone();
+            apply_diff(&mut value, &diff(a, b)).unwrap();
+            assert_eq!(*b, value);
+        }
+
+        // Only the middle element changed, which is found without comparing other elements.
+        let mut b = a.clone();
+        b[5_000] = 0;
+        let ReflectDiff::List(ops) = diff(&a, &b) else {
+            panic!("expected a list diff");
+        };
+        assert_eq!(1, ops.len());
+        assert!(matches!(ops[0], ListDiffOp::Modify { index: 5_000, .. }));
+    }
+
+    #[test]
+    fn should_diff_maps() {
+        let ReflectDiff::Struct(fields) = diff(&player(), &changed_player()) else {
+            panic!("expected a struct diff");
+        };
+        let (_, ReflectDiff::Map(ops)) = &fields[2] else {
+            panic!("expected a map diff");
+        };
+        assert_eq!(3, ops.len());
+        assert!(ops.iter().any(|op| matches!(op, MapDiffOp::Remove { .. })));
+        assert!(ops.iter().any(|op| matches!(op, MapDiffOp::Modify { .. })));
+        assert!(ops.iter().any(|op| matches!(op, MapDiffOp::Insert { .. })));
+    }
+
+    #[test]
+    fn should_fail_to_apply_to_different_enum_variant() {
+        let diff = diff(
+            &PlayerState::Walking { speed: 1.0 },
+            &PlayerState::Walking { speed: 2.0 },
+        );
+        let mut value = PlayerState::Idle;
+        let result = apply_diff(&mut value, &diff);
+        assert!(matches!(
+            result,
+            Err(ApplyDiffError::MismatchedVariant { .. })
+        ));
+    }
+
+    #[test]
+    fn should_fail_to_remove_missing_list_element() {
+        let diff = diff(&vec![1, 2, 3], &vec![1, 2]);
+        let mut value: Vec<i32> = Vec::new();
+        let result = apply_diff(&mut value, &diff);
+        assert!(matches!(result, Err(ApplyDiffError::MissingIndex(2))));
+    }
+
+    #[test]
+    fn should_roundtrip_serialized_diff() {
+        let registry = registry();
+        let diff = diff(&player(), &changed_player());
+
+        let serializer = ReflectDiffSerializer::new(&diff, &registry);
+        let output = ron::to_string(&serializer).unwrap();
+
+        let mut deserializer = ron::de::Deserializer::from_str(&output).unwrap();
+        let deserialized = ReflectDiffDeserializer::new(&registry)
+            .deserialize(&mut deserializer)
+            .unwrap();
+
+        let mut value = player();
+        apply_diff(&mut value, &deserialized).unwrap();
+        assert_eq!(changed_player(), value);
+    }
+
+    #[test]
+    fn should_roundtrip_serialized_diff_with_bincode() {
+        let registry = registry();
+        let diff = diff(&player(), &changed_player());
+
+        let serializer = ReflectDiffSerializer::new(&diff, &registry);
+        let output = bincode::serialize(&serializer).unwrap();
+
+        let deserialized = bincode::DefaultOptions::new()
+            .with_fixint_encoding()
+            .deserialize_seed(ReflectDiffDeserializer::new(&registry), &output)
+            .unwrap();
+
+        let mut value = player();
+        apply_diff(&mut value, &deserialized).unwrap();
+        assert_eq!(changed_player(), value);
+    }
+}
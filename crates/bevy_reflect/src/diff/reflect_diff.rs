@@ -0,0 +1,409 @@
+use std::ops::Range;
+
+use crate::{Enum, List, Map, Reflect, ReflectRef, Struct};
+
+/// The structural difference between two reflected values, as returned by [`diff`].
+///
+/// Applying it to the first value with [`apply_diff`] turns that value into the second one.
+/// Only the parts of the value that changed are stored, so a diff is usually much smaller than
+/// the values it was computed from.
+///
+/// A diff can be serialized with [`ReflectDiffSerializer`] and deserialized with
+/// [`ReflectDiffDeserializer`].
+///
+/// [`apply_diff`]: crate::apply_diff
+/// [`ReflectDiffSerializer`]: crate::serde::ReflectDiffSerializer
+/// [`ReflectDiffDeserializer`]: crate::serde::ReflectDiffDeserializer
+#[derive(Debug)]
+pub enum ReflectDiff {
+    /// The values are equal.
+    Unchanged,
+    /// The value was replaced as a whole.
+    ///
+    /// This is used when the values have different types, when an enum changed its variant,
+    /// and for values that cannot be compared field by field, such as [`ReflectRef::Value`].
+    Replaced(Box<dyn Reflect>),
+    /// The changed fields of a [`Struct`], by name.
+    Struct(Vec<(String, ReflectDiff)>),
+    /// The changed fields of a [`TupleStruct`](crate::TupleStruct), by index.
+    TupleStruct(Vec<(usize, ReflectDiff)>),
+    /// The changed fields of a [`Tuple`](crate::Tuple), by index.
+    Tuple(Vec<(usize, ReflectDiff)>),
+    /// The changed elements of an [`Array`](crate::Array), by index.
+    Array(Vec<(usize, ReflectDiff)>),
+    /// The operations that turn one [`List`] into the other, in the order they must be applied.
+    List(Vec<ListDiffOp>),
+    /// The operations that turn one [`Map`] into the other.
+    Map(Vec<MapDiffOp>),
+    /// The changed fields of an [`Enum`] whose variant did not change, by index.
+    Enum {
+        /// The name of the variant both values share.
+        variant: String,
+        fields: Vec<(usize, ReflectDiff)>,
+    },
+}
+
+/// A single operation of a [`ReflectDiff::List`].
+///
+/// Indices refer to the list as it is after all previous operations were applied.
+#[derive(Debug)]
+pub enum ListDiffOp {
+    /// Inserts `value` at `index`, shifting all elements after it to the right.
+    Insert {
+        index: usize,
+        value: Box<dyn Reflect>,
+    },
+    /// Removes the element at `index`, shifting all elements after it to the left.
+    Remove { index: usize },
+    /// Applies `diff` to the element at `index`.
+    Modify { index: usize, diff: ReflectDiff },
+}
+
+/// A single operation of a [`ReflectDiff::Map`].
+#[derive(Debug)]
+pub enum MapDiffOp {
+    /// Inserts a new entry.
+    Insert {
+        key: Box<dyn Reflect>,
+        value: Box<dyn Reflect>,
+    },
+    /// Removes the entry with the given `key`.
+    Remove { key: Box<dyn Reflect> },
+    /// Applies `diff` to the value of the entry with the given `key`.
+    Modify {
+        key: Box<dyn Reflect>,
+        diff: ReflectDiff,
+    },
+}
+
+impl ReflectDiff {
+    /// Returns `true` if this diff does not change anything.
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, ReflectDiff::Unchanged)
+    }
+}
+
+/// Computes the [`ReflectDiff`] that turns `a` into `b`.
+///
+/// Values are compared with [`Reflect::reflect_partial_eq`]. Values for which it returns `None`
+/// are considered to be different.
+///
+/// Lists are diffed with a longest common subsequence, so inserting or removing an element
+/// results in a single [`ListDiffOp`] rather than a change to every following element.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{apply_diff, diff, Reflect};
+/// #[derive(Reflect, Clone, PartialEq, Debug)]
+/// struct Player {
+///     name: String,
+///     health: u32,
+///     inventory: Vec<String>,
+/// }
+///
+/// let before = Player {
+///     name: "Ferris".to_string(),
+///     health: 100,
+///     inventory: vec!["sword".to_string()],
+/// };
+/// let after = Player {
+///     health: 80,
+///     inventory: vec!["shield".to_string(), "sword".to_string()],
+///     ..before.clone()
+/// };
+///
+/// let diff = diff(&before, &after);
+///
+/// let mut value = before.clone();
+/// apply_diff(&mut value, &diff).unwrap();
+/// assert_eq!(value, after);
+/// ```
+pub fn diff(a: &dyn Reflect, b: &dyn Reflect) -> ReflectDiff {
+    if !is_same_type(a, b) {
+        return ReflectDiff::Replaced(b.clone_value());
+    }
+
+    match (a.reflect_ref(), b.reflect_ref()) {
+        (ReflectRef::Struct(a), ReflectRef::Struct(b)) => diff_struct(a, b),
+        (ReflectRef::TupleStruct(a), ReflectRef::TupleStruct(b)) => {
+            if a.field_len() != b.field_len() {
+                return ReflectDiff::Replaced(b.clone_value());
+            }
+            changed_fields(
+                ReflectDiff::TupleStruct,
+                a.iter_fields().zip(b.iter_fields()),
+            )
+        }
+        (ReflectRef::Tuple(a), ReflectRef::Tuple(b)) => {
+            if a.field_len() != b.field_len() {
+                return ReflectDiff::Replaced(b.clone_value());
+            }
+            changed_fields(ReflectDiff::Tuple, a.iter_fields().zip(b.iter_fields()))
+        }
+        (ReflectRef::Array(a), ReflectRef::Array(b)) => {
+            if a.len() != b.len() {
+                return ReflectDiff::Replaced(b.clone_value());
+            }
+            changed_fields(ReflectDiff::Array, a.iter().zip(b.iter()))
+        }
+        (ReflectRef::List(a), ReflectRef::List(b)) => diff_list(a, b),
+        (ReflectRef::Map(a), ReflectRef::Map(b)) => diff_map(a, b),
+        (ReflectRef::Enum(a), ReflectRef::Enum(b)) => diff_enum(a, b),
+        _ => {
+            if a.reflect_partial_eq(b).unwrap_or(false) {
+                ReflectDiff::Unchanged
+            } else {
+                ReflectDiff::Replaced(b.clone_value())
+            }
+        }
+    }
+}
+
+fn is_same_type(a: &dyn Reflect, b: &dyn Reflect) -> bool {
+    match (a.get_represented_type_info(), b.get_represented_type_info()) {
+        (Some(a), Some(b)) => a.type_id() == b.type_id(),
+        _ => a.reflect_type_path() == b.reflect_type_path(),
+    }
+}
+
+/// Diffs pairs of fields, keeping the ones that changed along with their index.
+fn changed_fields<'a>(
+    make_diff: impl FnOnce(Vec<(usize, ReflectDiff)>) -> ReflectDiff,
+    fields: impl Iterator<Item = (&'a dyn Reflect, &'a dyn Reflect)>,
+) -> ReflectDiff {
+    let changed: Vec<_> = fields
+        .map(|(a, b)| diff(a, b))
+        .enumerate()
+        .filter(|(_, diff)| !diff.is_unchanged())
+        .collect();
+    if changed.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        make_diff(changed)
+    }
+}
+
+fn diff_struct(a: &dyn Struct, b: &dyn Struct) -> ReflectDiff {
+    let mut changed = Vec::new();
+    for (index, b_field) in b.iter_fields().enumerate() {
+        let name = b.name_at(index).unwrap();
+        let Some(a_field) = a.field(name) else {
+            return ReflectDiff::Replaced(b.clone_value());
+        };
+        let diff = diff(a_field, b_field);
+        if !diff.is_unchanged() {
+            changed.push((name.to_string(), diff));
+        }
+    }
+    if a.field_len() != b.field_len() {
+        return ReflectDiff::Replaced(b.clone_value());
+    }
+    if changed.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::Struct(changed)
+    }
+}
+
+fn diff_enum(a: &dyn Enum, b: &dyn Enum) -> ReflectDiff {
+    if a.variant_name() != b.variant_name() || a.field_len() != b.field_len() {
+        return ReflectDiff::Replaced(b.clone_value());
+    }
+    changed_fields(
+        |fields| ReflectDiff::Enum {
+            variant: b.variant_name().to_string(),
+            fields,
+        },
+        a.iter_fields()
+            .zip(b.iter_fields())
+            .map(|(a, b)| (a.value(), b.value())),
+    )
+}
+
+/// The largest table of common subsequence lengths [`diff_list`] builds, above which the elements of lists
+/// are compared by index instead.
+const MAX_LCS_TABLE_LEN: usize = 1 << 20;
+
+fn diff_list(a: &dyn List, b: &dyn List) -> ReflectDiff {
+    let a: Vec<_> = a.iter().collect();
+    let b: Vec<_> = b.iter().collect();
+    let eq = |i: usize, j: usize| a[i].reflect_partial_eq(b[j]).unwrap_or(false);
+
+    // Elements added or removed at the end or the start of a list are the most common edits,
+    // so the common prefix and suffix are skipped before looking for a common subsequence.
+    let shortest = a.len().min(b.len());
+    let prefix = (0..shortest).take_while(|&i| eq(i, i)).count();
+    let suffix = (0..shortest - prefix)
+        .take_while(|&k| eq(a.len() - 1 - k, b.len() - 1 - k))
+        .count();
+    let (a_end, b_end) = (a.len() - suffix, b.len() - suffix);
+    let matches = common_subsequence(prefix..a_end, prefix..b_end, eq);
+
+    let mut ops = Vec::new();
+    // The index in the list being patched, which matches `j` once all operations so far are applied.
+    let mut index = prefix;
+    let (mut i, mut j) = (prefix, prefix);
+    for (match_i, match_j) in matches.into_iter().chain([(a_end, b_end)]) {
+        // Elements replaced by other elements are modified in place rather than being
+        // removed and inserted again.
+        let removed = match_i - i;
+        let inserted = match_j - j;
+        for offset in 0..removed.min(inserted) {
+            let diff = diff(a[i + offset], b[j + offset]);
+            if !diff.is_unchanged() {
+                ops.push(ListDiffOp::Modify { index, diff });
+            }
+            index += 1;
+        }
+        for _ in inserted..removed {
+            ops.push(ListDiffOp::Remove { index });
+        }
+        for value in &b[j + removed.min(inserted)..match_j] {
+            ops.push(ListDiffOp::Insert {
+                index,
+                value: value.clone_value(),
+            });
+            index += 1;
+        }
+        // Skip the common element.
+        index += 1;
+        (i, j) = (match_i + 1, match_j + 1);
+    }
+
+    if ops.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::List(ops)
+    }
+}
+
+/// Returns the pairs of indices of the elements of a longest common subsequence of the elements of `a` and `b`,
+/// in increasing order.
+///
+/// If the table of subsequence lengths would be larger than [`MAX_LCS_TABLE_LEN`], no elements are matched.
+fn common_subsequence(
+    a: Range<usize>,
+    b: Range<usize>,
+    eq: impl Fn(usize, usize) -> bool,
+) -> Vec<(usize, usize)> {
+    let (n, m) = (a.len(), b.len());
+    let width = m + 1;
+    if (n + 1).saturating_mul(width) > MAX_LCS_TABLE_LEN {
+        return Vec::new();
+    }
+
+    // `lcs[i * width + j]` is the length of the longest common subsequence of `a[i..]` and `b[j..]`.
+    let mut lcs = vec![0usize; (n + 1) * width];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i * width + j] = if eq(a.start + i, b.start + j) {
+                lcs[(i + 1) * width + j + 1] + 1
+            } else {
+                lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut matches = Vec::with_capacity(lcs[0]);
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if eq(a.start + i, b.start + j) && lcs[i * width + j] == lcs[(i + 1) * width + j + 1] + 1 {
+            matches.push((a.start + i, b.start + j));
+            i += 1;
+            j += 1;
+        } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    matches
+}
+
+fn diff_map(a: &dyn Map, b: &dyn Map) -> ReflectDiff {
+    let mut ops = Vec::new();
+    for (key, _) in a.iter() {
+        if b.get(key).is_none() {
+            ops.push(MapDiffOp::Remove {
+                key: key.clone_value(),
+            });
+        }
+    }
+    for (key, b_value) in b.iter() {
+        match a.get(key) {
+            Some(a_value) => {
+                let diff = diff(a_value, b_value);
+                if !diff.is_unchanged() {
+                    ops.push(MapDiffOp::Modify {
+                        key: key.clone_value(),
+                        diff,
+                    });
+                }
+            }
+            None => ops.push(MapDiffOp::Insert {
+                key: key.clone_value(),
+                value: b_value.clone_value(),
+            }),
+        }
+    }
+
+    if ops.is_empty() {
+        ReflectDiff::Unchanged
+    } else {
+        ReflectDiff::Map(ops)
+    }
+}
+
+impl Clone for ReflectDiff {
+    fn clone(&self) -> Self {
+        match self {
+            ReflectDiff::Unchanged => ReflectDiff::Unchanged,
+            ReflectDiff::Replaced(value) => ReflectDiff::Replaced(value.clone_value()),
+            ReflectDiff::Struct(fields) => ReflectDiff::Struct(fields.clone()),
+            ReflectDiff::TupleStruct(fields) => ReflectDiff::TupleStruct(fields.clone()),
+            ReflectDiff::Tuple(fields) => ReflectDiff::Tuple(fields.clone()),
+            ReflectDiff::Array(elements) => ReflectDiff::Array(elements.clone()),
+            ReflectDiff::List(ops) => ReflectDiff::List(ops.clone()),
+            ReflectDiff::Map(ops) => ReflectDiff::Map(ops.clone()),
+            ReflectDiff::Enum { variant, fields } => ReflectDiff::Enum {
+                variant: variant.clone(),
+                fields: fields.clone(),
+            },
+        }
+    }
+}
+
+impl Clone for ListDiffOp {
+    fn clone(&self) -> Self {
+        match self {
+            ListDiffOp::Insert { index, value } => ListDiffOp::Insert {
+                index: *index,
+                value: value.clone_value(),
+            },
+            ListDiffOp::Remove { index } => ListDiffOp::Remove { index: *index },
+            ListDiffOp::Modify { index, diff } => ListDiffOp::Modify {
+                index: *index,
+                diff: diff.clone(),
+            },
+        }
+    }
+}
+
+impl Clone for MapDiffOp {
+    fn clone(&self) -> Self {
+        match self {
+            MapDiffOp::Insert { key, value } => MapDiffOp::Insert {
+                key: key.clone_value(),
+                value: value.clone_value(),
+            },
+            MapDiffOp::Remove { key } => MapDiffOp::Remove {
+                key: key.clone_value(),
+            },
+            MapDiffOp::Modify { key, diff } => MapDiffOp::Modify {
+                key: key.clone_value(),
+                diff: diff.clone(),
+            },
+        }
+    }
+}
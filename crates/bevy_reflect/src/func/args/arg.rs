@@ -187,6 +187,17 @@ pub enum ArgValue<'a> {
     Mut(&'a mut dyn Reflect),
 }
 
+impl<'a> ArgValue<'a> {
+    /// Returns the [`Ownership`] of this argument.
+    pub fn ownership(&self) -> Ownership {
+        match self {
+            ArgValue::Owned(_) => Ownership::Owned,
+            ArgValue::Ref(_) => Ownership::Ref,
+            ArgValue::Mut(_) => Ownership::Mut,
+        }
+    }
+}
+
 impl<'a> Deref for ArgValue<'a> {
     type Target = dyn Reflect;
 
@@ -1,4 +1,5 @@
 use alloc::borrow::Cow;
+use core::any::TypeId;
 
 use crate::func::args::{GetOwnership, Ownership};
 use crate::TypePath;
@@ -22,6 +23,8 @@ pub struct ArgInfo {
     ///
     /// [type path]: TypePath::type_path
     type_path: &'static str,
+    /// The [`TypeId`] of the argument, without its reference.
+    owned_type_id: TypeId,
 }
 
 impl ArgInfo {
@@ -34,6 +37,7 @@ impl ArgInfo {
             name: None,
             ownership: T::ownership(),
             type_path: T::type_path(),
+            owned_type_id: T::owned_type_id(),
         }
     }
 
@@ -78,6 +82,13 @@ impl ArgInfo {
         self.type_path
     }
 
+    /// The [`TypeId`] of the argument, without its reference.
+    ///
+    /// For example, this is the [`TypeId`] of `u32` for an argument of type `&u32`.
+    pub fn owned_type_id(&self) -> TypeId {
+        self.owned_type_id
+    }
+
     /// Get an ID representing the argument.
     ///
     /// This will return `ArgId::Name` if the argument has a name,
@@ -281,6 +281,11 @@ impl<'a> ArgList<'a> {
         self.pop_arg()?.take_mut()
     }
 
+    /// Returns an iterator over the arguments in the list.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Arg<'a>> {
+        self.list.iter()
+    }
+
     /// Returns the number of arguments in the list.
     pub fn len(&self) -> usize {
         self.list.len()
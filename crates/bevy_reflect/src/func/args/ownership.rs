@@ -1,3 +1,4 @@
+use core::any::TypeId;
 use core::fmt::{Display, Formatter};
 
 /// A trait for getting the ownership of a type.
@@ -14,10 +15,23 @@ use core::fmt::{Display, Formatter};
 pub trait GetOwnership {
     /// Returns the ownership of [`Self`].
     fn ownership() -> Ownership;
+
+    /// Returns the [`TypeId`] of [`Self`] with its reference, if any, removed.
+    ///
+    /// For example, this is the [`TypeId`] of `u32` for `u32`, `&u32`, and `&mut u32`.
+    ///
+    /// By default, this returns the [`TypeId`] of [`Self`], which is only correct for owned types.
+    /// Implementations for reference types must override it.
+    fn owned_type_id() -> TypeId
+    where
+        Self: 'static,
+    {
+        TypeId::of::<Self>()
+    }
 }
 
 /// The ownership of a type.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Ownership {
     /// The type is a reference (i.e. `&T`).
     Ref,
@@ -88,6 +102,10 @@ macro_rules! impl_get_ownership {
             fn ownership() -> $crate::func::args::Ownership {
                 $crate::func::args::Ownership::Ref
             }
+
+            fn owned_type_id() -> ::core::any::TypeId {
+                ::core::any::TypeId::of::<$ty>()
+            }
         }
 
         impl <
@@ -102,6 +120,10 @@ macro_rules! impl_get_ownership {
             fn ownership() -> $crate::func::args::Ownership {
                 $crate::func::args::Ownership::Mut
             }
+
+            fn owned_type_id() -> ::core::any::TypeId {
+                ::core::any::TypeId::of::<$ty>()
+            }
         }
     };
 }
@@ -1,5 +1,7 @@
+use alloc::borrow::Cow;
+
 use crate::func::args::ArgError;
-use crate::func::Return;
+use crate::func::{ArgumentSignature, Return};
 use thiserror::Error;
 
 /// An error that occurs when calling a [`DynamicFunction`] or [`DynamicClosure`].
@@ -14,6 +16,23 @@ pub enum FunctionError {
     /// The number of arguments provided does not match the expected number.
     #[error("expected {expected} arguments but received {received}")]
     ArgCountMismatch { expected: usize, received: usize },
+    /// None of the overloads of the function accept the provided arguments.
+    #[error(
+        "no overload matches the arguments {received}, expected one of: {}",
+        format_signatures(expected)
+    )]
+    NoOverload {
+        expected: Vec<ArgumentSignature>,
+        received: ArgumentSignature,
+    },
+}
+
+fn format_signatures(signatures: &[ArgumentSignature]) -> String {
+    signatures
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
 }
 
 /// The result of calling a dynamic [`DynamicFunction`] or [`DynamicClosure`].
@@ -24,3 +43,28 @@ pub enum FunctionError {
 /// [`DynamicFunction`]: crate::func::DynamicFunction
 /// [`DynamicClosure`]: crate::func::DynamicClosure
 pub type FunctionResult<'a> = Result<Return<'a>, FunctionError>;
+
+/// An error that occurs when registering a function into a [`FunctionRegistry`].
+///
+/// [`FunctionRegistry`]: crate::func::FunctionRegistry
+#[derive(Debug, Error, PartialEq)]
+pub enum FunctionRegistrationError {
+    /// A function with the given name has already been registered.
+    ///
+    /// Contains the duplicate function name.
+    #[error("a function has already been registered with name {0:?}")]
+    DuplicateName(Cow<'static, str>),
+    /// The function is missing a name by which it can be registered.
+    #[error("function name is missing")]
+    MissingName,
+}
+
+/// An error that occurs when adding an overload to a [`DynamicFunction`].
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+#[derive(Debug, Error, PartialEq)]
+pub enum FunctionOverloadError {
+    /// An overload with the same argument signature already exists.
+    #[error("an overload with the signature {0} already exists")]
+    DuplicateSignature(ArgumentSignature),
+}
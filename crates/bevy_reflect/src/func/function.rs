@@ -4,7 +4,12 @@ use std::sync::Arc;
 
 use crate::func::args::{ArgInfo, ArgList};
 use crate::func::info::FunctionInfo;
-use crate::func::{FunctionResult, IntoFunction, ReturnInfo};
+use crate::func::{
+    ArgumentSignature, FunctionError, FunctionOverloadError, FunctionResult, IntoFunction,
+    ReturnInfo,
+};
+
+type BoxedFunction = Arc<dyn for<'a> Fn(ArgList<'a>) -> FunctionResult<'a> + Send + Sync + 'static>;
 
 /// A dynamic representation of a Rust function.
 ///
@@ -89,11 +94,58 @@ use crate::func::{FunctionResult, IntoFunction, ReturnInfo};
 /// assert_eq!(list, vec!["Hello, World!!!"]);
 /// ```
 ///
+/// # Overloading
+///
+/// A single [`DynamicFunction`] can dispatch to several functions with different argument types
+/// or argument counts, which is useful when exposing functions to a scripting language
+/// that has no notion of generics.
+///
+/// Additional signatures are added using [`DynamicFunction::with_overload`].
+/// When called, the first overload whose [`ArgumentSignature`] exactly matches the types
+/// and ownership of the given arguments is used.
+/// If none match, a [`FunctionError::NoOverload`] listing every candidate signature is returned.
+///
+/// Arguments are compared by [`TypeId`](core::any::TypeId), with dynamic types such as
+/// [`DynamicStruct`](crate::DynamicStruct) matching the type they represent.
+/// Functions accepting any number of arguments aren't supported,
+/// but each overload may take a different number of arguments.
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, IntoFunction};
+/// fn add_i32(a: i32, b: i32) -> i32 {
+///   a + b
+/// }
+///
+/// fn add_f32(a: f32, b: f32) -> f32 {
+///   a + b
+/// }
+///
+/// fn add_three(a: i32, b: i32, c: i32) -> i32 {
+///   a + b + c
+/// }
+///
+/// let func = add_i32
+///   .into_function()
+///   .with_name("add")
+///   .with_overload(add_f32)
+///   .with_overload(add_three);
+///
+/// let args = ArgList::new().push_owned(1.5_f32).push_owned(2.0_f32);
+/// let value = func.call(args).unwrap().unwrap_owned();
+/// assert_eq!(value.downcast_ref::<f32>(), Some(&3.5));
+///
+/// let args = ArgList::new().push_owned(1_i32).push_owned(2_i32).push_owned(3_i32);
+/// let value = func.call(args).unwrap().unwrap_owned();
+/// assert_eq!(value.downcast_ref::<i32>(), Some(&6));
+/// ```
+///
 /// [`DynamicClosure`]: crate::func::DynamicClosure
 /// [module-level documentation]: crate::func
 pub struct DynamicFunction {
     info: FunctionInfo,
-    func: Arc<dyn for<'a> Fn(ArgList<'a>) -> FunctionResult<'a> + 'static>,
+    func: BoxedFunction,
+    /// Additional signatures this function can be called with.
+    overloads: Vec<(FunctionInfo, BoxedFunction)>,
 }
 
 impl DynamicFunction {
@@ -103,13 +155,22 @@ impl DynamicFunction {
     ///
     /// It's important that the function signature matches the provided [`FunctionInfo`].
     /// This info may be used by consumers of the function for validation and debugging.
-    pub fn new<F: for<'a> Fn(ArgList<'a>) -> FunctionResult<'a> + 'static>(
+    ///
+    /// The function must be `Send + Sync` so that it can be stored in a [`FunctionRegistry`]
+    /// or in [`ReflectMethods`] type data, which are shared between threads.
+    /// Closures capturing non-thread-safe state should use [`DynamicClosure`] instead.
+    ///
+    /// [`FunctionRegistry`]: crate::func::FunctionRegistry
+    /// [`ReflectMethods`]: crate::func::ReflectMethods
+    /// [`DynamicClosure`]: crate::func::DynamicClosure
+    pub fn new<F: for<'a> Fn(ArgList<'a>) -> FunctionResult<'a> + Send + Sync + 'static>(
         func: F,
         info: FunctionInfo,
     ) -> Self {
         Self {
             info,
             func: Arc::new(func),
+            overloads: Vec::new(),
         }
     }
 
@@ -139,8 +200,61 @@ impl DynamicFunction {
         self
     }
 
+    /// Add an overload to the function, allowing it to also be called with the
+    /// argument signature of `function`.
+    ///
+    /// Any name given to `function` is ignored in favor of the name of this function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this function already accepts the same [`ArgumentSignature`].
+    /// See [`DynamicFunction::try_with_overload`] for a non-panicking version.
+    pub fn with_overload<F: IntoFunction<Marker>, Marker>(self, function: F) -> Self {
+        self.try_with_overload(function)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+
+    /// Attempts to add an overload to the function, allowing it to also be called with the
+    /// argument signature of `function`.
+    ///
+    /// If `function` is itself overloaded, each of its signatures is added.
+    ///
+    /// Returns an error if this function already accepts one of the same [`ArgumentSignature`]s.
+    pub fn try_with_overload<F: IntoFunction<Marker>, Marker>(
+        mut self,
+        function: F,
+    ) -> Result<Self, FunctionOverloadError> {
+        let function = function.into_function();
+        for (info, func) in
+            core::iter::once((function.info, function.func)).chain(function.overloads)
+        {
+            let signature = ArgumentSignature::from_info(&info);
+            if self
+                .signatures()
+                .any(|existing| ArgumentSignature::from_info(existing) == signature)
+            {
+                return Err(FunctionOverloadError::DuplicateSignature(signature));
+            }
+            self.overloads.push((info, func));
+        }
+        Ok(self)
+    }
+
+    /// Returns the [`FunctionInfo`] of every signature this function can be called with,
+    /// starting with [`DynamicFunction::info`].
+    pub fn signatures(&self) -> impl Iterator<Item = &FunctionInfo> {
+        core::iter::once(&self.info).chain(self.overloads.iter().map(|(info, _)| info))
+    }
+
+    /// Returns `true` if this function has more than one signature.
+    pub fn is_overloaded(&self) -> bool {
+        !self.overloads.is_empty()
+    }
+
     /// Call the function with the given arguments.
     ///
+    /// If the function is [overloaded], the overload matching the given arguments is called.
+    ///
     /// # Example
     ///
     /// ```
@@ -154,11 +268,35 @@ impl DynamicFunction {
     /// let result = func.call(args).unwrap().unwrap_owned();
     /// assert_eq!(result.take::<i32>().unwrap(), 100);
     /// ```
+    ///
+    /// [overloaded]: DynamicFunction::with_overload
     pub fn call<'a>(&self, args: ArgList<'a>) -> FunctionResult<'a> {
-        (self.func)(args)
+        if self.overloads.is_empty() {
+            return (self.func)(args);
+        }
+
+        let func = core::iter::once((&self.info, &self.func))
+            .chain(self.overloads.iter().map(|(info, func)| (info, func)))
+            .find_map(|(info, func)| ArgumentSignature::matches(info, &args).then_some(func));
+
+        match func {
+            Some(func) => func(args),
+            None => Err(FunctionError::NoOverload {
+                expected: self
+                    .signatures()
+                    .map(ArgumentSignature::from_info)
+                    .collect(),
+                received: ArgumentSignature::from_args(&args),
+            }),
+        }
     }
 
     /// Returns the function info.
+    ///
+    /// For [overloaded] functions, this is the info of the first signature.
+    /// See [`DynamicFunction::signatures`] for the info of every signature.
+    ///
+    /// [overloaded]: DynamicFunction::with_overload
     pub fn info(&self) -> &FunctionInfo {
         &self.info
     }
@@ -169,23 +307,35 @@ impl DynamicFunction {
 /// This takes the format: `DynamicFunction(fn {name}({arg1}: {type1}, {arg2}: {type2}, ...) -> {return_type})`.
 ///
 /// Names for arguments and the function itself are optional and will default to `_` if not provided.
+/// For [overloaded] functions, each signature is listed, separated by commas.
+///
+/// [overloaded]: DynamicFunction::with_overload
 impl Debug for DynamicFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         let name = self.info.name().unwrap_or("_");
-        write!(f, "DynamicFunction(fn {name}(")?;
-
-        for (index, arg) in self.info.args().iter().enumerate() {
-            let name = arg.name().unwrap_or("_");
-            let ty = arg.type_path();
-            write!(f, "{name}: {ty}")?;
+        write!(f, "DynamicFunction(")?;
 
-            if index + 1 < self.info.args().len() {
+        for (index, info) in self.signatures().enumerate() {
+            if index > 0 {
                 write!(f, ", ")?;
             }
+
+            write!(f, "fn {name}(")?;
+            for (index, arg) in info.args().iter().enumerate() {
+                let name = arg.name().unwrap_or("_");
+                let ty = arg.type_path();
+                write!(f, "{name}: {ty}")?;
+
+                if index + 1 < info.args().len() {
+                    write!(f, ", ")?;
+                }
+            }
+
+            let ret = info.return_info().type_path();
+            write!(f, ") -> {ret}")?;
         }
 
-        let ret = self.info.return_info().type_path();
-        write!(f, ") -> {ret})")
+        write!(f, ")")
     }
 }
 
@@ -194,6 +344,7 @@ impl Clone for DynamicFunction {
         Self {
             info: self.info.clone(),
             func: Arc::clone(&self.func),
+            overloads: self.overloads.clone(),
         }
     }
 }
@@ -257,4 +408,152 @@ mod tests {
             .unwrap();
         assert_eq!(value, "foo");
     }
+
+    fn add_i32(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    fn add_f32(a: f32, b: f32) -> f32 {
+        a + b
+    }
+
+    #[test]
+    fn should_dispatch_on_argument_types() {
+        let func = add_i32.into_function().with_overload(add_f32);
+        assert!(func.is_overloaded());
+        assert_eq!(func.signatures().count(), 2);
+
+        let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+        let value = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.downcast_ref::<i32>(), Some(&100));
+
+        let args = ArgList::new().push_owned(0.5_f32).push_owned(0.25_f32);
+        let value = func.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.downcast_ref::<f32>(), Some(&0.75));
+    }
+
+    #[test]
+    fn should_dispatch_on_argument_count_and_ownership() {
+        #[allow(clippy::ptr_arg)]
+        fn len(list: &Vec<i32>) -> usize {
+            list.len()
+        }
+
+        #[allow(clippy::ptr_arg)]
+        fn push(list: &mut Vec<i32>, value: i32) -> usize {
+            list.push(value);
+            list.len()
+        }
+
+        fn sum(a: i32, b: i32, c: i32) -> i32 {
+            a + b + c
+        }
+
+        let func = len.into_function().with_overload(push).with_overload(sum);
+
+        let mut list = vec![1, 2];
+        let value = func.call(ArgList::new().push_ref(&list)).unwrap();
+        assert_eq!(value.unwrap_owned().downcast_ref::<usize>(), Some(&2));
+
+        let args = ArgList::new().push_mut(&mut list).push_owned(3_i32);
+        let value = func.call(args).unwrap();
+        assert_eq!(value.unwrap_owned().downcast_ref::<usize>(), Some(&3));
+        assert_eq!(list, vec![1, 2, 3]);
+
+        let args = ArgList::new()
+            .push_owned(1_i32)
+            .push_owned(2_i32)
+            .push_owned(3_i32);
+        let value = func.call(args).unwrap();
+        assert_eq!(value.unwrap_owned().downcast_ref::<i32>(), Some(&6));
+    }
+
+    #[test]
+    fn should_dispatch_dynamic_types_on_represented_type() {
+        use crate as bevy_reflect;
+        use crate::{DynamicStruct, FromReflect, Reflect};
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Foo(i32);
+
+        #[derive(Reflect, Debug, PartialEq)]
+        struct Bar {
+            value: f32,
+        }
+
+        let func = DynamicFunction::new(
+            |mut args| {
+                let foo = Foo::from_reflect(&*args.pop_arg()?.take_value()).unwrap();
+                Ok(Return::Owned(Box::new(foo.0 as f32)))
+            },
+            FunctionInfo::new().with_arg::<&Foo>("foo"),
+        )
+        .with_overload(DynamicFunction::new(
+            |mut args| {
+                let bar = Bar::from_reflect(&*args.pop_arg()?.take_value()).unwrap();
+                Ok(Return::Owned(Box::new(bar.value)))
+            },
+            FunctionInfo::new().with_arg::<&Bar>("bar"),
+        ));
+
+        let value = func.call(ArgList::new().push_ref(&Foo(1))).unwrap();
+        assert_eq!(value.unwrap_owned().downcast_ref::<f32>(), Some(&1.0));
+
+        let mut bar = DynamicStruct::default();
+        bar.insert("value", 2.0_f32);
+        bar.set_represented_type(Some(<Bar as crate::Typed>::type_info()));
+        let value = func.call(ArgList::new().push_ref(&bar)).unwrap();
+        assert_eq!(value.unwrap_owned().downcast_ref::<f32>(), Some(&2.0));
+
+        let error = func
+            .call(ArgList::new().push_ref(&DynamicStruct::default()))
+            .unwrap_err();
+        assert!(matches!(error, FunctionError::NoOverload { .. }));
+    }
+
+    #[test]
+    fn should_error_with_candidates_when_no_overload_matches() {
+        let func = add_i32
+            .into_function()
+            .with_name("add")
+            .with_overload(add_f32);
+
+        let args = ArgList::new().push_owned(1_i32).push_ref(&2_i32);
+        let error = func.call(args).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no overload matches the arguments (i32, &i32), expected one of: (i32, i32), (f32, f32)"
+        );
+        let FunctionError::NoOverload { expected, received } = error else {
+            panic!("expected `FunctionError::NoOverload`");
+        };
+        assert_eq!(expected.len(), 2);
+        assert_eq!(
+            received.type_paths().collect::<Vec<_>>(),
+            vec!["i32", "&i32"]
+        );
+    }
+
+    #[test]
+    fn should_error_on_duplicate_overload() {
+        let result = add_i32
+            .into_function()
+            .try_with_overload(|a: i32, b: i32| a - b);
+        assert!(matches!(
+            result,
+            Err(FunctionOverloadError::DuplicateSignature(_))
+        ));
+    }
+
+    #[test]
+    fn should_debug_all_signatures() {
+        let func = add_i32
+            .into_function()
+            .with_name("add")
+            .with_overload(add_f32);
+        assert_eq!(
+            format!("{func:?}"),
+            "DynamicFunction(fn add(_: i32, _: i32) -> i32, fn add(_: f32, _: f32) -> f32)"
+        );
+    }
 }
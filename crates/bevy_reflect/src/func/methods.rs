@@ -0,0 +1,215 @@
+use alloc::borrow::Cow;
+
+use bevy_utils::HashMap;
+
+use crate::func::args::ArgList;
+use crate::func::{DynamicFunction, FunctionResult, IntoFunction};
+use crate::TypeRegistry;
+
+/// Type data containing the reflected inherent methods of a type.
+///
+/// This is usually registered using the `#[reflect(methods(...))]` attribute,
+/// which accepts a list of method names and, optionally, an expression to convert
+/// into a [`DynamicFunction`] for methods that can't be converted directly
+/// (such as those taking `impl Trait` arguments).
+///
+/// Methods are called like any other [`DynamicFunction`],
+/// with the receiver passed as the first argument.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::{Reflect, TypeRegistry};
+/// # use bevy_reflect::func::{ArgList, ReflectMethods};
+/// #[derive(Reflect, Debug, PartialEq)]
+/// #[reflect(methods(scaled, offset = Self::offset as fn(Self, f32) -> Self))]
+/// struct Point(f32, f32);
+///
+/// impl Point {
+///   fn scaled(&self, factor: f32) -> Point {
+///     Point(self.0 * factor, self.1 * factor)
+///   }
+///
+///   fn offset(self, amount: impl Into<f32>) -> Self {
+///     let amount = amount.into();
+///     Point(self.0 + amount, self.1 + amount)
+///   }
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Point>();
+///
+/// let point = Point(1.0, 2.0);
+/// let args = ArgList::new().push_ref(&point).push_owned(2.0_f32);
+/// let method = registry.get_method("Point::scaled").unwrap();
+/// let value = method.call(args).unwrap().unwrap_owned();
+/// assert_eq!(value.downcast_ref::<Point>(), Some(&Point(2.0, 4.0)));
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct ReflectMethods {
+    methods: HashMap<Cow<'static, str>, DynamicFunction>,
+}
+
+impl ReflectMethods {
+    /// Create an empty [`ReflectMethods`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the given method with the given name, replacing any method with the same name.
+    ///
+    /// The name of the stored [`DynamicFunction`] is replaced with `name`.
+    pub fn with_method<F, Marker>(mut self, name: impl Into<Cow<'static, str>>, method: F) -> Self
+    where
+        F: IntoFunction<Marker>,
+    {
+        self.insert(name, method);
+        self
+    }
+
+    /// Inserts the given method with the given name, returning the method previously
+    /// registered under that name, if any.
+    ///
+    /// The name of the stored [`DynamicFunction`] is replaced with `name`.
+    pub fn insert<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        method: F,
+    ) -> Option<DynamicFunction>
+    where
+        F: IntoFunction<Marker>,
+    {
+        let name = name.into();
+        let method = method.into_function().with_name(name.clone());
+        self.methods.insert(name, method)
+    }
+
+    /// Returns a reference to the method with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction> {
+        self.methods.get(name)
+    }
+
+    /// Calls the method with the given name using the given arguments.
+    ///
+    /// The receiver of the method, if any, should be the first argument.
+    ///
+    /// Returns `None` if the type has no method with that name.
+    pub fn call<'a>(&self, name: &str, args: ArgList<'a>) -> Option<FunctionResult<'a>> {
+        self.get(name).map(|method| method.call(args))
+    }
+
+    /// Returns an iterator over the names and functions of all registered methods.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&str, &DynamicFunction)> {
+        self.methods
+            .iter()
+            .map(|(name, method)| (name.as_ref(), method))
+    }
+
+    /// Returns the number of registered methods.
+    pub fn len(&self) -> usize {
+        self.methods.len()
+    }
+
+    /// Returns `true` if no methods have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.methods.is_empty()
+    }
+}
+
+impl TypeRegistry {
+    /// Returns the method identified by the given path, if any.
+    ///
+    /// The path takes the form `Type::method`, where `Type` is either the full [type path]
+    /// or the [short type path] of a type registered with [`ReflectMethods`].
+    ///
+    /// [type path]: crate::TypePath::type_path
+    /// [short type path]: crate::TypePath::short_type_path
+    pub fn get_method(&self, path: &str) -> Option<&DynamicFunction> {
+        let (type_path, method) = path.rsplit_once("::")?;
+        self.get_with_type_path(type_path)
+            .or_else(|| self.get_with_short_type_path(type_path))?
+            .data::<ReflectMethods>()?
+            .get(method)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::Reflect;
+
+    #[derive(Reflect, Debug, Default, PartialEq)]
+    #[reflect(methods(
+        length,
+        scale,
+        with_x = Self::with_x as fn(Self, f32) -> Self,
+    ))]
+    struct Vector {
+        x: f32,
+        y: f32,
+    }
+
+    impl Vector {
+        fn length(&self) -> f32 {
+            (self.x * self.x + self.y * self.y).sqrt()
+        }
+
+        fn scale(&mut self, factor: f32) {
+            self.x *= factor;
+            self.y *= factor;
+        }
+
+        fn with_x(mut self, x: impl Into<f32>) -> Self {
+            self.x = x.into();
+            self
+        }
+    }
+
+    #[test]
+    fn should_register_methods_with_derive() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Vector>();
+
+        let methods = registry
+            .get_type_data::<ReflectMethods>(std::any::TypeId::of::<Vector>())
+            .unwrap();
+        assert_eq!(methods.len(), 3);
+        assert_eq!(methods.get("length").unwrap().info().name(), Some("length"));
+
+        let mut vector = Vector { x: 3.0, y: 4.0 };
+        let value = methods
+            .call("length", ArgList::new().push_ref(&vector))
+            .unwrap()
+            .unwrap()
+            .unwrap_owned();
+        assert_eq!(value.downcast_ref::<f32>(), Some(&5.0));
+
+        methods
+            .call(
+                "scale",
+                ArgList::new().push_mut(&mut vector).push_owned(2.0_f32),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(vector, Vector { x: 6.0, y: 8.0 });
+    }
+
+    #[test]
+    fn should_call_method_by_path() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Vector>();
+
+        let vector: Box<dyn Reflect> = Box::new(Vector { x: 3.0, y: 4.0 });
+        let args = ArgList::new().push_boxed(vector).push_owned(1.0_f32);
+
+        let method = registry.get_method("Vector::with_x").unwrap();
+        let value = method.call(args).unwrap().unwrap_owned();
+        assert_eq!(value.downcast_ref(), Some(&Vector { x: 1.0, y: 4.0 }));
+
+        let full_path = format!("{}::length", <Vector as crate::TypePath>::type_path());
+        assert!(registry.get_method(&full_path).is_some());
+        assert!(registry.get_method("Vector::missing").is_none());
+        assert!(registry.get_method("length").is_none());
+    }
+}
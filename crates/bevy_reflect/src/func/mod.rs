@@ -95,7 +95,24 @@
 //! For other functions that don't conform to one of the above signatures,
 //! [`DynamicFunction`] and [`DynamicClosure`] can instead be created manually.
 //!
+//! # Overloading
+//!
+//! A [`DynamicFunction`] may have several [overloads] with different argument types or counts,
+//! in which case calling it dispatches to the overload whose [`ArgumentSignature`]
+//! matches the [`ArgList`].
+//!
+//! # Registering Functions
+//!
+//! Functions can be stored by name in a [`FunctionRegistry`], so that they can be
+//! looked up and called dynamically, such as from a console or scripting language.
+//!
+//! Inherent methods of reflected types are instead registered as [`ReflectMethods`] type data,
+//! usually with the `#[reflect(methods(...))]` derive attribute,
+//! and can be looked up by path (e.g. `Transform::looking_at`) with [`TypeRegistry::get_method`].
+//!
 //! [`Reflect`]: crate::Reflect
+//! [`TypeRegistry::get_method`]: crate::TypeRegistry::get_method
+//! [overloads]: DynamicFunction::with_overload
 //! [lack of variadic generics]: https://poignardazur.github.io/2024/05/25/report-on-rustnl-variadics/
 //! [coherence issues]: https://doc.rust-lang.org/rustc/lints/listing/warn-by-default.html#coherence-leak-check
 
@@ -105,9 +122,12 @@ pub use error::*;
 pub use function::*;
 pub use info::*;
 pub use into_function::*;
+pub use methods::*;
 pub use reflect_fn::*;
 pub use reflect_fn_mut::*;
+pub use registry::*;
 pub use return_type::*;
+pub use signature::*;
 
 pub mod args;
 mod closures;
@@ -116,15 +136,18 @@ mod function;
 mod info;
 mod into_function;
 pub(crate) mod macros;
+mod methods;
 mod reflect_fn;
 mod reflect_fn_mut;
+mod registry;
 mod return_type;
+mod signature;
 
 #[cfg(test)]
 mod tests {
     use alloc::borrow::Cow;
 
-    use crate::func::args::{ArgError, ArgList, Ownership};
+    use crate::func::args::{ArgError, ArgInfo, ArgList, GetOwnership, Ownership};
     use crate::TypePath;
 
     use super::*;
@@ -194,4 +217,25 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn should_default_owned_type_id_to_self() {
+        use crate as bevy_reflect;
+
+        #[derive(TypePath)]
+        struct Foo;
+
+        impl GetOwnership for Foo {
+            fn ownership() -> Ownership {
+                Ownership::Owned
+            }
+        }
+
+        let info = ArgInfo::new::<Foo>(0);
+        assert_eq!(info.owned_type_id(), core::any::TypeId::of::<Foo>());
+        assert_eq!(
+            ArgInfo::new::<&i32>(0).owned_type_id(),
+            core::any::TypeId::of::<i32>()
+        );
+    }
 }
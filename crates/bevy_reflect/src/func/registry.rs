@@ -0,0 +1,296 @@
+use alloc::borrow::Cow;
+use core::fmt::Debug;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use bevy_utils::HashMap;
+
+use crate::func::args::ArgList;
+use crate::func::{
+    DynamicFunction, FunctionOverloadError, FunctionRegistrationError, FunctionResult, IntoFunction,
+};
+
+/// A registry of [reflected functions].
+///
+/// This is the function-equivalent to the [`TypeRegistry`].
+/// Functions are stored by name, so that they can be looked up and called dynamically,
+/// such as from a console command or a scripting language.
+///
+/// Methods of reflected types are registered on the type itself using [`ReflectMethods`]
+/// rather than in this registry.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::func::{ArgList, FunctionRegistry};
+/// fn add(a: i32, b: i32) -> i32 {
+///   a + b
+/// }
+///
+/// let mut registry = FunctionRegistry::default();
+/// registry.register_with_name("add", add).unwrap();
+///
+/// let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+/// let value = registry.call("add", args).unwrap().unwrap().unwrap_owned();
+/// assert_eq!(value.downcast_ref::<i32>(), Some(&100));
+/// ```
+///
+/// [reflected functions]: DynamicFunction
+/// [`TypeRegistry`]: crate::TypeRegistry
+/// [`ReflectMethods`]: crate::func::ReflectMethods
+#[derive(Default)]
+pub struct FunctionRegistry {
+    /// Maps function [names] to their respective [`DynamicFunctions`].
+    ///
+    /// [names]: crate::func::FunctionInfo::name
+    /// [`DynamicFunctions`]: DynamicFunction
+    functions: HashMap<Cow<'static, str>, DynamicFunction>,
+}
+
+impl FunctionRegistry {
+    /// Attempts to register the given function.
+    ///
+    /// The function is registered using its [name], which for functions converted
+    /// with [`IntoFunction`] defaults to the full path of the function
+    /// as returned by [`std::any::type_name`].
+    /// To register a function under a different name, use [`register_with_name`].
+    ///
+    /// Returns an error if a function has already been registered with the same name,
+    /// or if the function is missing a name.
+    ///
+    /// [name]: crate::func::FunctionInfo::name
+    /// [`register_with_name`]: Self::register_with_name
+    pub fn register<F, Marker>(
+        &mut self,
+        function: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        F: IntoFunction<Marker> + 'static,
+    {
+        let function = function.into_function();
+        let name = function
+            .info()
+            .name()
+            .ok_or(FunctionRegistrationError::MissingName)?
+            .to_string();
+        self.add(Cow::Owned(name), function)
+    }
+
+    /// Attempts to register the given function with the given name.
+    ///
+    /// The name of the stored [`DynamicFunction`] is replaced with `name`.
+    ///
+    /// Returns an error if a function has already been registered with the same name.
+    pub fn register_with_name<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        function: F,
+    ) -> Result<&mut Self, FunctionRegistrationError>
+    where
+        F: IntoFunction<Marker> + 'static,
+    {
+        let name = name.into();
+        let function = function.into_function().with_name(name.clone());
+        self.add(name, function)
+    }
+
+    /// Registers the given function with the given name, adding it as an [overload]
+    /// if a function with that name has already been registered.
+    ///
+    /// This allows several functions with different argument types to be exposed under one name.
+    ///
+    /// Returns an error if the registered function already accepts the same argument signature,
+    /// in which case the registry is left unchanged.
+    ///
+    /// [overload]: DynamicFunction::with_overload
+    pub fn register_overload<F, Marker>(
+        &mut self,
+        name: impl Into<Cow<'static, str>>,
+        function: F,
+    ) -> Result<&mut Self, FunctionOverloadError>
+    where
+        F: IntoFunction<Marker> + 'static,
+    {
+        let name = name.into();
+        let function = match self.functions.get(&name) {
+            Some(existing) => existing.clone().try_with_overload(function)?,
+            None => function.into_function().with_name(name.clone()),
+        };
+        self.functions.insert(name, function);
+        Ok(self)
+    }
+
+    /// Registers the given function, overwriting any existing registration with the same name.
+    ///
+    /// Returns the previously registered function, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the function is missing a name.
+    pub fn overwrite_registration<F, Marker>(&mut self, function: F) -> Option<DynamicFunction>
+    where
+        F: IntoFunction<Marker> + 'static,
+    {
+        let function = function.into_function();
+        let name = function
+            .info()
+            .name()
+            .expect("function name is missing")
+            .to_string();
+        self.functions.insert(Cow::Owned(name), function)
+    }
+
+    fn add(
+        &mut self,
+        name: Cow<'static, str>,
+        function: DynamicFunction,
+    ) -> Result<&mut Self, FunctionRegistrationError> {
+        if self.functions.contains_key(&name) {
+            return Err(FunctionRegistrationError::DuplicateName(name));
+        }
+        self.functions.insert(name, function);
+        Ok(self)
+    }
+
+    /// Removes the function with the given name, returning it if it was registered.
+    pub fn unregister(&mut self, name: &str) -> Option<DynamicFunction> {
+        self.functions.remove(name)
+    }
+
+    /// Returns a reference to the function with the given name, if any.
+    pub fn get(&self, name: &str) -> Option<&DynamicFunction> {
+        self.functions.get(name)
+    }
+
+    /// Returns `true` if a function with the given name has been registered.
+    pub fn contains(&self, name: &str) -> bool {
+        self.functions.contains_key(name)
+    }
+
+    /// Calls the function with the given name using the given arguments.
+    ///
+    /// Returns `None` if no function with that name has been registered.
+    pub fn call<'a>(&self, name: &str, args: ArgList<'a>) -> Option<FunctionResult<'a>> {
+        self.get(name).map(|function| function.call(args))
+    }
+
+    /// Returns an iterator over all registered functions.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &DynamicFunction> {
+        self.functions.values()
+    }
+
+    /// Returns the number of registered functions.
+    pub fn len(&self) -> usize {
+        self.functions.len()
+    }
+
+    /// Returns `true` if no functions have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.functions.is_empty()
+    }
+}
+
+impl Debug for FunctionRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.functions.values()).finish()
+    }
+}
+
+/// A synchronized wrapper around a [`FunctionRegistry`].
+#[derive(Clone, Default, Debug)]
+pub struct FunctionRegistryArc {
+    pub internal: Arc<RwLock<FunctionRegistry>>,
+}
+
+impl FunctionRegistryArc {
+    /// Takes a read lock on the underlying [`FunctionRegistry`].
+    pub fn read(&self) -> RwLockReadGuard<'_, FunctionRegistry> {
+        self.internal.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Takes a write lock on the underlying [`FunctionRegistry`].
+    pub fn write(&self) -> RwLockWriteGuard<'_, FunctionRegistry> {
+        self.internal
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::func::ArgList;
+
+    fn add(a: i32, b: i32) -> i32 {
+        a + b
+    }
+
+    #[test]
+    fn should_register_function_by_type_name() {
+        let mut registry = FunctionRegistry::default();
+        registry.register(add).unwrap();
+
+        let name = std::any::type_name_of_val(&add);
+        let function = registry.get(name).unwrap();
+        assert_eq!(function.info().name(), Some(name));
+    }
+
+    #[test]
+    fn should_call_function_by_name() {
+        let mut registry = FunctionRegistry::default();
+        registry
+            .register_with_name("add", add)
+            .unwrap()
+            .register_with_name("double", |value: i32| value * 2)
+            .unwrap();
+
+        let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+        let value = registry.call("add", args).unwrap().unwrap().unwrap_owned();
+        assert_eq!(value.downcast_ref::<i32>(), Some(&100));
+
+        let args = ArgList::new().push_owned(21_i32);
+        let value = registry.call("double", args).unwrap().unwrap();
+        assert_eq!(value.unwrap_owned().downcast_ref::<i32>(), Some(&42));
+
+        assert!(registry.call("missing", ArgList::new()).is_none());
+        assert_eq!(registry.len(), 2);
+    }
+
+    #[test]
+    fn should_error_on_duplicate_name() {
+        let mut registry = FunctionRegistry::default();
+        registry.register_with_name("add", add).unwrap();
+
+        let result = registry.register_with_name("add", |a: i32, b: i32| a - b);
+        assert_eq!(
+            result.unwrap_err(),
+            FunctionRegistrationError::DuplicateName(Cow::Borrowed("add"))
+        );
+
+        let previous = registry
+            .overwrite_registration((|a: i32, b: i32| a - b).into_function().with_name("add"));
+        assert!(previous.is_some());
+
+        let args = ArgList::new().push_owned(25_i32).push_owned(75_i32);
+        let value = registry.call("add", args).unwrap().unwrap().unwrap_owned();
+        assert_eq!(value.downcast_ref::<i32>(), Some(&-50));
+    }
+
+    #[test]
+    fn should_register_overloads_under_one_name() {
+        let mut registry = FunctionRegistry::default();
+        registry
+            .register_overload("add", add)
+            .unwrap()
+            .register_overload("add", |a: f32, b: f32| a + b)
+            .unwrap();
+        assert_eq!(registry.len(), 1);
+
+        let args = ArgList::new().push_owned(0.5_f32).push_owned(0.25_f32);
+        let value = registry.call("add", args).unwrap().unwrap().unwrap_owned();
+        assert_eq!(value.downcast_ref::<f32>(), Some(&0.75));
+
+        let result = registry.register_overload("add", |a: i32, b: i32| a - b);
+        assert!(result.is_err());
+        assert!(registry.get("add").unwrap().is_overloaded());
+    }
+}
@@ -0,0 +1,137 @@
+use alloc::borrow::Cow;
+use core::any::TypeId;
+use core::fmt::{Display, Formatter};
+use core::hash::{Hash, Hasher};
+
+use crate::func::args::{Arg, ArgList, Ownership};
+use crate::func::FunctionInfo;
+use crate::TypeInfo;
+
+/// The argument types of a function signature, used to select between the overloads
+/// of a [`DynamicFunction`].
+///
+/// Each argument is identified by the [`TypeId`] of its type, without its reference,
+/// and its [`Ownership`].
+/// Signatures are displayed using the [type path] of each argument, including any reference,
+/// such as `&glam::Vec3` or `f32`.
+///
+/// [`DynamicFunction`]: crate::func::DynamicFunction
+/// [type path]: crate::TypePath::type_path
+#[derive(Debug, Clone)]
+pub struct ArgumentSignature {
+    types: Vec<(TypeId, Ownership)>,
+    type_paths: Vec<Cow<'static, str>>,
+}
+
+impl ArgumentSignature {
+    /// Returns the signature expected by the given [`FunctionInfo`].
+    pub fn from_info(info: &FunctionInfo) -> Self {
+        Self {
+            types: info
+                .args()
+                .iter()
+                .map(|arg| (arg.owned_type_id(), arg.ownership()))
+                .collect(),
+            type_paths: info
+                .args()
+                .iter()
+                .map(|arg| Cow::Borrowed(arg.type_path()))
+                .collect(),
+        }
+    }
+
+    /// Returns the signature of the given [`ArgList`].
+    ///
+    /// Dynamic types, such as [`DynamicStruct`], are treated as the type they represent, if any.
+    ///
+    /// [`DynamicStruct`]: crate::DynamicStruct
+    pub fn from_args(args: &ArgList) -> Self {
+        Self {
+            types: args
+                .iter()
+                .map(|arg| (arg_type_id(arg), arg.value().ownership()))
+                .collect(),
+            type_paths: args
+                .iter()
+                .map(|arg| {
+                    let value = arg.value();
+                    let type_path = match value.get_represented_type_info() {
+                        Some(info) => info.type_path(),
+                        None => value.reflect_type_path(),
+                    };
+                    Cow::Owned(match value.ownership() {
+                        Ownership::Owned => type_path.to_string(),
+                        Ownership::Ref => format!("&{type_path}"),
+                        Ownership::Mut => format!("&mut {type_path}"),
+                    })
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns `true` if the given [`ArgList`] can be passed to a function
+    /// with the given [`FunctionInfo`].
+    ///
+    /// This is the case when every argument has the same type and [`Ownership`]
+    /// as the corresponding argument of the function.
+    /// Dynamic types, such as [`DynamicStruct`], match the type they represent, if any.
+    ///
+    /// [`DynamicStruct`]: crate::DynamicStruct
+    pub fn matches(info: &FunctionInfo, args: &ArgList) -> bool {
+        info.arg_count() == args.len()
+            && info.args().iter().zip(args.iter()).all(|(expected, arg)| {
+                expected.ownership() == arg.value().ownership()
+                    && expected.owned_type_id() == arg_type_id(arg)
+            })
+    }
+
+    /// The type paths of the arguments in this signature.
+    pub fn type_paths(&self) -> impl ExactSizeIterator<Item = &str> {
+        self.type_paths.iter().map(AsRef::as_ref)
+    }
+
+    /// The number of arguments in this signature.
+    pub fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    /// Returns `true` if this signature takes no arguments.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty()
+    }
+}
+
+/// Returns the [`TypeId`] of the type represented by the given argument, without its reference.
+fn arg_type_id(arg: &Arg) -> TypeId {
+    let value = arg.value();
+    value
+        .get_represented_type_info()
+        .map_or_else(|| value.as_any().type_id(), TypeInfo::type_id)
+}
+
+impl PartialEq for ArgumentSignature {
+    fn eq(&self, other: &Self) -> bool {
+        self.types == other.types
+    }
+}
+
+impl Eq for ArgumentSignature {}
+
+impl Hash for ArgumentSignature {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.types.hash(state);
+    }
+}
+
+impl Display for ArgumentSignature {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "(")?;
+        for (index, type_path) in self.type_paths().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{type_path}")?;
+        }
+        write!(f, ")")
+    }
+}
@@ -182,6 +182,20 @@
 //! assert_eq!(None, value);
 //! ```
 //!
+//! A patch that only contains what changed between two values can be computed with [`diff`]
+//! and applied with [`apply_diff`]. The resulting [`ReflectDiff`] can also be serialized,
+//! which makes it useful for things like undo history and sending changes over the network.
+//!
+//! ```
+//! # use bevy_reflect::{apply_diff, diff};
+//! let before = vec![1, 2, 3];
+//! let after = vec![0, 1, 3];
+//!
+//! let mut value = before.clone();
+//! apply_diff(&mut value, &diff(&before, &after)).unwrap();
+//! assert_eq!(after, value);
+//! ```
+//!
 //! ## `FromReflect`
 //!
 //! It's important to remember that dynamic types are _not_ the concrete type they may be representing.
@@ -445,8 +459,33 @@
 //! This can be useful for generating documentation for scripting language interop or
 //! for displaying tooltips in an editor.
 //!
+//! ## `binary`
+//!
+//! | Default | Dependencies   |
+//! | :-----: | :------------: |
+//! | ❌      | [`bincode`]    |
+//!
+//! This feature enables [`ReflectBinaryWriter`] and [`ReflectBinaryReader`], a compact binary
+//! format for reflected values that stores each type path only once.
+//!
+//! ## `json_schema`
+//!
+//! | Default | Dependencies     |
+//! | :-----: | :--------------: |
+//! | ❌      | [`serde_json`]   |
+//!
+//! This feature enables [`JsonSchemaBuilder`], which generates a [JSON Schema] for the
+//! serialized form of the types in a [type registry].
+//! Editors can use it to validate and auto-complete hand-written scene and config files.
+//!
 //! [Reflection]: https://en.wikipedia.org/wiki/Reflective_programming
 //! [Bevy]: https://bevyengine.org/
+//! [`bincode`]: https://docs.rs/bincode/latest/bincode/
+//! [`ReflectBinaryWriter`]: https://docs.rs/bevy_reflect/latest/bevy_reflect/serde/struct.ReflectBinaryWriter.html
+//! [`ReflectBinaryReader`]: https://docs.rs/bevy_reflect/latest/bevy_reflect/serde/struct.ReflectBinaryReader.html
+//! [`serde_json`]: https://docs.rs/serde_json/latest/serde_json/
+//! [`JsonSchemaBuilder`]: https://docs.rs/bevy_reflect/latest/bevy_reflect/serde/struct.JsonSchemaBuilder.html
+//! [JSON Schema]: https://json-schema.org/
 //! [limitations]: #limitations
 //! [`bevy_reflect`]: crate
 //! [runtime cost]: https://doc.rust-lang.org/book/ch17-02-trait-objects.html#trait-objects-perform-dynamic-dispatch
@@ -474,6 +513,7 @@
 //! [derive `Reflect`]: derive@crate::Reflect
 
 mod array;
+mod diff;
 mod fields;
 mod from_reflect;
 #[cfg(feature = "functions")]
@@ -524,6 +564,7 @@ pub mod prelude {
 }
 
 pub use array::*;
+pub use diff::*;
 pub use enums::*;
 pub use fields::*;
 pub use from_reflect::*;
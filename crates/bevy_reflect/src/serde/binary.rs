@@ -0,0 +1,361 @@
+use std::any::TypeId;
+
+use bevy_utils::HashMap;
+use bincode::{
+    config::{Bounded, WithOtherLimit},
+    de::read::SliceReader,
+    DefaultOptions, Options,
+};
+use serde::{de::DeserializeOwned, de::DeserializeSeed, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::serde::{TypedReflectDeserializer, TypedReflectSerializer};
+use crate::{Reflect, TypeRegistration, TypeRegistry};
+
+/// The bytes every reflect binary starts with.
+const MAGIC: [u8; 4] = *b"BRFL";
+/// The version of the reflect binary format, written after [`MAGIC`].
+const VERSION: u8 = 1;
+
+fn options() -> DefaultOptions {
+    DefaultOptions::new()
+}
+
+type ReadOptions = WithOtherLimit<DefaultOptions, Bounded>;
+
+/// The options used to read `len` bytes.
+///
+/// A value can't be larger than the data it was read from, so the limit makes lengths read
+/// from malformed data fail to decode instead of allocating unbounded amounts of memory.
+fn read_options(len: usize) -> ReadOptions {
+    options().with_limit(len as u64)
+}
+
+/// An error that occurs when writing or reading a reflect binary.
+#[derive(Error, Debug)]
+pub enum ReflectBinaryError {
+    #[error("data is not a reflect binary")]
+    /// The data does not start with the reflect binary header.
+    InvalidHeader,
+    #[error("unsupported reflect binary version {0}, expected {VERSION}")]
+    /// The data was written with a different version of the format.
+    UnsupportedVersion(u8),
+    #[error("type `{0}` does not represent any type")]
+    /// A dynamic value without a represented type was written.
+    MissingTypeInfo(Box<str>),
+    #[error("type `{0}` is not registered in the type registry")]
+    /// The type table contains a type that is not registered.
+    UnregisteredType(Box<str>),
+    #[error("type index {0} is not in the type table")]
+    /// A value refers to a type that is not in the type table.
+    InvalidTypeIndex(u32),
+    #[error(transparent)]
+    /// The data could not be encoded or decoded.
+    Bincode(#[from] bincode::Error),
+}
+
+/// Writes reflected values into a compact binary format.
+///
+/// The type path of every type written with [`ReflectBinaryWriter::write`] is stored only once,
+/// in a type table at the start of the output, and values refer to their type by its index
+/// in that table. The values themselves are encoded like [`TypedReflectSerializer`] with
+/// [`bincode`] using variable length integers, so fields are written without their names.
+///
+/// Use [`ReflectBinaryReader`] to read the values back, in the same order they were written.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{FromReflect, TypeRegistry, serde::{ReflectBinaryReader, ReflectBinaryWriter}};
+/// #[derive(Reflect, PartialEq, Debug)]
+/// struct Health(u32);
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Health>();
+///
+/// let mut writer = ReflectBinaryWriter::new(&registry);
+/// writer.write(&Health(100)).unwrap();
+/// writer.write(&Health(42)).unwrap();
+/// let bytes = writer.finish();
+///
+/// let mut reader = ReflectBinaryReader::new(&bytes, &registry).unwrap();
+/// let first = Health::from_reflect(&*reader.read().unwrap()).unwrap();
+/// let second = Health::from_reflect(&*reader.read().unwrap()).unwrap();
+/// assert_eq!((Health(100), Health(42)), (first, second));
+/// ```
+pub struct ReflectBinaryWriter<'a> {
+    registry: &'a TypeRegistry,
+    type_indices: HashMap<TypeId, u32>,
+    type_paths: Vec<&'static str>,
+    body: Vec<u8>,
+}
+
+impl<'a> ReflectBinaryWriter<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            type_indices: HashMap::default(),
+            type_paths: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Writes a value along with a reference to its type, so it can be read back with
+    /// [`ReflectBinaryReader::read`].
+    pub fn write(&mut self, value: &dyn Reflect) -> Result<(), ReflectBinaryError> {
+        let info = value
+            .get_represented_type_info()
+            .ok_or_else(|| ReflectBinaryError::MissingTypeInfo(value.reflect_type_path().into()))?;
+        let type_paths = &mut self.type_paths;
+        let index = *self.type_indices.entry(info.type_id()).or_insert_with(|| {
+            type_paths.push(info.type_path());
+            type_paths.len() as u32 - 1
+        });
+        self.write_serde(&index)?;
+        self.write_typed(value)
+    }
+
+    /// Writes a value without its type, so it can be read back with
+    /// [`ReflectBinaryReader::read_typed`] when the type is known.
+    pub fn write_typed(&mut self, value: &dyn Reflect) -> Result<(), ReflectBinaryError> {
+        self.write_serde(&TypedReflectSerializer::new(value, self.registry))
+    }
+
+    /// Writes any serializable value, such as the length of a collection of reflected values.
+    /// Read it back with [`ReflectBinaryReader::read_serde`].
+    pub fn write_serde<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), ReflectBinaryError> {
+        options().serialize_into(&mut self.body, value)?;
+        Ok(())
+    }
+
+    /// Returns the encoded type table followed by all the values written so far.
+    pub fn finish(self) -> Vec<u8> {
+        let mut output = Vec::with_capacity(self.body.len() + 64);
+        output.extend_from_slice(&MAGIC);
+        output.push(VERSION);
+        options()
+            .serialize_into(&mut output, &self.type_paths)
+            .expect("writing type paths to a `Vec` should never fail");
+        output.extend_from_slice(&self.body);
+        output
+    }
+}
+
+/// Reads reflected values written with a [`ReflectBinaryWriter`].
+///
+/// Every type in the type table needs to be registered in the [`TypeRegistry`].
+/// Values are read back as dynamic values, just like with [`TypedReflectDeserializer`],
+/// including the defaults of fields marked with `#[reflect(skip_serializing)]`.
+pub struct ReflectBinaryReader<'a, 'de> {
+    registry: &'a TypeRegistry,
+    types: Vec<&'a TypeRegistration>,
+    deserializer: bincode::Deserializer<SliceReader<'de>, ReadOptions>,
+}
+
+impl<'a, 'de> ReflectBinaryReader<'a, 'de> {
+    /// Reads the header and type table of `bytes`.
+    pub fn new(bytes: &'de [u8], registry: &'a TypeRegistry) -> Result<Self, ReflectBinaryError> {
+        let header_len = MAGIC.len() + 1;
+        if bytes.len() < header_len || bytes[..MAGIC.len()] != MAGIC {
+            return Err(ReflectBinaryError::InvalidHeader);
+        }
+        if bytes[MAGIC.len()] != VERSION {
+            return Err(ReflectBinaryError::UnsupportedVersion(bytes[MAGIC.len()]));
+        }
+
+        let body = &bytes[header_len..];
+        let mut deserializer = bincode::Deserializer::from_slice(body, read_options(body.len()));
+        let type_paths = Vec::<String>::deserialize(&mut deserializer)?;
+        let types = type_paths
+            .into_iter()
+            .map(|type_path| {
+                registry
+                    .get_with_type_path(&type_path)
+                    .ok_or_else(|| ReflectBinaryError::UnregisteredType(type_path.into()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            registry,
+            types,
+            deserializer,
+        })
+    }
+
+    /// Reads a value written with [`ReflectBinaryWriter::write`].
+    pub fn read(&mut self) -> Result<Box<dyn Reflect>, ReflectBinaryError> {
+        let index: u32 = self.read_serde()?;
+        let registration = *self
+            .types
+            .get(index as usize)
+            .ok_or(ReflectBinaryError::InvalidTypeIndex(index))?;
+        self.read_typed(registration)
+    }
+
+    /// Reads a value of the given type written with [`ReflectBinaryWriter::write_typed`].
+    pub fn read_typed(
+        &mut self,
+        registration: &TypeRegistration,
+    ) -> Result<Box<dyn Reflect>, ReflectBinaryError> {
+        Ok(TypedReflectDeserializer::new(registration, self.registry)
+            .deserialize(&mut self.deserializer)?)
+    }
+
+    /// Reads a value written with [`ReflectBinaryWriter::write_serde`].
+    pub fn read_serde<T: DeserializeOwned>(&mut self) -> Result<T, ReflectBinaryError> {
+        Ok(T::deserialize(&mut self.deserializer)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::ReflectSerializer;
+    use crate::{FromReflect, TypePath};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Transform {
+        translation: (f32, f32, f32),
+        scale: f32,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    struct Inventory {
+        items: Vec<String>,
+        counts: HashMap<String, u32>,
+        #[reflect(skip_serializing)]
+        #[reflect(default = "default_selected")]
+        selected: usize,
+        owner: Option<String>,
+        state: State,
+    }
+
+    #[derive(Reflect, Debug, PartialEq)]
+    enum State {
+        Open { page: u8 },
+        Closed,
+    }
+
+    fn default_selected() -> usize {
+        7
+    }
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Transform>();
+        registry.register::<Inventory>();
+        registry
+    }
+
+    fn inventory() -> Inventory {
+        Inventory {
+            items: vec!["sword".to_string(), "shield".to_string()],
+            counts: HashMap::from_iter([("arrow".to_string(), 20)]),
+            selected: 1,
+            owner: Some("Ferris".to_string()),
+            state: State::Open { page: 2 },
+        }
+    }
+
+    #[test]
+    fn should_roundtrip_values() {
+        let registry = registry();
+        let transforms: Vec<_> = (0..10)
+            .map(|i| Transform {
+                translation: (i as f32, 0.0, -1.0),
+                scale: 1.0,
+            })
+            .collect();
+
+        let mut writer = ReflectBinaryWriter::new(&registry);
+        for transform in &transforms {
+            writer.write(transform).unwrap();
+        }
+        writer.write(&inventory()).unwrap();
+        writer.write_serde("end").unwrap();
+        let bytes = writer.finish();
+
+        let mut reader = ReflectBinaryReader::new(&bytes, &registry).unwrap();
+        for transform in &transforms {
+            let value = reader.read().unwrap();
+            assert_eq!(*transform, Transform::from_reflect(&*value).unwrap());
+        }
+        let inventory = Inventory::from_reflect(&*reader.read().unwrap()).unwrap();
+        assert_eq!(
+            Inventory {
+                selected: default_selected(),
+                ..self::inventory()
+            },
+            inventory
+        );
+        assert_eq!("end", reader.read_serde::<String>().unwrap());
+    }
+
+    #[test]
+    fn should_write_each_type_path_once() {
+        let registry = registry();
+        let transform = Transform {
+            translation: (1.0, 2.0, 3.0),
+            scale: 1.0,
+        };
+
+        let mut writer = ReflectBinaryWriter::new(&registry);
+        let mut bincode_size = 0;
+        for _ in 0..100 {
+            writer.write(&transform).unwrap();
+            bincode_size += bincode::serialized_size(&ReflectSerializer::new(&transform, &registry))
+                .unwrap() as usize;
+        }
+        let bytes = writer.finish();
+
+        let type_path = Transform::type_path().as_bytes();
+        let occurrences = bytes
+            .windows(type_path.len())
+            .filter(|window| *window == type_path)
+            .count();
+        assert_eq!(1, occurrences);
+        assert!(bytes.len() * 4 < bincode_size);
+    }
+
+    #[test]
+    fn should_reject_invalid_data() {
+        let registry = registry();
+        assert!(matches!(
+            ReflectBinaryReader::new(b"RON!", &registry),
+            Err(ReflectBinaryError::InvalidHeader)
+        ));
+
+        let mut writer = ReflectBinaryWriter::new(&registry);
+        writer.write(&inventory()).unwrap();
+        let bytes = writer.finish();
+        assert!(matches!(
+            ReflectBinaryReader::new(&bytes, &TypeRegistry::empty()),
+            Err(ReflectBinaryError::UnregisteredType(_))
+        ));
+    }
+
+    #[test]
+    fn should_reject_oversized_lengths() {
+        let registry = registry();
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(VERSION);
+        // A type table claiming to contain `u64::MAX` type paths.
+        options().serialize_into(&mut bytes, &u64::MAX).unwrap();
+        assert!(matches!(
+            ReflectBinaryReader::new(&bytes, &registry),
+            Err(ReflectBinaryError::Bincode(_))
+        ));
+
+        let mut writer = ReflectBinaryWriter::new(&registry);
+        writer.write_serde(&u64::MAX).unwrap();
+        let bytes = writer.finish();
+        let mut reader = ReflectBinaryReader::new(&bytes, &registry).unwrap();
+        assert!(reader.read_serde::<Vec<u8>>().is_err());
+    }
+}
@@ -0,0 +1,631 @@
+use std::fmt::{self, Formatter};
+use std::marker::PhantomData;
+
+use serde::de::{DeserializeSeed, EnumAccess, Error, MapAccess, SeqAccess, VariantAccess, Visitor};
+use serde::ser::{SerializeMap, SerializeSeq, SerializeTuple, SerializeTupleVariant};
+use serde::{Serialize, Serializer};
+
+use crate::serde::{ReflectDeserializer, ReflectSerializer};
+use crate::{ListDiffOp, MapDiffOp, ReflectDiff, TypeRegistry};
+
+const DIFF_VARIANTS: &[&str] = &[
+    "Unchanged",
+    "Replaced",
+    "Struct",
+    "TupleStruct",
+    "Tuple",
+    "Array",
+    "List",
+    "Map",
+    "Enum",
+];
+const OP_VARIANTS: &[&str] = &["Insert", "Remove", "Modify"];
+
+/// A serializer for [`ReflectDiff`].
+///
+/// This is the serializer counterpart to [`ReflectDiffDeserializer`].
+///
+/// # Output
+///
+/// A diff is serialized as an enum with the same variants as [`ReflectDiff`].
+/// Values that were replaced or inserted are serialized with [`ReflectSerializer`],
+/// so their types need to be registered in the [`TypeRegistry`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_reflect::prelude::*;
+/// # use bevy_reflect::{diff, TypeRegistry, serde::ReflectDiffSerializer};
+/// #[derive(Reflect)]
+/// struct Health {
+///     current: u32,
+///     max: u32,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Health>();
+///
+/// let diff = diff(
+///     &Health { current: 100, max: 100 },
+///     &Health { current: 80, max: 100 },
+/// );
+///
+/// let serializer = ReflectDiffSerializer::new(&diff, &registry);
+/// let output = ron::to_string(&serializer).unwrap();
+///
+/// assert_eq!(output, r#"Struct({"current":Replaced({"u32":80})})"#);
+/// ```
+pub struct ReflectDiffSerializer<'a> {
+    pub diff: &'a ReflectDiff,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffSerializer<'a> {
+    pub fn new(diff: &'a ReflectDiff, registry: &'a TypeRegistry) -> Self {
+        Self { diff, registry }
+    }
+}
+
+impl<'a> Serialize for ReflectDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let registry = self.registry;
+        match self.diff {
+            ReflectDiff::Unchanged => {
+                serializer.serialize_unit_variant("ReflectDiff", 0, "Unchanged")
+            }
+            ReflectDiff::Replaced(value) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                1,
+                "Replaced",
+                &ReflectSerializer::new(value.as_ref(), registry),
+            ),
+            ReflectDiff::Struct(fields) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                2,
+                "Struct",
+                &NamedDiffsSerializer { fields, registry },
+            ),
+            ReflectDiff::TupleStruct(fields) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                3,
+                "TupleStruct",
+                &IndexedDiffsSerializer { fields, registry },
+            ),
+            ReflectDiff::Tuple(fields) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                4,
+                "Tuple",
+                &IndexedDiffsSerializer { fields, registry },
+            ),
+            ReflectDiff::Array(fields) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                5,
+                "Array",
+                &IndexedDiffsSerializer { fields, registry },
+            ),
+            ReflectDiff::List(ops) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                6,
+                "List",
+                &ListOpsSerializer { ops, registry },
+            ),
+            ReflectDiff::Map(ops) => serializer.serialize_newtype_variant(
+                "ReflectDiff",
+                7,
+                "Map",
+                &MapOpsSerializer { ops, registry },
+            ),
+            ReflectDiff::Enum { variant, fields } => {
+                let mut state = serializer.serialize_tuple_variant("ReflectDiff", 8, "Enum", 2)?;
+                state.serialize_field(variant)?;
+                state.serialize_field(&IndexedDiffsSerializer { fields, registry })?;
+                state.end()
+            }
+        }
+    }
+}
+
+struct NamedDiffsSerializer<'a> {
+    fields: &'a [(String, ReflectDiff)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for NamedDiffsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.fields.len()))?;
+        for (name, diff) in self.fields {
+            state.serialize_entry(name, &ReflectDiffSerializer::new(diff, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+struct IndexedDiffsSerializer<'a> {
+    fields: &'a [(usize, ReflectDiff)],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for IndexedDiffsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.fields.len()))?;
+        for (index, diff) in self.fields {
+            state.serialize_element(&IndexedDiffSerializer {
+                index: *index,
+                diff: ReflectDiffSerializer::new(diff, self.registry),
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct IndexedDiffSerializer<'a> {
+    index: usize,
+    diff: ReflectDiffSerializer<'a>,
+}
+
+impl<'a> Serialize for IndexedDiffSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_tuple(2)?;
+        state.serialize_element(&self.index)?;
+        state.serialize_element(&self.diff)?;
+        state.end()
+    }
+}
+
+struct ListOpsSerializer<'a> {
+    ops: &'a [ListDiffOp],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ListOpsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.ops.len()))?;
+        for op in self.ops {
+            state.serialize_element(&ListOpSerializer {
+                op,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct ListOpSerializer<'a> {
+    op: &'a ListDiffOp,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ListOpSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.op {
+            ListDiffOp::Insert { index, value } => {
+                let mut state = serializer.serialize_tuple_variant("ListDiffOp", 0, "Insert", 2)?;
+                state.serialize_field(index)?;
+                state.serialize_field(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+                state.end()
+            }
+            ListDiffOp::Remove { index } => {
+                serializer.serialize_newtype_variant("ListDiffOp", 1, "Remove", index)
+            }
+            ListDiffOp::Modify { index, diff } => {
+                let mut state = serializer.serialize_tuple_variant("ListDiffOp", 2, "Modify", 2)?;
+                state.serialize_field(index)?;
+                state.serialize_field(&ReflectDiffSerializer::new(diff, self.registry))?;
+                state.end()
+            }
+        }
+    }
+}
+
+struct MapOpsSerializer<'a> {
+    ops: &'a [MapDiffOp],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for MapOpsSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.ops.len()))?;
+        for op in self.ops {
+            state.serialize_element(&MapOpSerializer {
+                op,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct MapOpSerializer<'a> {
+    op: &'a MapDiffOp,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for MapOpSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.op {
+            MapDiffOp::Insert { key, value } => {
+                let mut state = serializer.serialize_tuple_variant("MapDiffOp", 0, "Insert", 2)?;
+                state.serialize_field(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+                state.serialize_field(&ReflectSerializer::new(value.as_ref(), self.registry))?;
+                state.end()
+            }
+            MapDiffOp::Remove { key } => serializer.serialize_newtype_variant(
+                "MapDiffOp",
+                1,
+                "Remove",
+                &ReflectSerializer::new(key.as_ref(), self.registry),
+            ),
+            MapDiffOp::Modify { key, diff } => {
+                let mut state = serializer.serialize_tuple_variant("MapDiffOp", 2, "Modify", 2)?;
+                state.serialize_field(&ReflectSerializer::new(key.as_ref(), self.registry))?;
+                state.serialize_field(&ReflectDiffSerializer::new(diff, self.registry))?;
+                state.end()
+            }
+        }
+    }
+}
+
+/// A deserializer for [`ReflectDiff`].
+///
+/// This is the deserializer counterpart to [`ReflectDiffSerializer`].
+///
+/// Replaced and inserted values are deserialized with [`ReflectDeserializer`], so they will
+/// generally be dynamic values. [`apply_diff`](crate::apply_diff) applies them to the
+/// target value, so the resulting diff can be used the same way as the original one.
+#[derive(Clone, Copy)]
+pub struct ReflectDiffDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> ReflectDiffDeserializer<'a> {
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self { registry }
+    }
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReflectDiffDeserializer<'a> {
+    type Value = ReflectDiff;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("ReflectDiff", DIFF_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ReflectDiffDeserializer<'a> {
+    type Value = ReflectDiff;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("reflect diff")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let registry = self.registry;
+        let indexed_diffs = SeqSeed(PairSeed(PhantomData::<usize>, self));
+        let (variant, access) = data.variant_seed(VariantSeed(DIFF_VARIANTS))?;
+        Ok(match variant {
+            "Unchanged" => {
+                access.unit_variant()?;
+                ReflectDiff::Unchanged
+            }
+            "Replaced" => ReflectDiff::Replaced(
+                access.newtype_variant_seed(ReflectDeserializer::new(registry))?,
+            ),
+            "Struct" => ReflectDiff::Struct(access.newtype_variant_seed(NamedDiffsSeed(self))?),
+            "TupleStruct" => ReflectDiff::TupleStruct(access.newtype_variant_seed(indexed_diffs)?),
+            "Tuple" => ReflectDiff::Tuple(access.newtype_variant_seed(indexed_diffs)?),
+            "Array" => ReflectDiff::Array(access.newtype_variant_seed(indexed_diffs)?),
+            "List" => ReflectDiff::List(
+                access.newtype_variant_seed(SeqSeed(ListOpDeserializer { registry }))?,
+            ),
+            "Map" => ReflectDiff::Map(
+                access.newtype_variant_seed(SeqSeed(MapOpDeserializer { registry }))?,
+            ),
+            "Enum" => {
+                let (variant, fields) =
+                    access.tuple_variant(2, PairSeed(PhantomData::<String>, indexed_diffs))?;
+                ReflectDiff::Enum { variant, fields }
+            }
+            _ => unreachable!(),
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct ListOpDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ListOpDeserializer<'a> {
+    type Value = ListDiffOp;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("ListDiffOp", OP_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for ListOpDeserializer<'a> {
+    type Value = ListDiffOp;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("list diff operation")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let (variant, access) = data.variant_seed(VariantSeed(OP_VARIANTS))?;
+        Ok(match variant {
+            "Insert" => {
+                let (index, value) = access.tuple_variant(
+                    2,
+                    PairSeed(
+                        PhantomData::<usize>,
+                        ReflectDeserializer::new(self.registry),
+                    ),
+                )?;
+                ListDiffOp::Insert { index, value }
+            }
+            "Remove" => ListDiffOp::Remove {
+                index: access.newtype_variant()?,
+            },
+            "Modify" => {
+                let (index, diff) = access.tuple_variant(
+                    2,
+                    PairSeed(
+                        PhantomData::<usize>,
+                        ReflectDiffDeserializer::new(self.registry),
+                    ),
+                )?;
+                ListDiffOp::Modify { index, diff }
+            }
+            _ => unreachable!(),
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+struct MapOpDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for MapOpDeserializer<'a> {
+    type Value = MapDiffOp;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_enum("MapDiffOp", OP_VARIANTS, self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for MapOpDeserializer<'a> {
+    type Value = MapDiffOp;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map diff operation")
+    }
+
+    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+    where
+        A: EnumAccess<'de>,
+    {
+        let registry = self.registry;
+        let (variant, access) = data.variant_seed(VariantSeed(OP_VARIANTS))?;
+        Ok(match variant {
+            "Insert" => {
+                let (key, value) = access.tuple_variant(
+                    2,
+                    PairSeed(
+                        ReflectDeserializer::new(registry),
+                        ReflectDeserializer::new(registry),
+                    ),
+                )?;
+                MapDiffOp::Insert { key, value }
+            }
+            "Remove" => MapDiffOp::Remove {
+                key: access.newtype_variant_seed(ReflectDeserializer::new(registry))?,
+            },
+            "Modify" => {
+                let (key, diff) = access.tuple_variant(
+                    2,
+                    PairSeed(
+                        ReflectDeserializer::new(registry),
+                        ReflectDiffDeserializer::new(registry),
+                    ),
+                )?;
+                MapDiffOp::Modify { key, diff }
+            }
+            _ => unreachable!(),
+        })
+    }
+}
+
+/// Deserializes the changed fields of a struct, keyed by name.
+#[derive(Clone, Copy)]
+struct NamedDiffsSeed<'a>(ReflectDiffDeserializer<'a>);
+
+impl<'a, 'de> DeserializeSeed<'de> for NamedDiffsSeed<'a> {
+    type Value = Vec<(String, ReflectDiff)>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for NamedDiffsSeed<'a> {
+    type Value = Vec<(String, ReflectDiff)>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("map of field names to reflect diffs")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut fields = Vec::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some(name) = map.next_key::<String>()? {
+            fields.push((name, map.next_value_seed(self.0)?));
+        }
+        Ok(fields)
+    }
+}
+
+/// Deserializes a sequence of values, each with a copy of the given seed.
+#[derive(Clone, Copy)]
+struct SeqSeed<S>(S);
+
+impl<'de, S: DeserializeSeed<'de> + Copy> DeserializeSeed<'de> for SeqSeed<S> {
+    type Value = Vec<S::Value>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, S: DeserializeSeed<'de> + Copy> Visitor<'de> for SeqSeed<S> {
+    type Value = Vec<S::Value>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("sequence")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element_seed(self.0)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+/// Deserializes a tuple of two values with the given seeds.
+#[derive(Clone, Copy)]
+struct PairSeed<A, B>(A, B);
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> DeserializeSeed<'de>
+    for PairSeed<A, B>
+{
+    type Value = (A::Value, B::Value);
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_tuple(2, self)
+    }
+}
+
+impl<'de, A: DeserializeSeed<'de>, B: DeserializeSeed<'de>> Visitor<'de> for PairSeed<A, B> {
+    type Value = (A::Value, B::Value);
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("tuple of two values")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<Self::Value, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let first = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| Error::invalid_length(0, &"tuple of two values"))?;
+        let second = seq
+            .next_element_seed(self.1)?
+            .ok_or_else(|| Error::invalid_length(1, &"tuple of two values"))?;
+        Ok((first, second))
+    }
+}
+
+/// Deserializes an enum variant identifier, given either by name or by index,
+/// into its name from the given list of variants.
+struct VariantSeed(&'static [&'static str]);
+
+impl<'de> DeserializeSeed<'de> for VariantSeed {
+    type Value = &'static str;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl<'de> Visitor<'de> for VariantSeed {
+    type Value = &'static str;
+
+    fn expecting(&self, formatter: &mut Formatter) -> fmt::Result {
+        formatter.write_str("variant identifier")
+    }
+
+    fn visit_u64<E>(self, index: u64) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        usize::try_from(index)
+            .ok()
+            .and_then(|index| self.0.get(index).copied())
+            .ok_or_else(|| {
+                Error::invalid_value(
+                    serde::de::Unexpected::Unsigned(index),
+                    &"valid variant index",
+                )
+            })
+    }
+
+    fn visit_str<E>(self, name: &str) -> Result<Self::Value, E>
+    where
+        E: Error,
+    {
+        self.0
+            .iter()
+            .find(|variant| **variant == name)
+            .copied()
+            .ok_or_else(|| Error::unknown_variant(name, self.0))
+    }
+}
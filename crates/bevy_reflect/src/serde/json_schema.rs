@@ -0,0 +1,524 @@
+use std::any::TypeId;
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+use serde_json::{json, Map, Value};
+
+use crate::serde::{SerializationData, TypedReflectSerializer};
+use crate::std_traits::ReflectDefault;
+use crate::{
+    NamedField, ReflectRef, ReflectSerialize, Struct, TypeInfo, TypeRegistration, TypeRegistry,
+    UnnamedField, VariantInfo,
+};
+
+/// The JSON Schema dialect used by [`JsonSchemaBuilder`].
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Builds [JSON Schemas] describing the output of [`TypedReflectSerializer`] and
+/// [`ReflectSerializer`] for the types in a [`TypeRegistry`].
+///
+/// Every type is described by a definition in the `$defs` of the final schema,
+/// keyed by its [type path], and referred to with a `$ref`.
+///
+/// The schema follows the serde data model in the same way `serde_json` does:
+/// structs and struct variants become objects, tuples, tuple structs, lists and arrays become
+/// arrays, maps become objects and enums are externally tagged, with `Option` being either
+/// `null` or its value.
+/// Types that are serialized with their own [`Serialize`] implementation (via [`ReflectSerialize`])
+/// are described precisely for primitives and strings, and accept any value otherwise.
+///
+/// If a type registers [`ReflectDefault`], its default value (and the default of each of its fields)
+/// is included in the schema. With the `documentation` feature, doc comments are included as descriptions.
+///
+/// # Example
+///
+/// ```
+/// # use std::any::TypeId;
+/// # use bevy_reflect::{prelude::*, TypePath, TypeRegistry, serde::JsonSchemaBuilder};
+/// #[derive(Reflect, Default)]
+/// #[reflect(Default)]
+/// struct Config {
+///     volume: f32,
+///     fullscreen: bool,
+/// }
+///
+/// let mut registry = TypeRegistry::default();
+/// registry.register::<Config>();
+///
+/// let mut builder = JsonSchemaBuilder::new(&registry);
+/// let root = builder.type_ref(TypeId::of::<Config>());
+/// let schema = builder.build(root);
+///
+/// let definition = &schema["$defs"][Config::type_path()];
+/// assert_eq!(definition["type"], "object");
+/// assert_eq!(definition["properties"]["volume"]["default"], 0.0);
+/// ```
+///
+/// [JSON Schemas]: https://json-schema.org/
+/// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+/// [type path]: crate::TypePath::type_path
+/// [`Serialize`]: serde::Serialize
+pub struct JsonSchemaBuilder<'a> {
+    registry: &'a TypeRegistry,
+    definitions: Map<String, Value>,
+}
+
+impl<'a> JsonSchemaBuilder<'a> {
+    /// Creates a builder describing the types registered in `registry`, with no definitions yet.
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            definitions: Map::new(),
+        }
+    }
+
+    /// Returns a schema referring to the definition of the type with the given [`TypeId`],
+    /// adding that definition (and the definitions of the types it contains) if needed.
+    ///
+    /// Types that are not registered accept any value.
+    pub fn type_ref(&mut self, type_id: TypeId) -> Value {
+        let Some(registration) = self.registry.get(type_id) else {
+            return json!({});
+        };
+        let type_path = registration.type_info().type_path();
+        if !self.definitions.contains_key(type_path) {
+            // Insert a placeholder first so recursive types refer to it instead of recursing forever.
+            self.definitions.insert(type_path.to_string(), Value::Null);
+            let definition = self.definition(registration);
+            self.definitions.insert(type_path.to_string(), definition);
+        }
+        json!({ "$ref": definition_ref(type_path) })
+    }
+
+    /// Returns a schema for the output of [`ReflectSerializer`] for any of the registered types:
+    /// an object with a single entry, whose key is the type path and whose value is the serialized data.
+    ///
+    /// [`ReflectSerializer`]: crate::serde::ReflectSerializer
+    pub fn reflect_ref(&mut self) -> Value {
+        let mut schema = self.type_map_ref(self.registry.iter().map(TypeRegistration::type_id));
+        schema["minProperties"] = json!(1);
+        schema["maxProperties"] = json!(1);
+        schema
+    }
+
+    /// Returns a schema for an object mapping the type paths of the given types to their
+    /// serialized data, as used by scenes for their resources and components.
+    pub fn type_map_ref(&mut self, type_ids: impl IntoIterator<Item = TypeId>) -> Value {
+        let mut properties = Map::new();
+        for type_id in type_ids {
+            let Some(registration) = self.registry.get(type_id) else {
+                continue;
+            };
+            let type_path = registration.type_info().type_path();
+            properties.insert(type_path.to_string(), self.type_ref(type_id));
+        }
+        json!({
+            "type": "object",
+            "properties": properties,
+            "additionalProperties": false,
+        })
+    }
+
+    /// Finishes the schema, using `root` as the schema that documents are validated against.
+    pub fn build(self, root: Value) -> Value {
+        let mut schema = match root {
+            Value::Object(root) => root,
+            root => Map::from_iter([("allOf".to_string(), json!([root]))]),
+        };
+        schema.insert("$schema".to_string(), json!(JSON_SCHEMA_DIALECT));
+        schema.insert("$defs".to_string(), Value::Object(self.definitions));
+        Value::Object(schema)
+    }
+
+    fn definition(&mut self, registration: &TypeRegistration) -> Value {
+        let info = registration.type_info();
+        let mut schema = match info {
+            TypeInfo::Enum(enum_info)
+                if info.type_path_table().module_path() == Some("core::option")
+                    && info.type_path_table().ident() == Some("Option") =>
+            {
+                let some = match enum_info.variant("Some") {
+                    Some(VariantInfo::Tuple(variant)) => {
+                        self.type_ref(variant.field_at(0).unwrap().type_id())
+                    }
+                    _ => json!({}),
+                };
+                json!({ "anyOf": [{ "type": "null" }, some] })
+            }
+            _ if registration.data::<ReflectSerialize>().is_some() => {
+                serialized_value_schema(info.type_id())
+            }
+            TypeInfo::Struct(struct_info) => {
+                let serialization_data = registration.data::<SerializationData>();
+                let fields = struct_info.iter().enumerate().filter_map(|(index, field)| {
+                    (!serialization_data.is_some_and(|data| data.is_field_skipped(index)))
+                        .then_some(field)
+                });
+                let default = registration
+                    .data::<ReflectDefault>()
+                    .map(ReflectDefault::default);
+                let default = default
+                    .as_deref()
+                    .and_then(|default| match default.reflect_ref() {
+                        ReflectRef::Struct(default) => Some(default),
+                        _ => None,
+                    });
+                self.fields_schema(fields, default)
+            }
+            TypeInfo::TupleStruct(tuple_struct_info) => {
+                let serialization_data = registration.data::<SerializationData>();
+                let fields = tuple_struct_info.iter().filter(|field| {
+                    !serialization_data.is_some_and(|data| data.is_field_skipped(field.index()))
+                });
+                self.tuple_schema(fields)
+            }
+            TypeInfo::Tuple(tuple_info) => self.tuple_schema(tuple_info.iter()),
+            TypeInfo::List(list_info) => json!({
+                "type": "array",
+                "items": self.type_ref(list_info.item_type_id()),
+            }),
+            TypeInfo::Array(array_info) => json!({
+                "type": "array",
+                "items": self.type_ref(array_info.item_type_id()),
+                "minItems": array_info.capacity(),
+                "maxItems": array_info.capacity(),
+            }),
+            TypeInfo::Map(map_info) => json!({
+                "type": "object",
+                "additionalProperties": self.type_ref(map_info.value_type_id()),
+            }),
+            TypeInfo::Enum(enum_info) => {
+                let variants: Vec<_> = enum_info
+                    .iter()
+                    .map(|variant| self.variant_schema(variant))
+                    .collect();
+                json!({ "oneOf": variants })
+            }
+            // Values can only be serialized with `ReflectSerialize`.
+            TypeInfo::Value(_) => Value::Bool(false),
+        };
+
+        let Value::Object(fields) = &mut schema else {
+            return schema;
+        };
+        fields.insert("title".to_string(), json!(info.type_path()));
+        #[cfg(feature = "documentation")]
+        if let Some(docs) = info.docs() {
+            fields.insert("description".to_string(), json!(docs.trim()));
+        }
+        if let Some(default) = registration.data::<ReflectDefault>() {
+            if let Ok(default) = serde_json::to_value(TypedReflectSerializer::new(
+                &*default.default(),
+                self.registry,
+            )) {
+                fields.insert("default".to_string(), default);
+            }
+        }
+        schema
+    }
+
+    /// Describes a struct or struct variant with the given fields, using the fields of `default`
+    /// as their default values.
+    fn fields_schema<'f>(
+        &mut self,
+        fields: impl Iterator<Item = &'f NamedField>,
+        default: Option<&dyn Struct>,
+    ) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for field in fields {
+            let mut schema = self.type_ref(field.type_id());
+            #[cfg(feature = "documentation")]
+            describe(&mut schema, field.docs());
+            if let Some(value) = default.and_then(|default| default.field(field.name())) {
+                if let Ok(value) =
+                    serde_json::to_value(TypedReflectSerializer::new(value, self.registry))
+                {
+                    schema["default"] = value;
+                }
+            }
+            properties.insert(field.name().to_string(), schema);
+            required.push(field.name());
+        }
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
+    /// Describes a tuple, tuple struct or tuple variant with the given fields.
+    fn tuple_schema<'f>(&mut self, fields: impl Iterator<Item = &'f UnnamedField>) -> Value {
+        let items: Vec<_> = fields
+            .map(|field| {
+                #[allow(unused_mut)]
+                let mut schema = self.type_ref(field.type_id());
+                #[cfg(feature = "documentation")]
+                describe(&mut schema, field.docs());
+                schema
+            })
+            .collect();
+        json!({
+            "type": "array",
+            "minItems": items.len(),
+            "maxItems": items.len(),
+            "prefixItems": items,
+        })
+    }
+
+    /// Describes an enum variant, which is either its name for unit variants or an object with
+    /// a single entry mapping its name to its data.
+    fn variant_schema(&mut self, variant: &VariantInfo) -> Value {
+        let data = match variant {
+            VariantInfo::Unit(_) => None,
+            VariantInfo::Tuple(tuple) if tuple.field_len() == 1 => {
+                Some(self.type_ref(tuple.field_at(0).unwrap().type_id()))
+            }
+            VariantInfo::Tuple(tuple) => Some(self.tuple_schema(tuple.iter())),
+            VariantInfo::Struct(struct_variant) => {
+                Some(self.fields_schema(struct_variant.iter(), None))
+            }
+        };
+        #[allow(unused_mut)]
+        let mut schema = match data {
+            None => json!({ "const": variant.name() }),
+            Some(data) => json!({
+                "type": "object",
+                "properties": { variant.name(): data },
+                "required": [variant.name()],
+                "additionalProperties": false,
+            }),
+        };
+        #[cfg(feature = "documentation")]
+        describe(&mut schema, variant.docs());
+        schema
+    }
+}
+
+/// Adds the given doc comment to `schema` as its description.
+#[cfg(feature = "documentation")]
+fn describe(schema: &mut Value, docs: Option<&str>) {
+    if let Some(docs) = docs {
+        schema["description"] = json!(docs.trim());
+    }
+}
+
+/// Describes a type that is serialized with its own `Serialize` implementation.
+fn serialized_value_schema(type_id: TypeId) -> Value {
+    macro_rules! integer_schema {
+        ($($ty:ty),*) => {
+            $(
+                if type_id == TypeId::of::<$ty>() {
+                    return json!({
+                        "type": "integer",
+                        "minimum": <$ty>::MIN,
+                        "maximum": <$ty>::MAX,
+                    });
+                }
+            )*
+        };
+    }
+    integer_schema!(u8, u16, u32, u64, i8, i16, i32, i64);
+    if type_id == TypeId::of::<usize>() || type_id == TypeId::of::<u128>() {
+        return json!({ "type": "integer", "minimum": 0 });
+    }
+    if type_id == TypeId::of::<isize>() || type_id == TypeId::of::<i128>() {
+        return json!({ "type": "integer" });
+    }
+    if type_id == TypeId::of::<f32>() || type_id == TypeId::of::<f64>() {
+        return json!({ "type": "number" });
+    }
+    if type_id == TypeId::of::<bool>() {
+        return json!({ "type": "boolean" });
+    }
+    if type_id == TypeId::of::<char>() {
+        return json!({ "type": "string", "minLength": 1, "maxLength": 1 });
+    }
+    if type_id == TypeId::of::<String>()
+        || type_id == TypeId::of::<&'static str>()
+        || type_id == TypeId::of::<Cow<'static, str>>()
+        || type_id == TypeId::of::<PathBuf>()
+    {
+        return json!({ "type": "string" });
+    }
+    // Any other custom serialization is unknown to reflection.
+    json!({})
+}
+
+/// Returns the `$ref` for the definition of the type with the given type path.
+///
+/// Type paths are escaped as a JSON pointer and then percent-encoded to form a valid URI fragment.
+fn definition_ref(type_path: &str) -> String {
+    let mut reference = String::from("#/$defs/");
+    for byte in type_path.bytes() {
+        match byte {
+            b'~' => reference.push_str("~0"),
+            b'/' => reference.push_str("~1"),
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b':' => {
+                reference.push(byte as char);
+            }
+            _ => reference.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    reference
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate as bevy_reflect;
+    use crate::serde::ReflectSerializer;
+    use crate::{Reflect, TypePath};
+    use bevy_utils::HashMap;
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    struct Settings {
+        name: String,
+        volume: u8,
+        #[reflect(skip_serializing)]
+        cache: Vec<u8>,
+        resolution: (u32, u32),
+        mode: Mode,
+        bindings: HashMap<String, Vec<Mode>>,
+        label: Option<Tag>,
+        tags: [Tag; 2],
+    }
+
+    #[derive(Reflect, Default)]
+    #[reflect(Default)]
+    enum Mode {
+        #[default]
+        Windowed,
+        Fullscreen(u32),
+        Custom(u32, u32),
+        Borderless {
+            monitor: u32,
+        },
+    }
+
+    #[derive(Reflect, Default)]
+    struct Tag(String);
+
+    fn registry() -> TypeRegistry {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Settings>();
+        registry.register::<Option<Tag>>();
+        registry
+    }
+
+    fn definition<T: TypePath>(schema: &Value) -> &Value {
+        &schema["$defs"][T::type_path()]
+    }
+
+    #[test]
+    fn should_describe_struct() {
+        let registry = registry();
+        let mut builder = JsonSchemaBuilder::new(&registry);
+        let root = builder.type_ref(TypeId::of::<Settings>());
+        let schema = builder.build(root);
+
+        assert_eq!(JSON_SCHEMA_DIALECT, schema["$schema"]);
+        assert_eq!(definition_ref(Settings::type_path()), schema["$ref"]);
+
+        let settings = definition::<Settings>(&schema);
+        assert_eq!("object", settings["type"]);
+        assert_eq!(false, settings["additionalProperties"]);
+        assert!(settings["properties"]["cache"].is_null());
+        assert_eq!(
+            json!([
+                "name",
+                "volume",
+                "resolution",
+                "mode",
+                "bindings",
+                "label",
+                "tags"
+            ]),
+            settings["required"]
+        );
+        assert_eq!(json!(0), settings["properties"]["volume"]["default"]);
+        assert_eq!(json!("Windowed"), settings["properties"]["mode"]["default"]);
+
+        let volume = definition::<u8>(&schema);
+        assert_eq!(
+            json!({"type": "integer", "minimum": 0, "maximum": 255, "title": "u8", "default": 0}),
+            *volume
+        );
+
+        let resolution = definition::<(u32, u32)>(&schema);
+        assert_eq!("array", resolution["type"]);
+        assert_eq!(2, resolution["prefixItems"].as_array().unwrap().len());
+
+        let tags = definition::<[Tag; 2]>(&schema);
+        assert_eq!(2, tags["minItems"]);
+        assert_eq!(2, tags["maxItems"]);
+
+        let bindings = definition::<HashMap<String, Vec<Mode>>>(&schema);
+        assert_eq!(
+            definition_ref(<Vec<Mode>>::type_path()),
+            bindings["additionalProperties"]["$ref"]
+        );
+    }
+
+    #[test]
+    fn should_describe_enum() {
+        let registry = registry();
+        let mut builder = JsonSchemaBuilder::new(&registry);
+        builder.type_ref(TypeId::of::<Settings>());
+        let schema = builder.build(json!({}));
+
+        let variants = definition::<Mode>(&schema)["oneOf"].as_array().unwrap();
+        assert_eq!(json!({ "const": "Windowed" }), variants[0]);
+        assert_eq!(json!(["Fullscreen"]), variants[1]["required"]);
+        assert_eq!(
+            definition_ref("u32"),
+            variants[1]["properties"]["Fullscreen"]["$ref"]
+        );
+        assert_eq!("array", variants[2]["properties"]["Custom"]["type"]);
+        assert_eq!(
+            json!(["monitor"]),
+            variants[3]["properties"]["Borderless"]["required"]
+        );
+
+        let label = definition::<Option<Tag>>(&schema);
+        assert_eq!(json!({ "type": "null" }), label["anyOf"][0]);
+    }
+
+    #[test]
+    fn should_describe_reflect_serializer_output() {
+        let registry = registry();
+        let mut builder = JsonSchemaBuilder::new(&registry);
+        let root = builder.reflect_ref();
+        let schema = builder.build(root);
+
+        let value = Settings::default();
+        let output = serde_json::to_value(ReflectSerializer::new(&value, &registry)).unwrap();
+        let (type_path, data) = output.as_object().unwrap().iter().next().unwrap();
+        assert!(schema["properties"][type_path].is_object());
+        assert_eq!(1, schema["maxProperties"]);
+
+        // Every serialized field is described and required.
+        let settings = definition::<Settings>(&schema);
+        let mut fields: Vec<_> = data.as_object().unwrap().keys().collect();
+        let mut required: Vec<_> = settings["required"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|field| field.as_str().unwrap())
+            .collect();
+        fields.sort();
+        required.sort();
+        assert_eq!(required, fields);
+    }
+
+    #[test]
+    fn should_escape_definition_refs() {
+        assert_eq!(
+            "#/$defs/core::option::Option%3Cu32%3E",
+            definition_ref("core::option::Option<u32>")
+        );
+        assert_eq!("#/$defs/a~1b~0c", definition_ref("a/b~c"));
+    }
+}
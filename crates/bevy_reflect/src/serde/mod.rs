@@ -1,8 +1,18 @@
+#[cfg(feature = "binary")]
+mod binary;
 mod de;
+mod diff;
+#[cfg(feature = "json_schema")]
+mod json_schema;
 mod ser;
 mod type_data;
 
+#[cfg(feature = "binary")]
+pub use binary::*;
 pub use de::*;
+pub use diff::*;
+#[cfg(feature = "json_schema")]
+pub use json_schema::*;
 pub use ser::*;
 pub use type_data::*;
 
@@ -741,7 +741,7 @@ all_tuples!(impl_type_path_tuple, 0, 12, P);
 const _: () = {
     macro_rules! impl_get_ownership_tuple {
     ($($name: ident),*) => {
-        $crate::func::args::impl_get_ownership!(($($name,)*); <$($name),*>);
+        $crate::func::args::impl_get_ownership!(($($name,)*); <$($name: TypePath),*>);
     };
 }
 
@@ -193,6 +193,23 @@ impl TypeRegistry {
             .insert(registration.type_id(), registration);
     }
 
+    /// Removes the registration of the type with the given [`TypeId`], returning it if it was registered.
+    ///
+    /// This is mainly useful when the code backing a registration is about to be unloaded,
+    /// such as with dynamically loaded plugins.
+    /// The type's short path remains [ambiguous](Self::is_ambiguous) if it was before.
+    ///
+    /// This method will _not_ unregister type dependencies.
+    pub fn unregister(&mut self, type_id: TypeId) -> Option<TypeRegistration> {
+        let registration = self.registrations.remove(&type_id)?;
+        let type_path_table = registration.type_info().type_path_table();
+        if self.short_path_to_id.get(type_path_table.short_path()) == Some(&type_id) {
+            self.short_path_to_id.remove(type_path_table.short_path());
+        }
+        self.type_path_to_id.remove(type_path_table.path());
+        Some(registration)
+    }
+
     /// Internal method to register a type with a given [`TypeId`] and [`TypeRegistration`].
     ///
     /// By using this method, we are able to reduce the number of `TypeId` hashes and lookups needed
@@ -795,4 +812,21 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn should_unregister_type() {
+        #[derive(Reflect)]
+        struct Foo;
+
+        let mut registry = crate::TypeRegistry::default();
+        registry.register::<Foo>();
+        let type_path = <Foo as crate::TypePath>::type_path();
+        assert!(registry.get_with_short_type_path("Foo").is_some());
+
+        let registration = registry.unregister(std::any::TypeId::of::<Foo>()).unwrap();
+        assert_eq!(registration.type_info().type_path(), type_path);
+        assert!(registry.get_with_type_path(type_path).is_none());
+        assert!(registry.get_with_short_type_path("Foo").is_none());
+        assert!(registry.unregister(std::any::TypeId::of::<Foo>()).is_none());
+    }
 }
@@ -60,6 +60,13 @@ pub struct ImageLoaderSettings {
     pub is_srgb: bool,
     pub sampler: ImageSampler,
     pub asset_usage: RenderAssetUsages,
+    /// If `true`, lower resolution previews of the image are published while it is still
+    /// loading, using the smallest mip levels that have already been read.
+    ///
+    /// This is currently only supported for KTX2 images, which store their smallest mip levels
+    /// first.
+    #[serde(default)]
+    pub progressive: bool,
 }
 
 impl Default for ImageLoaderSettings {
@@ -69,6 +76,7 @@ impl Default for ImageLoaderSettings {
             is_srgb: true,
             sampler: ImageSampler::Default,
             asset_usage: RenderAssetUsages::default(),
+            progressive: false,
         }
     }
 }
@@ -93,6 +101,14 @@ impl AssetLoader for ImageLoader {
         load_context: &'a mut LoadContext<'_>,
     ) -> Result<Image, Self::Error> {
         let mut bytes = Vec::new();
+        #[cfg(feature = "ktx2")]
+        if settings.progressive
+            && load_context.can_publish_partial()
+            && is_ktx2(settings, load_context)
+        {
+            self.read_progressive_ktx2(reader, &mut bytes, settings, load_context)
+                .await?;
+        }
         reader.read_to_end(&mut bytes).await?;
         let image_type = match settings.format {
             ImageFormatSetting::FromExtension => {
@@ -135,6 +151,76 @@ impl AssetLoader for ImageLoader {
     }
 }
 
+#[cfg(feature = "ktx2")]
+impl ImageLoader {
+    /// The number of bytes read between attempts to publish a preview of a progressive image.
+    const PROGRESSIVE_CHUNK_SIZE: usize = 64 * 1024;
+
+    /// Reads a KTX2 file into `bytes`, publishing a partial [`Image`] each time a more detailed
+    /// mip level becomes available.
+    async fn read_progressive_ktx2(
+        &self,
+        reader: &mut dyn Reader,
+        bytes: &mut Vec<u8>,
+        settings: &ImageLoaderSettings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<(), ImageLoaderError> {
+        use bevy_asset::AsyncReadExt;
+
+        let mut published_level = u32::MAX;
+        let mut chunk = vec![0; Self::PROGRESSIVE_CHUNK_SIZE];
+        loop {
+            let read = reader.read(&mut chunk).await?;
+            if read == 0 {
+                return Ok(());
+            }
+            bytes.extend_from_slice(&chunk[..read]);
+
+            let Some((level, preview)) = super::ktx2_mip_preview(bytes) else {
+                continue;
+            };
+            if level >= published_level {
+                continue;
+            }
+            match Image::from_buffer(
+                #[cfg(all(debug_assertions, feature = "dds"))]
+                load_context.path().display().to_string(),
+                &preview,
+                ImageType::Format(ImageFormat::Ktx2),
+                self.supported_compressed_formats,
+                settings.is_srgb,
+                settings.sampler.clone(),
+                settings.asset_usage,
+            ) {
+                Ok(image) => {
+                    published_level = level;
+                    load_context.publish_partial(image);
+                }
+                Err(err) => {
+                    // The complete image will report the error if there is one.
+                    bevy_utils::tracing::debug!(
+                        "Could not build preview of {}: {err}",
+                        load_context.path().display()
+                    );
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ktx2")]
+fn is_ktx2(settings: &ImageLoaderSettings, load_context: &LoadContext) -> bool {
+    match settings.format {
+        ImageFormatSetting::FromExtension => load_context
+            .path()
+            .extension()
+            .is_some_and(|ext| ext == "ktx2"),
+        ImageFormatSetting::Format(format) => matches!(format, ImageFormat::Ktx2),
+        ImageFormatSetting::Guess => false,
+    }
+}
+
 impl FromWorld for ImageLoader {
     fn from_world(world: &mut World) -> Self {
         let supported_compressed_formats = match world.get_resource::<RenderDevice>() {
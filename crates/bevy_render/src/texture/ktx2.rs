@@ -1490,11 +1490,104 @@ pub fn ktx2_format_to_texture_format(
     })
 }
 
+/// Length of the fixed size KTX2 header, which is followed by the level index.
+const KTX2_HEADER_LENGTH: usize = 80;
+/// Length of a single entry of the KTX2 level index.
+const KTX2_LEVEL_INDEX_ENTRY_LENGTH: usize = 24;
+
+/// Builds a KTX2 file that only contains the mip levels of a partially read KTX2 file whose data
+/// is already available, so that a lower resolution preview can be shown while the rest of the
+/// file is still loading.
+///
+/// KTX2 files store their mip levels from smallest to largest, so a prefix of the file contains
+/// the smallest levels. Returns the index of the most detailed level that is available along with
+/// a copy of `prefix` whose header and level index were rewritten to start at that level.
+///
+/// Returns `None` if no preview can be built yet, if the texture has no mipmaps, if every level
+/// is already available, or if the file uses `BasisLZ` supercompression. In that last case, the
+/// complete file has to be read before the image can be created.
+///
+/// The preview is a complete KTX2 file that can be passed to [`ktx2_buffer_to_image`]. Its level
+/// index and dimensions are the ones of the preview, but the offsets of its levels still point
+/// into `prefix`, so the level data must not be moved.
+///
+/// This is used by the [`ImageLoader`](super::ImageLoader) when
+/// [`ImageLoaderSettings::progressive`](super::ImageLoaderSettings::progressive) is enabled.
+///
+/// # Example
+///
+/// ```no_run
+/// # use bevy_render::texture::{ktx2_buffer_to_image, ktx2_mip_preview, CompressedImageFormats, Image};
+/// fn on_bytes_read(file_prefix: &[u8], published_level: &mut u32) -> Option<Image> {
+///     let (level, preview) = ktx2_mip_preview(file_prefix)?;
+///     // Only publish a preview when it is more detailed than the previous one.
+///     if level >= *published_level {
+///         return None;
+///     }
+///     *published_level = level;
+///     ktx2_buffer_to_image(&preview, CompressedImageFormats::empty(), true).ok()
+/// }
+/// ```
+pub fn ktx2_mip_preview(prefix: &[u8]) -> Option<(u32, Vec<u8>)> {
+    let read_u32 = |offset: usize| {
+        prefix
+            .get(offset..offset + 4)
+            .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()))
+    };
+    let read_u64 = |offset: usize| {
+        prefix
+            .get(offset..offset + 8)
+            .map(|bytes| u64::from_le_bytes(bytes.try_into().unwrap()))
+    };
+
+    if prefix.len() < KTX2_HEADER_LENGTH {
+        return None;
+    }
+    let level_count = read_u32(40)? as usize;
+    // Supercompression scheme 1 is BasisLZ, which stores global data for all levels after the
+    // level data of the smallest levels.
+    if level_count <= 1 || read_u32(44)? == 1 {
+        return None;
+    }
+    let dfd_end = read_u32(48)? as u64 + read_u32(52)? as u64;
+    if dfd_end >= prefix.len() as u64 {
+        return None;
+    }
+
+    // Find the most detailed level for which it and all smaller levels are fully available.
+    let mut first_level = None;
+    for level in (0..level_count).rev() {
+        let entry = KTX2_HEADER_LENGTH + level * KTX2_LEVEL_INDEX_ENTRY_LENGTH;
+        let end = read_u64(entry)?.checked_add(read_u64(entry + 8)?)?;
+        if end > prefix.len() as u64 {
+            break;
+        }
+        first_level = Some(level);
+    }
+    let first_level = first_level.filter(|level| *level > 0)?;
+
+    let mut preview = prefix.to_vec();
+    for offset in [20, 24, 28] {
+        let size = read_u32(offset)?;
+        if size > 0 {
+            let size = (size >> first_level).max(1);
+            preview[offset..offset + 4].copy_from_slice(&size.to_le_bytes());
+        }
+    }
+    let preview_level_count = (level_count - first_level) as u32;
+    preview[40..44].copy_from_slice(&preview_level_count.to_le_bytes());
+    let index_start = KTX2_HEADER_LENGTH + first_level * KTX2_LEVEL_INDEX_ENTRY_LENGTH;
+    let index_end = KTX2_HEADER_LENGTH + level_count * KTX2_LEVEL_INDEX_ENTRY_LENGTH;
+    preview.copy_within(index_start..index_end, KTX2_HEADER_LENGTH);
+
+    Some((first_level as u32, preview))
+}
+
 #[cfg(test)]
 mod tests {
     use crate::texture::CompressedImageFormats;
 
-    use super::ktx2_buffer_to_image;
+    use super::{ktx2_buffer_to_image, ktx2_mip_preview};
 
     #[test]
     fn test_ktx_levels() {
@@ -1521,4 +1614,96 @@ mod tests {
         let result = ktx2_buffer_to_image(&buffer, supported_compressed_formats, true);
         assert!(result.is_ok());
     }
+
+    /// Builds an uncompressed `R8G8B8A8Unorm` KTX2 file whose level `n` is filled with the byte
+    /// `n`, returning it along with the offset of each level and the offset of the level data.
+    fn test_ktx2_file(sizes: &[u32], supercompression: u32) -> (Vec<u8>, Vec<u64>, usize) {
+        let level_lengths: Vec<u64> = sizes.iter().map(|size| (size * size * 4) as u64).collect();
+        let dfd: [u32; 1] = [4];
+        let index_end = 80 + 24 * sizes.len();
+        let dfd_offset = index_end;
+        let data_start = dfd_offset + 4;
+
+        let mut buffer = Vec::new();
+        buffer.extend_from_slice(&[
+            0xab, 0x4b, 0x54, 0x58, 0x20, 0x32, 0x30, 0xbb, 0x0d, 0x0a, 0x1a, 0x0a,
+        ]);
+        for value in [
+            37u32,
+            1,
+            sizes[0],
+            sizes[0],
+            0,
+            0,
+            1,
+            sizes.len() as u32,
+            supercompression,
+        ] {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        for value in [dfd_offset as u32, 4, 0, 0] {
+            buffer.extend_from_slice(&value.to_le_bytes());
+        }
+        buffer.extend_from_slice(&[0; 16]);
+        // Levels are stored from smallest to largest.
+        let mut offsets = vec![0; sizes.len()];
+        let mut offset = data_start as u64;
+        for level in (0..sizes.len()).rev() {
+            offsets[level] = offset;
+            offset += level_lengths[level];
+        }
+        for level in 0..sizes.len() {
+            for value in [offsets[level], level_lengths[level], level_lengths[level]] {
+                buffer.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        buffer.extend_from_slice(&dfd[0].to_le_bytes());
+        for level in (0..sizes.len()).rev() {
+            buffer.resize(buffer.len() + level_lengths[level] as usize, level as u8);
+        }
+        (buffer, offsets, data_start)
+    }
+
+    #[test]
+    fn test_ktx_mip_preview() {
+        // 4x4 pixels data and 3 levels of mipmaps
+        let (buffer, offsets, data_start) = test_ktx2_file(&[4, 2, 1], 0);
+
+        // Only part of the header has been read.
+        assert!(ktx2_mip_preview(&buffer[..40]).is_none());
+        // Only the header has been read.
+        assert!(ktx2_mip_preview(&buffer[..data_start]).is_none());
+
+        // Only the smallest level is available.
+        let (level, preview) = ktx2_mip_preview(&buffer[..offsets[1] as usize]).unwrap();
+        assert_eq!(level, 2);
+        let image = ktx2_buffer_to_image(&preview, CompressedImageFormats::empty(), false).unwrap();
+        assert_eq!(image.width(), 1);
+        assert_eq!(image.texture_descriptor.mip_level_count, 1);
+        assert!(image.data.iter().all(|byte| *byte == 2));
+
+        // The two smallest levels and part of the largest level are available.
+        let (level, preview) = ktx2_mip_preview(&buffer[..(offsets[0] + 8) as usize]).unwrap();
+        assert_eq!(level, 1);
+        let image = ktx2_buffer_to_image(&preview, CompressedImageFormats::empty(), false).unwrap();
+        assert_eq!(image.width(), 2);
+        assert_eq!(image.height(), 2);
+        assert_eq!(image.texture_descriptor.mip_level_count, 2);
+        assert!(image.data[..16].iter().all(|byte| *byte == 1));
+        assert!(image.data[16..].iter().all(|byte| *byte == 2));
+
+        // Every level is available, so the complete file should be used instead.
+        assert!(ktx2_mip_preview(&buffer).is_none());
+    }
+
+    #[test]
+    fn test_ktx_mip_preview_unsupported() {
+        // Without mipmaps, there is nothing to preview.
+        let (buffer, _, _) = test_ktx2_file(&[4], 0);
+        assert!(ktx2_mip_preview(&buffer[..buffer.len() - 1]).is_none());
+
+        // BasisLZ stores data needed by every level after the smallest ones.
+        let (buffer, offsets, _) = test_ktx2_file(&[4, 2, 1], 1);
+        assert!(ktx2_mip_preview(&buffer[..offsets[0] as usize]).is_none());
+    }
 }
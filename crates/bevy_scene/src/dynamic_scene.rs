@@ -1,18 +1,22 @@
 use crate::{ron, DynamicSceneBuilder, Scene, SceneSpawnError};
-use bevy_ecs::entity::EntityHashMap;
+use bevy_ecs::entity::{EntityHashMap, EntityHashSet};
 use bevy_ecs::{
     entity::Entity,
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
     world::World,
 };
-use bevy_reflect::{Reflect, TypePath, TypeRegistry};
+use bevy_hierarchy::{BuildChildren, Children, DespawnRecursiveExt};
+use bevy_reflect::{Reflect, TypeInfo, TypePath, TypeRegistry};
 use bevy_utils::TypeIdMap;
+use std::any::TypeId;
 
 #[cfg(feature = "serialize")]
-use crate::serde::SceneSerializer;
+use crate::serde::{readable::ReadableSceneSerializer, SceneSerializer};
 use bevy_asset::Asset;
 use bevy_ecs::reflect::{ReflectMapEntitiesResource, ReflectResource};
 #[cfg(feature = "serialize")]
+use bevy_reflect::serde::{ReflectBinaryError, ReflectBinaryReader, ReflectBinaryWriter};
+#[cfg(feature = "serialize")]
 use serde::Serialize;
 
 /// A collection of serializable resources and dynamic entities.
@@ -44,6 +48,68 @@ pub struct DynamicEntity {
     pub components: Vec<Box<dyn Reflect>>,
 }
 
+impl DynamicEntity {
+    fn component(&self, type_id: TypeId) -> Option<&dyn Reflect> {
+        find_by_type_id(&self.components, type_id)
+    }
+}
+
+fn find_by_type_id(values: &[Box<dyn Reflect>], type_id: TypeId) -> Option<&dyn Reflect> {
+    values
+        .iter()
+        .find(|value| {
+            value
+                .get_represented_type_info()
+                .is_some_and(|info| info.type_id() == type_id)
+        })
+        .map(|value| &**value)
+}
+
+/// Returns `true` if `previous` and `current` are known to be equal.
+fn is_unchanged(previous: Option<&dyn Reflect>, current: &dyn Reflect) -> bool {
+    previous
+        .and_then(|previous| previous.reflect_partial_eq(current))
+        .unwrap_or(false)
+}
+
+/// The changes made to a world by [`DynamicScene::write_changes_to_world`].
+///
+/// All entities are entities of the world the scene was written to.
+#[derive(Clone, Debug, Default)]
+pub struct SceneChanges {
+    /// Entities spawned because they were added to the scene.
+    pub spawned: Vec<Entity>,
+    /// Entities despawned because they were removed from the scene.
+    pub despawned: Vec<Entity>,
+    /// Components of existing entities that were inserted or overwritten
+    /// because they were added to the scene or their value in the scene changed.
+    pub changed_components: Vec<(Entity, TypeId)>,
+    /// Components of existing entities that were removed because they were removed from the scene.
+    pub removed_components: Vec<(Entity, TypeId)>,
+    /// Resources that were inserted or overwritten.
+    pub changed_resources: Vec<TypeId>,
+    /// Resources that were removed because they were removed from the scene.
+    pub removed_resources: Vec<TypeId>,
+}
+
+impl SceneChanges {
+    /// Returns `true` if nothing was changed.
+    pub fn is_empty(&self) -> bool {
+        self.spawned.is_empty()
+            && self.despawned.is_empty()
+            && self.changed_components.is_empty()
+            && self.removed_components.is_empty()
+            && self.changed_resources.is_empty()
+            && self.removed_resources.is_empty()
+    }
+
+    /// Returns `true` if the component with the given [`TypeId`] was written to `entity`,
+    /// either because it changed or because the entity was spawned.
+    pub fn is_component_written(&self, entity: Entity, type_id: TypeId) -> bool {
+        self.spawned.contains(&entity) || self.changed_components.contains(&(entity, type_id))
+    }
+}
+
 impl DynamicScene {
     /// Create a new dynamic scene from a given scene.
     pub fn from_scene(scene: &Scene) -> Self {
@@ -177,6 +243,231 @@ impl DynamicScene {
         self.write_to_world_with(world, entity_map, &registry)
     }
 
+    /// Write the differences between `previous` and this scene to the given world, where `previous`
+    /// is the version of this scene that was last written using `entity_map`.
+    ///
+    /// Unlike [`write_to_world`](Self::write_to_world), this only touches what changed in the scene:
+    /// * components whose value changed, or that were added to an entity of the scene, are written,
+    /// * components removed from an entity of the scene are removed from the corresponding entity,
+    /// * entities added to the scene are spawned and entities removed from it are despawned,
+    ///   along with their descendants that aren't part of the scene,
+    /// * resources are updated in the same way.
+    ///
+    /// Components that were added to the entities at runtime are left untouched, as are entities of
+    /// the scene that were despawned at runtime. Values are compared using
+    /// [`Reflect::reflect_partial_eq`], so values that don't support comparison are always written.
+    ///
+    /// This method will return a [`SceneSpawnError`] if a type either is not registered
+    /// in the world's [`AppTypeRegistry`] resource, or doesn't reflect the
+    /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
+    pub fn write_changes_to_world(
+        &self,
+        world: &mut World,
+        previous: &DynamicScene,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<SceneChanges, SceneSpawnError> {
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let mut changes = SceneChanges::default();
+
+        let previous_entities = previous
+            .entities
+            .iter()
+            .map(|entity| (entity.entity, entity))
+            .collect::<EntityHashMap<_>>();
+
+        // Components that reference other entities need to be mapped, but only if they were written.
+        let mut scene_mappings: TypeIdMap<Vec<Entity>> = Default::default();
+
+        for scene_entity in &self.entities {
+            let (entity, previous_entity) = match entity_map.get(&scene_entity.entity) {
+                Some(&entity) if world.get_entity(entity).is_none() => continue,
+                Some(&entity) => (entity, previous_entities.get(&scene_entity.entity)),
+                None => {
+                    let entity = world.spawn_empty().id();
+                    entity_map.insert(scene_entity.entity, entity);
+                    changes.spawned.push(entity);
+                    (entity, None)
+                }
+            };
+
+            for component in &scene_entity.components {
+                let type_info = component.get_represented_type_info().ok_or_else(|| {
+                    SceneSpawnError::NoRepresentedType {
+                        type_path: component.reflect_type_path().to_string(),
+                    }
+                })?;
+                let type_id = type_info.type_id();
+                if is_unchanged(
+                    previous_entity.and_then(|previous| previous.component(type_id)),
+                    &**component,
+                ) {
+                    continue;
+                }
+
+                let registration = type_registry.get(type_id).ok_or_else(|| {
+                    SceneSpawnError::UnregisteredButReflectedType {
+                        type_path: type_info.type_path().to_string(),
+                    }
+                })?;
+                let reflect_component =
+                    registration.data::<ReflectComponent>().ok_or_else(|| {
+                        SceneSpawnError::UnregisteredComponent {
+                            type_path: type_info.type_path().to_string(),
+                        }
+                    })?;
+
+                if registration.data::<ReflectMapEntities>().is_some() {
+                    scene_mappings.entry(type_id).or_default().push(entity);
+                }
+
+                reflect_component.apply_or_insert(
+                    &mut world.entity_mut(entity),
+                    &**component,
+                    &type_registry,
+                );
+                if previous_entity.is_some() {
+                    changes.changed_components.push((entity, type_id));
+                }
+            }
+
+            let Some(previous_entity) = previous_entity else {
+                continue;
+            };
+            for component in &previous_entity.components {
+                let Some(type_id) = component.get_represented_type_info().map(TypeInfo::type_id)
+                else {
+                    continue;
+                };
+                if scene_entity.component(type_id).is_some() {
+                    continue;
+                }
+                if let Some(reflect_component) = type_registry
+                    .get(type_id)
+                    .and_then(|registration| registration.data::<ReflectComponent>())
+                {
+                    reflect_component.remove(&mut world.entity_mut(entity));
+                    changes.removed_components.push((entity, type_id));
+                }
+            }
+        }
+
+        // Despawn the entities that were removed from the scene, keeping their children that
+        // are still part of it.
+        let scene_entities = self
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .collect::<EntityHashSet>();
+        let removed = entity_map
+            .iter()
+            .filter(|(&scene_entity, _)| !scene_entities.contains(&scene_entity))
+            .map(|(&scene_entity, &entity)| (scene_entity, entity))
+            .collect::<Vec<_>>();
+        for (scene_entity, _) in &removed {
+            entity_map.remove(scene_entity);
+        }
+        let kept = entity_map.values().copied().collect::<EntityHashSet>();
+        for (_, entity) in removed {
+            let Some(mut entity_mut) = world.get_entity_mut(entity) else {
+                continue;
+            };
+            let kept_children = entity_mut
+                .get::<Children>()
+                .map(|children| {
+                    children
+                        .iter()
+                        .filter(|&child| kept.contains(child))
+                        .copied()
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            entity_mut.remove_children(&kept_children);
+            entity_mut.remove_parent();
+            entity_mut.despawn_recursive();
+            changes.despawned.push(entity);
+        }
+
+        for (type_id, entities) in scene_mappings.into_iter() {
+            let registration = type_registry.get(type_id).expect(
+                "we should be getting TypeId from this TypeRegistration in the first place",
+            );
+            if let Some(map_entities_reflect) = registration.data::<ReflectMapEntities>() {
+                map_entities_reflect.map_entities(world, entity_map, &entities);
+            }
+        }
+
+        for resource in &self.resources {
+            let type_info = resource.get_represented_type_info().ok_or_else(|| {
+                SceneSpawnError::NoRepresentedType {
+                    type_path: resource.reflect_type_path().to_string(),
+                }
+            })?;
+            let type_id = type_info.type_id();
+            if is_unchanged(find_by_type_id(&previous.resources, type_id), &**resource) {
+                continue;
+            }
+
+            let registration = type_registry.get(type_id).ok_or_else(|| {
+                SceneSpawnError::UnregisteredButReflectedType {
+                    type_path: type_info.type_path().to_string(),
+                }
+            })?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_path: type_info.type_path().to_string(),
+                }
+            })?;
+
+            reflect_resource.apply_or_insert(world, &**resource, &type_registry);
+            if let Some(map_entities_reflect) = registration.data::<ReflectMapEntitiesResource>() {
+                map_entities_reflect.map_entities(world, entity_map);
+            }
+            changes.changed_resources.push(type_id);
+        }
+
+        for resource in &previous.resources {
+            let Some(type_id) = resource.get_represented_type_info().map(TypeInfo::type_id) else {
+                continue;
+            };
+            if find_by_type_id(&self.resources, type_id).is_some() {
+                continue;
+            }
+            if let Some(reflect_resource) = type_registry
+                .get(type_id)
+                .and_then(|registration| registration.data::<ReflectResource>())
+            {
+                reflect_resource.remove(world);
+                changes.removed_resources.push(type_id);
+            }
+        }
+
+        Ok(changes)
+    }
+
+    /// Clones all resources and entities of this scene.
+    pub(crate) fn clone_scene(&self) -> Self {
+        Self {
+            resources: self
+                .resources
+                .iter()
+                .map(|resource| resource.clone_value())
+                .collect(),
+            entities: self
+                .entities
+                .iter()
+                .map(|entity| DynamicEntity {
+                    entity: entity.entity,
+                    components: entity
+                        .components
+                        .iter()
+                        .map(|component| component.clone_value())
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
     // TODO: move to AssetSaver when it is implemented
     /// Serialize this dynamic scene into the official Bevy scene format (`.scn` / `.scn.ron`).
     ///
@@ -189,6 +480,70 @@ impl DynamicScene {
     pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into the [readable scene format] (`.scene.ron`).
+    ///
+    /// This format is meant to be edited by hand: children are nested inside their parents,
+    /// types are referred to by their short type path and fields left at their default value
+    /// are omitted. To deserialize the scene, use the [`ReadableSceneLoader`].
+    ///
+    /// [readable scene format]: crate::serde::readable
+    /// [`ReadableSceneLoader`]: crate::ReadableSceneLoader
+    #[cfg(feature = "serialize")]
+    pub fn serialize_readable(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
+        serialize_ron(ReadableSceneSerializer::new(self, registry))
+    }
+
+    /// Serialize this dynamic scene into a compact binary format.
+    ///
+    /// The type path of each resource and component type is only stored once, which makes the
+    /// output much smaller and faster to read than [`DynamicScene::serialize`], at the cost of not
+    /// being human readable. Use [`DynamicScene::deserialize_binary`] to read it back.
+    #[cfg(feature = "serialize")]
+    pub fn serialize_binary(&self, registry: &TypeRegistry) -> Result<Vec<u8>, ReflectBinaryError> {
+        let mut writer = ReflectBinaryWriter::new(registry);
+        writer.write_serde(&self.resources.len())?;
+        for resource in &self.resources {
+            writer.write(resource.as_ref())?;
+        }
+        writer.write_serde(&self.entities.len())?;
+        for entity in &self.entities {
+            writer.write_serde(&entity.entity.to_bits())?;
+            writer.write_serde(&entity.components.len())?;
+            for component in &entity.components {
+                writer.write(component.as_ref())?;
+            }
+        }
+        Ok(writer.finish())
+    }
+
+    /// Deserialize a dynamic scene written with [`DynamicScene::serialize_binary`].
+    #[cfg(feature = "serialize")]
+    pub fn deserialize_binary(
+        bytes: &[u8],
+        registry: &TypeRegistry,
+    ) -> Result<Self, ReflectBinaryError> {
+        let mut reader = ReflectBinaryReader::new(bytes, registry)?;
+        let resource_count: usize = reader.read_serde()?;
+        let resources = (0..resource_count)
+            .map(|_| reader.read())
+            .collect::<Result<_, _>>()?;
+        let entity_count: usize = reader.read_serde()?;
+        // The count comes from the data, so it can't be trusted to preallocate.
+        let mut entities = Vec::new();
+        for _ in 0..entity_count {
+            let entity = Entity::from_bits(reader.read_serde()?);
+            let component_count: usize = reader.read_serde()?;
+            let components = (0..component_count)
+                .map(|_| reader.read())
+                .collect::<Result<_, _>>()?;
+            entities.push(DynamicEntity { entity, components });
+        }
+        Ok(Self {
+            resources,
+            entities,
+        })
+    }
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
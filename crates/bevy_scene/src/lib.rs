@@ -14,6 +14,7 @@
 mod bundle;
 mod dynamic_scene;
 mod dynamic_scene_builder;
+mod prefab;
 mod scene;
 mod scene_filter;
 mod scene_loader;
@@ -29,6 +30,7 @@ use bevy_ecs::schedule::IntoSystemConfigs;
 pub use bundle::*;
 pub use dynamic_scene::*;
 pub use dynamic_scene_builder::*;
+pub use prefab::*;
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
@@ -39,7 +41,7 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         DynamicScene, DynamicSceneBuilder, DynamicSceneBundle, Scene, SceneBundle, SceneFilter,
-        SceneSpawner,
+        ScenePrefab, SceneSpawner,
     };
 }
 
@@ -56,9 +58,15 @@ impl Plugin for ScenePlugin {
         app.init_asset::<DynamicScene>()
             .init_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_loader::<ReadableSceneLoader>()
             .add_event::<SceneInstanceReady>()
+            .add_event::<SceneInstanceUpdated>()
             .init_resource::<SceneSpawner>()
-            .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
+            .register_type::<ScenePrefab>()
+            .add_systems(
+                SpawnScene,
+                (scene_spawner, spawn_scene_prefabs, scene_spawner_system).chain(),
+            );
 
         // Register component hooks for DynamicScene
         app.world_mut()
@@ -79,6 +87,18 @@ impl Plugin for ScenePlugin {
                 }
             });
 
+        // Register component hooks for ScenePrefab
+        app.world_mut()
+            .register_component_hooks::<ScenePrefab>()
+            .on_remove(|mut world, entity, _| {
+                if let Some(&SceneInstance(scene_instance)) = world.get::<SceneInstance>(entity) {
+                    let Some(mut scene_spawner) = world.get_resource_mut::<SceneSpawner>() else {
+                        return;
+                    };
+                    scene_spawner.despawn_instance(scene_instance);
+                }
+            });
+
         // Register component hooks for Scene
         app.world_mut()
             .register_component_hooks::<Handle<Scene>>()
@@ -0,0 +1,847 @@
+use bevy_asset::{AssetPath, AssetServer, Handle};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    entity::{Entity, EntityHashMap},
+    prelude::{Changed, Component, ReflectComponent},
+    reflect::AppTypeRegistry,
+    system::{Commands, Query, Res, ResMut},
+    world::World,
+};
+use bevy_reflect::{
+    apply_diff, diff, prelude::ReflectDefault, ApplyDiffError, Reflect, ReflectDiff, TypePath,
+    TypeRegistration,
+};
+use bevy_utils::tracing::error;
+use thiserror::Error;
+#[cfg(feature = "serialize")]
+use {
+    bevy_reflect::{
+        serde::{ReflectDiffDeserializer, ReflectDiffSerializer},
+        FromReflect, TypeRegistry,
+    },
+    serde::{
+        de::{DeserializeSeed, Error as _, MapAccess, SeqAccess, Visitor},
+        ser::{SerializeSeq, SerializeStruct},
+        Deserialize, Deserializer, Serialize, Serializer,
+    },
+    std::{any::TypeId, fmt::Formatter},
+};
+
+use crate::{DynamicScene, SceneChanges, SceneInstance, SceneSpawner};
+
+/// A reference to another [`DynamicScene`] that is spawned as a child of this entity,
+/// with per-instance overrides applied on top of it.
+///
+/// This allows scenes to be composed from other scenes, like prefabs:
+/// a [`DynamicScene`] may contain entities with a [`ScenePrefab`],
+/// which will in turn spawn their own scene once the outer scene is spawned.
+///
+/// Once spawned, the entity will have a [`SceneInstance`] component.
+/// Changing the [`scene`](Self::scene) or [`path`](Self::path) respawns the instance,
+/// while changing only the [`overrides`](Self::overrides) reapplies them to the existing instance.
+/// When the referenced scene is modified (e.g. hot-reloaded), the instance is updated
+/// and the overrides are applied again.
+#[derive(Component, Reflect, Clone, Default, Debug)]
+#[reflect(Component, Default, Debug)]
+pub struct ScenePrefab {
+    /// Handle to the scene to spawn.
+    ///
+    /// Handles are not serialized. Scenes loaded from files should use [`path`](Self::path) instead.
+    #[reflect(skip_serializing)]
+    pub scene: Handle<DynamicScene>,
+    /// The asset path of the scene to spawn.
+    ///
+    /// If set, the scene is loaded from this path using the [`AssetServer`]
+    /// unless [`scene`](Self::scene) already refers to it.
+    pub path: Option<AssetPath<'static>>,
+    /// Overrides applied to the entities of this instance.
+    ///
+    /// Overrides are only serialized as part of a scene, by the scene serializers.
+    /// Other reflection serializers skip them.
+    #[reflect(skip_serializing)]
+    pub overrides: PrefabOverrides,
+}
+
+impl ScenePrefab {
+    /// Creates a [`ScenePrefab`] spawning the given scene.
+    pub fn new(scene: Handle<DynamicScene>) -> Self {
+        Self {
+            scene,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a [`ScenePrefab`] spawning the scene at the given asset path.
+    pub fn from_path(path: impl Into<AssetPath<'static>>) -> Self {
+        Self {
+            path: Some(path.into()),
+            ..Default::default()
+        }
+    }
+
+    /// Adds an override to this prefab.
+    pub fn with_override(mut self, prefab_override: PrefabOverride) -> Self {
+        self.overrides.0.push(prefab_override);
+        self
+    }
+}
+
+/// A list of [`PrefabOverride`]s applied to the entities of a [`ScenePrefab`] instance.
+///
+/// In a serialized scene, each override is written with its entity, the type path of its component,
+/// and its patch, which is serialized with a [`ReflectDiffSerializer`](bevy_reflect::serde::ReflectDiffSerializer).
+#[derive(Reflect, Clone, Default, Debug)]
+#[reflect_value(Default, Debug)]
+pub struct PrefabOverrides(pub Vec<PrefabOverride>);
+
+/// A reflected patch applied to a component of an entity in a [`ScenePrefab`] instance.
+#[derive(Clone, Debug)]
+pub struct PrefabOverride {
+    /// The entity the override applies to, as identified by [`DynamicEntity::entity`]
+    /// within the prefab's scene.
+    ///
+    /// [`DynamicEntity::entity`]: crate::DynamicEntity::entity
+    pub entity: Entity,
+    /// The [type path] of the overridden component.
+    ///
+    /// [type path]: bevy_reflect::TypePath::type_path
+    pub component: String,
+    /// The patch applied to the component.
+    pub patch: ReflectDiff,
+}
+
+impl PrefabOverride {
+    /// Creates an override of the component `C` of the given scene entity,
+    /// changing it from `base` to `value`.
+    ///
+    /// Only the fields that differ between `base` and `value` are overridden,
+    /// so other changes to the component in the prefab's scene are kept.
+    pub fn new<C: Component + Reflect + TypePath>(entity: Entity, base: &C, value: &C) -> Self {
+        Self {
+            entity,
+            component: C::type_path().to_string(),
+            patch: diff(base, value),
+        }
+    }
+
+    /// Applies this override to a spawned instance, given the instance's scene-to-world entity map.
+    pub fn apply(
+        &self,
+        world: &mut World,
+        entity_map: &EntityHashMap<Entity>,
+    ) -> Result<(), PrefabOverrideError> {
+        let entity =
+            *entity_map
+                .get(&self.entity)
+                .ok_or(PrefabOverrideError::NonExistentEntity {
+                    entity: self.entity,
+                })?;
+
+        let type_registry = world.resource::<AppTypeRegistry>().clone();
+        let type_registry = type_registry.read();
+        let reflect_component = type_registry
+            .get_with_type_path(&self.component)
+            .and_then(|registration| registration.data::<ReflectComponent>())
+            .ok_or_else(|| PrefabOverrideError::UnregisteredComponent {
+                type_path: self.component.clone(),
+            })?;
+
+        let entity_mut =
+            world
+                .get_entity_mut(entity)
+                .ok_or(PrefabOverrideError::NonExistentEntity {
+                    entity: self.entity,
+                })?;
+        let mut component = reflect_component.reflect_mut(entity_mut).ok_or_else(|| {
+            PrefabOverrideError::MissingComponent {
+                entity: self.entity,
+                type_path: self.component.clone(),
+            }
+        })?;
+
+        apply_diff(component.as_reflect_mut(), &self.patch)?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serialize")]
+const SCENE_PREFAB_STRUCT: &str = "ScenePrefab";
+#[cfg(feature = "serialize")]
+const SCENE_PREFAB_FIELDS: &[&str] = &["path", "overrides"];
+#[cfg(feature = "serialize")]
+const PREFAB_OVERRIDE_STRUCT: &str = "PrefabOverride";
+#[cfg(feature = "serialize")]
+const PREFAB_OVERRIDE_FIELDS: &[&str] = &["entity", "component", "patch"];
+
+/// Serializes a [`ScenePrefab`] component along with its [`PrefabOverrides`].
+///
+/// The patches of the overrides need the type registry to be serialized,
+/// so the scene serializers use this instead of a [`TypedReflectSerializer`](bevy_reflect::serde::TypedReflectSerializer).
+#[cfg(feature = "serialize")]
+pub(crate) struct ScenePrefabSerializer<'a> {
+    pub value: &'a dyn Reflect,
+    pub registry: &'a TypeRegistry,
+}
+
+#[cfg(feature = "serialize")]
+impl<'a> ScenePrefabSerializer<'a> {
+    /// Returns `true` if `value` is a (possibly dynamic) [`ScenePrefab`].
+    pub fn is_scene_prefab(value: &dyn Reflect) -> bool {
+        value
+            .get_represented_type_info()
+            .is_some_and(|info| info.type_id() == TypeId::of::<ScenePrefab>())
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'a> Serialize for ScenePrefabSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let prefab = ScenePrefab::from_reflect(self.value).ok_or_else(|| {
+            serde::ser::Error::custom(format_args!(
+                "expected a `{}` value",
+                ScenePrefab::type_path()
+            ))
+        })?;
+
+        let mut state =
+            serializer.serialize_struct(SCENE_PREFAB_STRUCT, SCENE_PREFAB_FIELDS.len())?;
+        state.serialize_field("path", &prefab.path)?;
+        state.serialize_field(
+            "overrides",
+            &PrefabOverridesSerializer {
+                overrides: &prefab.overrides,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serialize")]
+struct PrefabOverridesSerializer<'a> {
+    overrides: &'a PrefabOverrides,
+    registry: &'a TypeRegistry,
+}
+
+#[cfg(feature = "serialize")]
+impl<'a> Serialize for PrefabOverridesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.overrides.0.len()))?;
+        for prefab_override in &self.overrides.0 {
+            state.serialize_element(&PrefabOverrideSerializer {
+                prefab_override,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+#[cfg(feature = "serialize")]
+struct PrefabOverrideSerializer<'a> {
+    prefab_override: &'a PrefabOverride,
+    registry: &'a TypeRegistry,
+}
+
+#[cfg(feature = "serialize")]
+impl<'a> Serialize for PrefabOverrideSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state =
+            serializer.serialize_struct(PREFAB_OVERRIDE_STRUCT, PREFAB_OVERRIDE_FIELDS.len())?;
+        state.serialize_field("entity", &self.prefab_override.entity)?;
+        state.serialize_field("component", &self.prefab_override.component)?;
+        state.serialize_field(
+            "patch",
+            &ReflectDiffSerializer::new(&self.prefab_override.patch, self.registry),
+        )?;
+        state.end()
+    }
+}
+
+/// Deserializes a [`ScenePrefab`] component written by a [`ScenePrefabSerializer`].
+#[cfg(feature = "serialize")]
+pub(crate) struct ScenePrefabDeserializer<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+#[cfg(feature = "serialize")]
+impl<'a> ScenePrefabDeserializer<'a> {
+    /// Returns `true` if `registration` is the registration of [`ScenePrefab`].
+    pub fn is_scene_prefab(registration: &TypeRegistration) -> bool {
+        registration.type_id() == TypeId::of::<ScenePrefab>()
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum ScenePrefabField {
+    Path,
+    Overrides,
+}
+
+#[cfg(feature = "serialize")]
+impl<'a, 'de> DeserializeSeed<'de> for ScenePrefabDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(SCENE_PREFAB_STRUCT, SCENE_PREFAB_FIELDS, self)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'a, 'de> Visitor<'de> for ScenePrefabDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("scene prefab")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let path = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::missing_field("path"))?;
+        let overrides = seq
+            .next_element_seed(PrefabOverridesDeserializer {
+                registry: self.registry,
+            })?
+            .ok_or_else(|| A::Error::missing_field("overrides"))?;
+        Ok(Box::new(ScenePrefab {
+            path,
+            overrides,
+            ..Default::default()
+        }))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut path = None;
+        let mut overrides = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                ScenePrefabField::Path => {
+                    if path.is_some() {
+                        return Err(A::Error::duplicate_field("path"));
+                    }
+                    path = Some(map.next_value()?);
+                }
+                ScenePrefabField::Overrides => {
+                    if overrides.is_some() {
+                        return Err(A::Error::duplicate_field("overrides"));
+                    }
+                    overrides = Some(map.next_value_seed(PrefabOverridesDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+            }
+        }
+
+        Ok(Box::new(ScenePrefab {
+            path: path.unwrap_or_default(),
+            overrides: overrides.unwrap_or_default(),
+            ..Default::default()
+        }))
+    }
+}
+
+#[cfg(feature = "serialize")]
+struct PrefabOverridesDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+#[cfg(feature = "serialize")]
+impl<'a, 'de> DeserializeSeed<'de> for PrefabOverridesDeserializer<'a> {
+    type Value = PrefabOverrides;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'a, 'de> Visitor<'de> for PrefabOverridesDeserializer<'a> {
+    type Value = PrefabOverrides;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("prefab overrides")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut overrides = Vec::new();
+        while let Some(prefab_override) = seq.next_element_seed(PrefabOverrideDeserializer {
+            registry: self.registry,
+        })? {
+            overrides.push(prefab_override);
+        }
+        Ok(PrefabOverrides(overrides))
+    }
+}
+
+#[cfg(feature = "serialize")]
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum PrefabOverrideField {
+    Entity,
+    Component,
+    Patch,
+}
+
+#[cfg(feature = "serialize")]
+struct PrefabOverrideDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+#[cfg(feature = "serialize")]
+impl<'a, 'de> DeserializeSeed<'de> for PrefabOverrideDeserializer<'a> {
+    type Value = PrefabOverride;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(PREFAB_OVERRIDE_STRUCT, PREFAB_OVERRIDE_FIELDS, self)
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl<'a, 'de> Visitor<'de> for PrefabOverrideDeserializer<'a> {
+    type Value = PrefabOverride;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("prefab override")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let entity = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::missing_field("entity"))?;
+        let component = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::missing_field("component"))?;
+        let patch = seq
+            .next_element_seed(ReflectDiffDeserializer::new(self.registry))?
+            .ok_or_else(|| A::Error::missing_field("patch"))?;
+        Ok(PrefabOverride {
+            entity,
+            component,
+            patch,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entity = None;
+        let mut component = None;
+        let mut patch = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                PrefabOverrideField::Entity => {
+                    if entity.is_some() {
+                        return Err(A::Error::duplicate_field("entity"));
+                    }
+                    entity = Some(map.next_value()?);
+                }
+                PrefabOverrideField::Component => {
+                    if component.is_some() {
+                        return Err(A::Error::duplicate_field("component"));
+                    }
+                    component = Some(map.next_value()?);
+                }
+                PrefabOverrideField::Patch => {
+                    if patch.is_some() {
+                        return Err(A::Error::duplicate_field("patch"));
+                    }
+                    patch = Some(map.next_value_seed(ReflectDiffDeserializer::new(self.registry))?);
+                }
+            }
+        }
+
+        Ok(PrefabOverride {
+            entity: entity.ok_or_else(|| A::Error::missing_field("entity"))?,
+            component: component.ok_or_else(|| A::Error::missing_field("component"))?,
+            patch: patch.ok_or_else(|| A::Error::missing_field("patch"))?,
+        })
+    }
+}
+
+/// Errors that can occur when applying a [`PrefabOverride`].
+#[derive(Error, Debug)]
+pub enum PrefabOverrideError {
+    /// The overridden entity does not exist in the prefab instance.
+    #[error("the prefab scene does not contain the entity {entity}")]
+    NonExistentEntity {
+        /// The entity in the prefab's scene.
+        entity: Entity,
+    },
+    /// The overridden component is not registered with `#[reflect(Component)]`.
+    #[error("the overridden component `{type_path}` is not registered. consider adding `#[reflect(Component)]` to your type")]
+    UnregisteredComponent {
+        /// The type path of the component.
+        type_path: String,
+    },
+    /// The overridden entity does not have the component.
+    #[error("the prefab entity {entity} does not have the overridden component `{type_path}`")]
+    MissingComponent {
+        /// The entity in the prefab's scene.
+        entity: Entity,
+        /// The type path of the component.
+        type_path: String,
+    },
+    /// The patch could not be applied to the component.
+    #[error(transparent)]
+    Apply(#[from] ApplyDiffError),
+}
+
+/// System that spawns the scenes of [`ScenePrefab`]s.
+pub fn spawn_scene_prefabs(
+    mut commands: Commands,
+    mut prefabs: Query<
+        (Entity, &mut ScenePrefab, Option<&mut SceneInstance>),
+        Changed<ScenePrefab>,
+    >,
+    asset_server: Option<Res<AssetServer>>,
+    mut scene_spawner: ResMut<SceneSpawner>,
+) {
+    for (entity, mut prefab, instance) in &mut prefabs {
+        if let (Some(path), Some(asset_server)) = (&prefab.path, &asset_server) {
+            if prefab.scene.path() != Some(path) {
+                let scene = asset_server.load(path.clone());
+                prefab.bypass_change_detection().scene = scene;
+            }
+        }
+
+        let scene_id = prefab.scene.id();
+        match instance {
+            Some(instance)
+                if scene_spawner
+                    .spawned_dynamic_scenes
+                    .get(&scene_id)
+                    .is_some_and(|instances| instances.contains(&**instance)) =>
+            {
+                // Only the overrides changed, so keep the existing instance.
+                scene_spawner.prefab_overrides_to_apply.push(**instance);
+            }
+            Some(mut old_instance) => {
+                scene_spawner.despawn_instance(**old_instance);
+                let new_instance = scene_spawner.spawn_prefab(prefab.scene.clone(), entity);
+                *old_instance = SceneInstance(new_instance);
+            }
+            None => {
+                let new_instance = scene_spawner.spawn_prefab(prefab.scene.clone(), entity);
+                commands.entity(entity).insert(SceneInstance(new_instance));
+            }
+        }
+    }
+}
+
+/// Applies the overrides of the [`ScenePrefab`] on `entity` to its spawned instance.
+///
+/// If `changes` is given, only the overrides of components that were written by an update of the
+/// instance are applied, as the others are still in effect.
+pub(crate) fn apply_prefab_overrides(
+    world: &mut World,
+    entity: Entity,
+    entity_map: &EntityHashMap<Entity>,
+    changes: Option<&SceneChanges>,
+) {
+    let Some(prefab) = world.get::<ScenePrefab>(entity) else {
+        return;
+    };
+    let overrides = prefab.overrides.clone();
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    for prefab_override in &overrides.0 {
+        if let Some(changes) = changes {
+            let target = entity_map.get(&prefab_override.entity).copied();
+            let type_id = type_registry
+                .read()
+                .get_with_type_path(&prefab_override.component)
+                .map(TypeRegistration::type_id);
+            let written = target
+                .zip(type_id)
+                .is_some_and(|(target, type_id)| changes.is_component_written(target, type_id));
+            if !written {
+                continue;
+            }
+        }
+        if let Err(err) = prefab_override.apply(world, entity_map) {
+            error!("failed to apply prefab override: {err}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_asset::{AssetPlugin, Assets};
+    use bevy_ecs::query::With;
+    use bevy_hierarchy::Parent;
+
+    use bevy_reflect::FromReflect;
+
+    use super::*;
+    use crate::{DynamicEntity, ScenePlugin};
+
+    #[derive(Component, Reflect, Default, Clone, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health {
+        current: u32,
+        max: u32,
+    }
+
+    #[derive(Component, Reflect, Default)]
+    #[reflect(Component)]
+    struct Outer;
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<Health>()
+            .register_type::<Outer>();
+        app
+    }
+
+    fn scene_with(components: Vec<Box<dyn Reflect>>) -> DynamicScene {
+        DynamicScene {
+            resources: Vec::new(),
+            entities: vec![DynamicEntity {
+                entity: Entity::from_raw(0),
+                components,
+            }],
+        }
+    }
+
+    fn health_override(current: u32) -> PrefabOverride {
+        let base = Health::default();
+        PrefabOverride::new(
+            Entity::from_raw(0),
+            &base,
+            &Health {
+                current,
+                ..base.clone()
+            },
+        )
+    }
+
+    fn spawned_health(app: &mut App) -> (Entity, Health) {
+        let (entity, health) = app
+            .world_mut()
+            .query::<(Entity, &Health)>()
+            .single(app.world());
+        (entity, health.clone())
+    }
+
+    #[test]
+    fn spawns_nested_prefab_with_overrides() {
+        let mut app = setup();
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        let inner = scenes.add(scene_with(vec![Box::new(Health {
+            current: 10,
+            max: 10,
+        })]));
+        let outer = scenes.add(scene_with(vec![
+            Box::new(Outer),
+            Box::new(ScenePrefab::new(inner).with_override(health_override(3))),
+        ]));
+
+        app.world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(outer);
+        app.update();
+        app.update();
+
+        let (entity, health) = spawned_health(&mut app);
+        assert_eq!(
+            health,
+            Health {
+                current: 3,
+                max: 10
+            }
+        );
+
+        // The prefab's scene is spawned as a child of the entity holding the prefab.
+        let parent = app.world().get::<Parent>(entity).unwrap().get();
+        assert!(app.world().entity(parent).contains::<Outer>());
+    }
+
+    #[test]
+    fn reapplies_overrides_when_scene_is_modified() {
+        let mut app = setup();
+        let inner = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene_with(vec![Box::new(Health {
+                current: 10,
+                max: 10,
+            })]));
+        let prefab = app
+            .world_mut()
+            .spawn(ScenePrefab::new(inner.clone()).with_override(health_override(3)))
+            .id();
+        app.update();
+
+        let (entity, health) = spawned_health(&mut app);
+        assert_eq!(
+            health,
+            Health {
+                current: 3,
+                max: 10
+            }
+        );
+
+        // Modifying the base scene updates the instance and keeps the override.
+        let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+        *scenes.get_mut(&inner).unwrap() = scene_with(vec![Box::new(Health {
+            current: 20,
+            max: 20,
+        })]);
+        // Asset events are sent at the end of the frame, so the update happens in the next one.
+        app.update();
+        app.update();
+
+        assert_eq!(
+            spawned_health(&mut app),
+            (
+                entity,
+                Health {
+                    current: 3,
+                    max: 20
+                }
+            )
+        );
+
+        // Changing only the overrides keeps the existing instance.
+        app.world_mut()
+            .get_mut::<ScenePrefab>(prefab)
+            .unwrap()
+            .overrides = PrefabOverrides(vec![health_override(7)]);
+        app.update();
+
+        assert_eq!(
+            spawned_health(&mut app),
+            (
+                entity,
+                Health {
+                    current: 7,
+                    max: 20
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn despawns_instance_with_prefab() {
+        let mut app = setup();
+        let inner = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene_with(vec![Box::new(Health::default())]));
+        let prefab = app.world_mut().spawn(ScenePrefab::new(inner)).id();
+        app.update();
+        assert_eq!(
+            app.world_mut()
+                .query_filtered::<Entity, With<Health>>()
+                .iter(app.world())
+                .count(),
+            1
+        );
+
+        app.world_mut().entity_mut(prefab).remove::<ScenePrefab>();
+        app.update();
+        assert_eq!(
+            app.world_mut()
+                .query_filtered::<Entity, With<Health>>()
+                .iter(app.world())
+                .count(),
+            0
+        );
+    }
+
+    #[cfg(feature = "serialize")]
+    #[test]
+    fn serializes_prefab_with_overrides() {
+        let app = setup();
+        let scene = scene_with(vec![Box::new(
+            ScenePrefab::from_path("scenes/enemy.scn.ron").with_override(health_override(3)),
+        )]);
+
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+        let serialized = scene.serialize(&registry).unwrap();
+
+        let mut deserializer = crate::ron::de::Deserializer::from_str(&serialized).unwrap();
+        let scene = serde::de::DeserializeSeed::deserialize(
+            crate::serde::SceneDeserializer {
+                type_registry: &registry,
+            },
+            &mut deserializer,
+        )
+        .unwrap();
+
+        assert_serialized_prefab(&scene);
+
+        let serialized = crate::serialize_ron(
+            crate::serde::readable::ReadableSceneSerializer::new(&scene, &registry),
+        )
+        .unwrap();
+        let mut deserializer = crate::ron::de::Deserializer::from_str(&serialized).unwrap();
+        let scene = serde::de::DeserializeSeed::deserialize(
+            crate::serde::readable::ReadableSceneDeserializer {
+                type_registry: &registry,
+            },
+            &mut deserializer,
+        )
+        .unwrap();
+        assert_serialized_prefab(&scene);
+    }
+
+    #[cfg(feature = "serialize")]
+    fn assert_serialized_prefab(scene: &DynamicScene) {
+        let prefab = ScenePrefab::from_reflect(&*scene.entities[0].components[0]).unwrap();
+        assert_eq!(prefab.path, Some(AssetPath::from("scenes/enemy.scn.ron")));
+
+        let [prefab_override] = &prefab.overrides.0[..] else {
+            panic!("expected a single override");
+        };
+        assert_eq!(prefab_override.entity, Entity::from_raw(0));
+        assert_eq!(prefab_override.component, Health::type_path());
+        let mut health = Health {
+            current: 10,
+            max: 10,
+        };
+        apply_diff(&mut health, &prefab_override.patch).unwrap();
+        assert_eq!(
+            health,
+            Health {
+                current: 3,
+                max: 10
+            }
+        );
+    }
+}
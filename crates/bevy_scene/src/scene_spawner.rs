@@ -1,4 +1,4 @@
-use crate::{DynamicScene, Scene};
+use crate::{prefab::apply_prefab_overrides, DynamicScene, Scene, SceneChanges};
 use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
 use bevy_ecs::entity::EntityHashMap;
 use bevy_ecs::{
@@ -24,6 +24,17 @@ pub struct SceneInstanceReady {
     pub parent: Option<Entity>,
 }
 
+/// Emitted when a spawned instance of a [`DynamicScene`] is updated after the scene asset changed.
+///
+/// See [`SceneSpawner::update_spawned_scenes`].
+#[derive(Clone, Debug, Event)]
+pub struct SceneInstanceUpdated {
+    /// ID of the updated instance.
+    pub id: InstanceId,
+    /// The changes made to the instance.
+    pub changes: SceneChanges,
+}
+
 /// Information about a scene instance.
 #[derive(Debug)]
 pub struct InstanceInfo {
@@ -70,6 +81,13 @@ pub struct SceneSpawner {
     scenes_to_despawn: Vec<AssetId<DynamicScene>>,
     instances_to_despawn: Vec<InstanceId>,
     scenes_with_parent: Vec<(InstanceId, Entity)>,
+    /// Maps instances spawned by a [`ScenePrefab`](crate::ScenePrefab) to the entity holding it.
+    prefab_instances: HashMap<InstanceId, Entity>,
+    pub(crate) prefab_overrides_to_apply: Vec<InstanceId>,
+    /// The entities that scene instances were spawned as children of.
+    instance_parents: HashMap<InstanceId, Entity>,
+    /// The version of each spawned dynamic scene that its instances were last updated to.
+    scene_snapshots: HashMap<AssetId<DynamicScene>, DynamicScene>,
 }
 
 /// Errors that can occur when spawning a scene.
@@ -148,6 +166,15 @@ impl SceneSpawner {
         instance_id
     }
 
+    /// Schedule the spawn of a new instance of the provided dynamic scene as a child of `prefab`,
+    /// applying the overrides of its [`ScenePrefab`](crate::ScenePrefab) once spawned.
+    pub(crate) fn spawn_prefab(&mut self, id: Handle<DynamicScene>, prefab: Entity) -> InstanceId {
+        let instance_id = self.spawn_dynamic_as_child(id, prefab);
+        self.prefab_instances.insert(instance_id, prefab);
+        self.prefab_overrides_to_apply.push(instance_id);
+        instance_id
+    }
+
     /// Schedule the spawn of a new instance of the provided scene.
     pub fn spawn(&mut self, id: impl Into<Handle<Scene>>) -> InstanceId {
         let instance_id = InstanceId::new();
@@ -180,7 +207,9 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<DynamicScene>>,
     ) -> Result<(), SceneSpawnError> {
-        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id.into()) {
+        let id = id.into();
+        self.scene_snapshots.remove(&id);
+        if let Some(instance_ids) = self.spawned_dynamic_scenes.remove(&id) {
             for instance_id in instance_ids {
                 self.despawn_instance_sync(world, &instance_id);
             }
@@ -190,6 +219,8 @@ impl SceneSpawner {
 
     /// Immediately despawns a scene instance, removing all its entities from the world.
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
+        self.prefab_instances.remove(instance_id);
+        self.instance_parents.remove(instance_id);
         if let Some(instance) = self.spawned_instances.remove(instance_id) {
             for &entity in instance.entity_map.values() {
                 if let Some(mut entity_mut) = world.get_entity_mut(entity) {
@@ -214,9 +245,21 @@ impl SceneSpawner {
             .insert(instance_id, InstanceInfo { entity_map });
         let spawned = self.spawned_dynamic_scenes.entry(id).or_default();
         spawned.insert(instance_id);
+        self.snapshot_scene(world, id);
         Ok(instance_id)
     }
 
+    /// Records the current version of a dynamic scene, unless it already has spawned instances
+    /// that may need to be updated from an older version.
+    fn snapshot_scene(&mut self, world: &World, id: AssetId<DynamicScene>) {
+        if self.scene_snapshots.contains_key(&id) {
+            return;
+        }
+        if let Some(scene) = world.resource::<Assets<DynamicScene>>().get(id) {
+            self.scene_snapshots.insert(id, scene.clone_scene());
+        }
+    }
+
     fn spawn_dynamic_internal(
         world: &mut World,
         id: AssetId<DynamicScene>,
@@ -267,23 +310,89 @@ impl SceneSpawner {
     /// Iterate through all instances of the provided scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding scene has been modified.
+    /// Only the differences between the scene and the version the instances were last updated to are
+    /// written, so that state added at runtime is preserved (see [`DynamicScene::write_changes_to_world`]).
+    /// A [`SceneInstanceUpdated`] event describing the changes is sent for each updated instance.
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
         scene_ids: &[AssetId<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
-        for id in scene_ids {
-            if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) {
-                for instance_id in spawned_instances {
-                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
+        for &id in scene_ids {
+            let Some(instance_ids) = self.spawned_dynamic_scenes.get(&id) else {
+                continue;
+            };
+            let instance_ids = instance_ids.iter().copied().collect::<Vec<_>>();
+            let previous = self.scene_snapshots.remove(&id).unwrap_or_default();
+
+            let updates = world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
+                let scene = scenes
+                    .get(id)
+                    .ok_or(SceneSpawnError::NonExistentScene { id })?;
+
+                let mut updates = Vec::new();
+                for instance_id in instance_ids {
+                    if let Some(instance_info) = self.spawned_instances.get_mut(&instance_id) {
+                        let changes = scene.write_changes_to_world(
+                            world,
+                            &previous,
+                            &mut instance_info.entity_map,
+                        )?;
+                        updates.push(SceneInstanceUpdated {
+                            id: instance_id,
+                            changes,
+                        });
+                    }
+                }
+                self.scene_snapshots.insert(id, scene.clone_scene());
+                Ok(updates)
+            })?;
+
+            for update in updates {
+                if let Some(&parent) = self.instance_parents.get(&update.id) {
+                    for &entity in &update.changes.spawned {
+                        if !world.entity(entity).contains::<Parent>() {
+                            PushChild {
+                                parent,
+                                child: entity,
+                            }
+                            .apply(world);
+                        }
                     }
                 }
+                if let (Some(&prefab), Some(instance)) = (
+                    self.prefab_instances.get(&update.id),
+                    self.spawned_instances.get(&update.id),
+                ) {
+                    apply_prefab_overrides(
+                        world,
+                        prefab,
+                        &instance.entity_map,
+                        Some(&update.changes),
+                    );
+                }
+                world.send_event(update);
             }
         }
         Ok(())
     }
 
+    /// Immediately applies the overrides of all [`ScenePrefab`](crate::ScenePrefab) instances
+    /// scheduled for it, keeping those that are not spawned yet in the queue.
+    pub fn apply_queued_prefab_overrides(&mut self, world: &mut World) {
+        let overrides_to_apply = std::mem::take(&mut self.prefab_overrides_to_apply);
+
+        for instance_id in overrides_to_apply {
+            let Some(&prefab) = self.prefab_instances.get(&instance_id) else {
+                continue;
+            };
+            match self.spawned_instances.get(&instance_id) {
+                Some(instance) => apply_prefab_overrides(world, prefab, &instance.entity_map, None),
+                None => self.prefab_overrides_to_apply.push(instance_id),
+            }
+        }
+    }
+
     /// Immediately despawns all scenes scheduled for despawn by despawning their instances.
     pub fn despawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
         let scenes_to_despawn = std::mem::take(&mut self.scenes_to_despawn);
@@ -319,6 +428,7 @@ impl SceneSpawner {
                         .entry(handle.id())
                         .or_insert_with(HashSet::new);
                     spawned.insert(instance_id);
+                    self.snapshot_scene(world, handle.id());
 
                     // Scenes with parents need more setup before they are ready.
                     // See `set_scene_instance_parent_sync()`.
@@ -372,6 +482,7 @@ impl SceneSpawner {
 
         for (instance_id, parent) in scenes_with_parent {
             if let Some(instance) = self.spawned_instances.get(&instance_id) {
+                self.instance_parents.insert(instance_id, parent);
                 for &entity in instance.entity_map.values() {
                     // Add the `Parent` component to the scene root, and update the `Children` component of
                     // the scene parent
@@ -471,6 +582,7 @@ pub fn scene_spawner_system(world: &mut World) {
             .update_spawned_scenes(world, &updated_spawned_scenes)
             .unwrap();
         scene_spawner.set_scene_instance_parent_sync(world);
+        scene_spawner.apply_queued_prefab_overrides(world);
     });
 }
 
@@ -486,7 +598,7 @@ mod tests {
     use bevy_ecs::{component::Component, system::Query};
     use bevy_reflect::Reflect;
 
-    use crate::{DynamicSceneBuilder, ScenePlugin};
+    use crate::{DynamicEntity, DynamicSceneBuilder, ScenePlugin};
 
     use super::*;
 
@@ -756,4 +868,106 @@ mod tests {
         app.update();
         check(app.world_mut(), 0);
     }
+
+    #[derive(Reflect, Component, Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[reflect(Component)]
+    struct B(usize);
+
+    #[test]
+    fn update_preserves_runtime_state() {
+        let mut app = App::new();
+        app.add_plugins((AssetPlugin::default(), ScenePlugin))
+            .register_type::<A>()
+            .register_type::<B>();
+
+        let scene_entity = Entity::from_raw;
+        let scene = DynamicScene {
+            resources: Vec::new(),
+            entities: vec![
+                DynamicEntity {
+                    entity: scene_entity(0),
+                    components: vec![Box::new(A(1))],
+                },
+                DynamicEntity {
+                    entity: scene_entity(1),
+                    components: vec![Box::new(A(2)), Box::new(B(2))],
+                },
+                DynamicEntity {
+                    entity: scene_entity(2),
+                    components: vec![Box::new(A(3))],
+                },
+            ],
+        };
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene);
+        let instance_id = app
+            .world_mut()
+            .resource_mut::<SceneSpawner>()
+            .spawn_dynamic(handle.clone());
+        app.update();
+
+        let entity_map = |app: &App| {
+            app.world().resource::<SceneSpawner>().spawned_instances[&instance_id]
+                .entity_map
+                .clone()
+        };
+        let spawned = entity_map(&app);
+        let (first, second, third) = (
+            spawned[&scene_entity(0)],
+            spawned[&scene_entity(1)],
+            spawned[&scene_entity(2)],
+        );
+
+        // Change the world at runtime.
+        app.world_mut().entity_mut(first).insert(B(100));
+        app.world_mut().get_mut::<A>(second).unwrap().0 = 20;
+
+        // Change the scene asset.
+        {
+            let mut scenes = app.world_mut().resource_mut::<Assets<DynamicScene>>();
+            let scene = scenes.get_mut(&handle).unwrap();
+            scene.entities[0].components = vec![Box::new(A(10))];
+            scene.entities[1].components.pop();
+            scene.entities[2] = DynamicEntity {
+                entity: scene_entity(3),
+                components: vec![Box::new(A(4))],
+            };
+        }
+        // Asset events are sent at the end of the frame, so the update happens on the next one.
+        app.update();
+        app.update();
+
+        let world = app.world();
+        assert_eq!(world.get::<A>(first), Some(&A(10)));
+        assert_eq!(world.get::<B>(first), Some(&B(100)));
+        assert_eq!(world.get::<A>(second), Some(&A(20)));
+        assert_eq!(world.get::<B>(second), None);
+        assert!(world.get_entity(third).is_none());
+
+        let updated = entity_map(&app);
+        let fourth = updated[&scene_entity(3)];
+        assert_eq!(world.get::<A>(fourth), Some(&A(4)));
+        assert!(!updated.contains_key(&scene_entity(2)));
+
+        let events = world.resource::<Events<SceneInstanceUpdated>>();
+        let mut reader = events.get_cursor();
+        let mut events = reader.read(events);
+        let event = events
+            .next()
+            .expect("found no `SceneInstanceUpdated` event");
+        assert_eq!(event.id, instance_id);
+        assert_eq!(event.changes.spawned, vec![fourth]);
+        assert_eq!(event.changes.despawned, vec![third]);
+        assert_eq!(
+            event.changes.changed_components,
+            vec![(first, std::any::TypeId::of::<A>())]
+        );
+        assert_eq!(
+            event.changes.removed_components,
+            vec![(second, std::any::TypeId::of::<B>())]
+        );
+        assert!(events.next().is_none(), "found more than one event");
+    }
 }
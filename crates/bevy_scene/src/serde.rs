@@ -1,5 +1,6 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
+use crate::prefab::{ScenePrefabDeserializer, ScenePrefabSerializer};
 use crate::{DynamicEntity, DynamicScene};
 use bevy_ecs::entity::Entity;
 use bevy_reflect::serde::{TypedReflectDeserializer, TypedReflectSerializer};
@@ -16,6 +17,8 @@ use serde::{
 };
 use std::fmt::Formatter;
 
+pub mod readable;
+
 /// Name of the serialized scene struct type.
 pub const SCENE_STRUCT: &str = "Scene";
 /// Name of the serialized resources field in a scene struct.
@@ -167,10 +170,21 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
     {
         let mut state = serializer.serialize_map(Some(self.entries.len()))?;
         for reflect in self.entries {
-            state.serialize_entry(
-                reflect.get_represented_type_info().unwrap().type_path(),
-                &TypedReflectSerializer::new(&**reflect, self.registry),
-            )?;
+            let type_path = reflect.get_represented_type_info().unwrap().type_path();
+            if ScenePrefabSerializer::is_scene_prefab(&**reflect) {
+                state.serialize_entry(
+                    type_path,
+                    &ScenePrefabSerializer {
+                        value: &**reflect,
+                        registry: self.registry,
+                    },
+                )?;
+            } else {
+                state.serialize_entry(
+                    type_path,
+                    &TypedReflectSerializer::new(&**reflect, self.registry),
+                )?;
+            }
         }
         state.end()
     }
@@ -470,9 +484,14 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
                 )));
             }
 
-            entries.push(
-                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?,
-            );
+            let value = if ScenePrefabDeserializer::is_scene_prefab(registration) {
+                map.next_value_seed(ScenePrefabDeserializer {
+                    registry: self.registry,
+                })?
+            } else {
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?
+            };
+            entries.push(value);
         }
 
         Ok(entries)
@@ -855,6 +874,59 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_compact_binary() {
+        let mut world = create_world();
+
+        for i in 0..4 {
+            world.spawn((
+                Foo(i),
+                MyComponent {
+                    foo: [1, 2, 3],
+                    bar: (1.3, 3.7),
+                    baz: MyEnum::Struct { value: i as u32 },
+                },
+            ));
+        }
+        world.insert_resource(MyResource { foo: 123 });
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let registry = &registry.read();
+
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entities(world.iter_entities().map(|entity| entity.id()))
+            .extract_resources()
+            .build();
+
+        let serialized_scene = scene.serialize_binary(registry).unwrap();
+        let bincode_scene = bincode::serialize(&SceneSerializer::new(&scene, registry)).unwrap();
+        assert!(serialized_scene.len() < bincode_scene.len() / 2);
+
+        let deserialized_scene =
+            DynamicScene::deserialize_binary(&serialized_scene, registry).unwrap();
+
+        assert_eq!(1, deserialized_scene.resources.len());
+        assert!(scene.resources[0]
+            .reflect_partial_eq(deserialized_scene.resources[0].as_ref())
+            .unwrap());
+        assert_eq!(4, deserialized_scene.entities.len());
+        assert_scene_eq(&scene, &deserialized_scene);
+    }
+
+    #[test]
+    fn should_reject_compact_binary_with_oversized_counts() {
+        let registry = AppTypeRegistry::default();
+        let registry = &registry.read();
+
+        // No resources, but an entity count larger than any scene could hold.
+        let mut writer = bevy_reflect::serde::ReflectBinaryWriter::new(registry);
+        writer.write_serde(&0usize).unwrap();
+        writer.write_serde(&usize::MAX).unwrap();
+        let bytes = writer.finish();
+
+        assert!(DynamicScene::deserialize_binary(&bytes, registry).is_err());
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(
@@ -0,0 +1,1187 @@
+//! A human-friendly scene format, intended for scenes that are written or edited by hand.
+//!
+//! Compared to the [standard scene format](super), this format:
+//! * nests children inside their parent entity instead of listing [`Parent`] and [`Children`]
+//!   as raw entity references,
+//! * refers to types by their [short type path] when it is unambiguous in the [`TypeRegistry`],
+//! * omits the fields of struct types that are equal to the type's [`Default`] value,
+//!   for types registered with [`ReflectDefault`],
+//! * allows entity ids to be left out, in which case unused ids are generated when deserializing.
+//!
+//! Scenes written with [`ReadableSceneSerializer`] deserialize into an equivalent [`DynamicScene`]
+//! with [`ReadableSceneDeserializer`]. Scenes whose [`Parent`] and [`Children`] components form
+//! a cycle can't be nested, and fail to serialize.
+//!
+//! ```ron
+//! (
+//!   resources: {},
+//!   entities: [
+//!     (
+//!       id: 4294967296,
+//!       components: {
+//!         "Transform": (
+//!           translation: (x: 0.0, y: 1.0, z: 0.0),
+//!         ),
+//!       },
+//!       children: [
+//!         (
+//!           components: {
+//!             "my_game::weapons::Sword": (damage: 10),
+//!           },
+//!         ),
+//!       ],
+//!     ),
+//!   ],
+//! )
+//! ```
+//!
+//! [short type path]: bevy_reflect::TypePath::short_type_path
+
+use crate::prefab::{ScenePrefabDeserializer, ScenePrefabSerializer};
+use crate::serde::{SceneField, SCENE_ENTITIES, SCENE_RESOURCES, SCENE_STRUCT};
+use crate::{DynamicEntity, DynamicScene};
+use bevy_ecs::entity::{Entity, EntityHashMap, EntityHashSet};
+use bevy_hierarchy::{Children, Parent};
+use bevy_reflect::serde::{SerializationData, TypedReflectDeserializer, TypedReflectSerializer};
+use bevy_reflect::std_traits::ReflectDefault;
+use bevy_reflect::{
+    DynamicList, DynamicTupleStruct, FromReflect, Reflect, ReflectDeserialize, ReflectMut,
+    ReflectRef, ReflectSerialize, StructInfo, TypeInfo, TypeRegistration, TypeRegistry,
+};
+use bevy_utils::HashSet;
+use serde::de::{DeserializeSeed, Error, MapAccess, SeqAccess, Visitor};
+use serde::ser::{SerializeMap, SerializeSeq, SerializeStruct};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::any::TypeId;
+use std::fmt::Formatter;
+
+/// Name of the serialized id field in an entity struct.
+pub const ENTITY_FIELD_ID: &str = "id";
+/// Name of the serialized children field in an entity struct.
+pub const ENTITY_FIELD_CHILDREN: &str = "children";
+
+const ENTITY_STRUCT: &str = crate::serde::ENTITY_STRUCT;
+const ENTITY_FIELD_COMPONENTS: &str = crate::serde::ENTITY_FIELD_COMPONENTS;
+
+/// Serializer for a [`DynamicScene`] in the [readable scene format](self).
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_scene::{DynamicScene, serde::readable::ReadableSceneSerializer};
+/// # let mut world = World::default();
+/// # world.insert_resource(AppTypeRegistry::default());
+/// let registry = world.resource::<AppTypeRegistry>();
+/// let registry = registry.read();
+///
+/// let scene = DynamicScene::from_world(&world);
+///
+/// let scene_serializer = ReadableSceneSerializer::new(&scene, &registry);
+/// let ron_string = bevy_scene::serialize_ron(scene_serializer).unwrap();
+/// ```
+pub struct ReadableSceneSerializer<'a> {
+    /// The scene to serialize.
+    pub scene: &'a DynamicScene,
+    /// The type registry containing the types present in the scene.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ReadableSceneSerializer<'a> {
+    /// Create a new serializer from a [`DynamicScene`] and an associated [`TypeRegistry`].
+    pub fn new(scene: &'a DynamicScene, registry: &'a TypeRegistry) -> Self {
+        ReadableSceneSerializer { scene, registry }
+    }
+}
+
+impl<'a> Serialize for ReadableSceneSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let hierarchy = SceneHierarchy::new(&self.scene.entities);
+        let roots = self
+            .scene
+            .entities
+            .iter()
+            .filter(|entity| !hierarchy.nested.contains(&entity.entity))
+            .collect::<Vec<_>>();
+        let unreachable = hierarchy.unreachable(&roots);
+        if !unreachable.is_empty() {
+            return Err(serde::ser::Error::custom(format_args!(
+                "the `Parent` and `Children` components of entities {unreachable:?} form a cycle"
+            )));
+        }
+
+        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        state.serialize_field(
+            SCENE_RESOURCES,
+            &ReadableMapSerializer {
+                entries: &self.scene.resources,
+                skip_parent: false,
+                skip_children: false,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(
+            SCENE_ENTITIES,
+            &ReadableEntitiesSerializer {
+                entities: &roots,
+                hierarchy: &hierarchy,
+                registry: self.registry,
+            },
+        )?;
+        state.end()
+    }
+}
+
+/// The parent-child relationships of a scene that can be represented by nesting.
+///
+/// The children of an entity are nested inside it if every entity in its [`Children`] is part of
+/// the scene and has the entity as its [`Parent`]. Otherwise, both components are kept as is.
+struct SceneHierarchy<'a> {
+    entities: EntityHashMap<&'a DynamicEntity>,
+    children: EntityHashMap<Vec<Entity>>,
+    nested: EntityHashSet,
+}
+
+impl<'a> SceneHierarchy<'a> {
+    fn new(entities: &'a [DynamicEntity]) -> Self {
+        let by_id = entities
+            .iter()
+            .map(|entity| (entity.entity, entity))
+            .collect::<EntityHashMap<_>>();
+
+        let mut children = EntityHashMap::default();
+        let mut nested = EntityHashSet::default();
+        for entity in entities {
+            let Some(entity_children) = find_children(&entity.components) else {
+                continue;
+            };
+            let nestable = !entity_children.is_empty()
+                && entity_children.iter().all(|child| {
+                    by_id
+                        .get(child)
+                        .and_then(|child| find_parent(&child.components))
+                        == Some(entity.entity)
+                });
+            if nestable {
+                nested.extend(entity_children.iter().copied());
+                children.insert(entity.entity, entity_children);
+            }
+        }
+
+        Self {
+            entities: by_id,
+            children,
+            nested,
+        }
+    }
+
+    /// Returns the entities that aren't nested inside any of `roots`, sorted.
+    ///
+    /// This only happens when the [`Parent`] and [`Children`] components of some entities form a cycle.
+    fn unreachable(&self, roots: &[&DynamicEntity]) -> Vec<Entity> {
+        let mut reached = EntityHashSet::default();
+        let mut stack = roots.iter().map(|root| root.entity).collect::<Vec<_>>();
+        while let Some(entity) = stack.pop() {
+            if reached.insert(entity) {
+                stack.extend(self.children.get(&entity).into_iter().flatten().copied());
+            }
+        }
+
+        let mut unreachable = self
+            .entities
+            .keys()
+            .filter(|entity| !reached.contains(*entity))
+            .copied()
+            .collect::<Vec<_>>();
+        unreachable.sort();
+        unreachable
+    }
+}
+
+fn is_component<T: Reflect>(component: &dyn Reflect) -> bool {
+    component
+        .get_represented_type_info()
+        .is_some_and(|info| info.type_id() == TypeId::of::<T>())
+}
+
+fn find_parent(components: &[Box<dyn Reflect>]) -> Option<Entity> {
+    components
+        .iter()
+        .find(|component| is_component::<Parent>(&***component))
+        .and_then(|component| Parent::from_reflect(&**component))
+        .map(|parent| parent.get())
+}
+
+fn find_children(components: &[Box<dyn Reflect>]) -> Option<Vec<Entity>> {
+    components
+        .iter()
+        .find(|component| is_component::<Children>(&***component))
+        .and_then(|component| Children::from_reflect(&**component))
+        .map(|children| children.to_vec())
+}
+
+struct ReadableEntitiesSerializer<'a> {
+    entities: &'a [&'a DynamicEntity],
+    hierarchy: &'a SceneHierarchy<'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ReadableEntitiesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_seq(Some(self.entities.len()))?;
+        for entity in self.entities {
+            state.serialize_element(&ReadableEntitySerializer {
+                entity,
+                hierarchy: self.hierarchy,
+                registry: self.registry,
+            })?;
+        }
+        state.end()
+    }
+}
+
+struct ReadableEntitySerializer<'a> {
+    entity: &'a DynamicEntity,
+    hierarchy: &'a SceneHierarchy<'a>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ReadableEntitySerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let children = self
+            .hierarchy
+            .children
+            .get(&self.entity.entity)
+            .map(|children| {
+                children
+                    .iter()
+                    .map(|child| self.hierarchy.entities[child])
+                    .collect::<Vec<_>>()
+            });
+
+        let mut state = serializer.serialize_struct(ENTITY_STRUCT, 3)?;
+        state.serialize_field(ENTITY_FIELD_ID, &self.entity.entity)?;
+        state.serialize_field(
+            ENTITY_FIELD_COMPONENTS,
+            &ReadableMapSerializer {
+                entries: &self.entity.components,
+                skip_parent: self.hierarchy.nested.contains(&self.entity.entity),
+                skip_children: children.is_some(),
+                registry: self.registry,
+            },
+        )?;
+        match children {
+            Some(children) => state.serialize_field(
+                ENTITY_FIELD_CHILDREN,
+                &ReadableEntitiesSerializer {
+                    entities: &children,
+                    hierarchy: self.hierarchy,
+                    registry: self.registry,
+                },
+            )?,
+            None => state.skip_field(ENTITY_FIELD_CHILDREN)?,
+        }
+        state.end()
+    }
+}
+
+/// Serializes a list of values with unique types as a map of type name to value.
+struct ReadableMapSerializer<'a> {
+    entries: &'a [Box<dyn Reflect>],
+    skip_parent: bool,
+    skip_children: bool,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for ReadableMapSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let entries = self
+            .entries
+            .iter()
+            .filter(|entry| !(self.skip_parent && is_component::<Parent>(&***entry)))
+            .filter(|entry| !(self.skip_children && is_component::<Children>(&***entry)))
+            .collect::<Vec<_>>();
+
+        let mut state = serializer.serialize_map(Some(entries.len()))?;
+        for entry in entries {
+            let type_info = entry.get_represented_type_info().ok_or_else(|| {
+                serde::ser::Error::custom(format_args!(
+                    "cannot get type info for `{}`",
+                    entry.reflect_type_path()
+                ))
+            })?;
+            let registration = self.registry.get(type_info.type_id()).ok_or_else(|| {
+                serde::ser::Error::custom(format_args!(
+                    "no registration found for `{}`",
+                    type_info.type_path()
+                ))
+            })?;
+
+            let short_type_path = type_info.type_path_table().short_path();
+            if self
+                .registry
+                .get_with_short_type_path(short_type_path)
+                .is_some()
+            {
+                state.serialize_key(short_type_path)?;
+            } else {
+                state.serialize_key(type_info.type_path())?;
+            }
+
+            if ScenePrefabSerializer::is_scene_prefab(&**entry) {
+                state.serialize_value(&ScenePrefabSerializer {
+                    value: &**entry,
+                    registry: self.registry,
+                })?;
+                continue;
+            }
+
+            match default_struct(registration) {
+                Some((struct_info, reflect_default)) => {
+                    state.serialize_value(&PartialStructSerializer {
+                        value: &**entry,
+                        struct_info,
+                        default: reflect_default.default(),
+                        registration,
+                        registry: self.registry,
+                    })?;
+                }
+                None => {
+                    state.serialize_value(&TypedReflectSerializer::new(&**entry, self.registry))?;
+                }
+            }
+        }
+        state.end()
+    }
+}
+
+/// Returns the struct info and [`ReflectDefault`] of a type whose default fields can be omitted.
+///
+/// Types that provide their own serde implementation are always serialized in full.
+fn default_struct(
+    registration: &TypeRegistration,
+) -> Option<(&'static StructInfo, &ReflectDefault)> {
+    if registration.data::<ReflectSerialize>().is_some()
+        || registration.data::<ReflectDeserialize>().is_some()
+    {
+        return None;
+    }
+    let TypeInfo::Struct(struct_info) = registration.type_info() else {
+        return None;
+    };
+    Some((struct_info, registration.data::<ReflectDefault>()?))
+}
+
+/// Serializes the fields of a struct that differ from its default value.
+struct PartialStructSerializer<'a> {
+    value: &'a dyn Reflect,
+    struct_info: &'static StructInfo,
+    default: Box<dyn Reflect>,
+    registration: &'a TypeRegistration,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for PartialStructSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let ReflectRef::Struct(value) = self.value.reflect_ref() else {
+            return Err(serde::ser::Error::custom(format_args!(
+                "expected a struct value for `{}`",
+                self.struct_info.type_path()
+            )));
+        };
+        let ReflectRef::Struct(default) = self.default.reflect_ref() else {
+            return Err(serde::ser::Error::custom(format_args!(
+                "expected a struct default value for `{}`",
+                self.struct_info.type_path()
+            )));
+        };
+        let serialization_data = self.registration.data::<SerializationData>();
+
+        let fields = self
+            .struct_info
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| {
+                !serialization_data.is_some_and(|data| data.is_field_skipped(*index))
+            })
+            .filter_map(|(_, field)| Some((field.name(), value.field(field.name())?)))
+            .filter(|(name, field)| {
+                !default
+                    .field(name)
+                    .and_then(|default| field.reflect_partial_eq(default))
+                    .unwrap_or(false)
+            })
+            .collect::<Vec<_>>();
+
+        let mut state = serializer.serialize_struct(
+            self.struct_info
+                .type_path_table()
+                .ident()
+                .unwrap_or_default(),
+            fields.len(),
+        )?;
+        for (name, field) in fields {
+            state.serialize_field(name, &TypedReflectSerializer::new(field, self.registry))?;
+        }
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum ReadableEntityField {
+    Id,
+    Components,
+    Children,
+}
+
+/// Handles deserialization of a scene in the [readable scene format](self).
+pub struct ReadableSceneDeserializer<'a> {
+    /// Type registry in which the components and resources types used in the scene to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReadableSceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            SCENE_STRUCT,
+            &[SCENE_RESOURCES, SCENE_ENTITIES],
+            ReadableSceneVisitor {
+                registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct ReadableSceneVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ReadableSceneVisitor<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("scene struct")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut resources = None;
+        let mut entities = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                SceneField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(ReadableMapDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+                SceneField::Entities => {
+                    if entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_ENTITIES));
+                    }
+                    entities = Some(map.next_value_seed(ReadableEntitiesDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+            }
+        }
+
+        let mut allocator = EntityAllocator::default();
+        for entity in entities.iter().flatten() {
+            allocator
+                .reserve(entity)
+                .map_err(|entity| Error::custom(format_args!("duplicate entity id: `{entity}`")))?;
+        }
+
+        let mut flattened = Vec::new();
+        for entity in entities.unwrap_or_default() {
+            allocator.flatten(entity, None, &mut flattened);
+        }
+
+        Ok(DynamicScene {
+            resources: resources.unwrap_or_default(),
+            entities: flattened,
+        })
+    }
+}
+
+/// An entity as written in the readable scene format, with its children nested inside it.
+struct ReadableEntity {
+    entity: Option<Entity>,
+    components: Vec<Box<dyn Reflect>>,
+    children: Vec<ReadableEntity>,
+}
+
+/// Assigns ids to entities that don't specify one, avoiding the ids used in the scene.
+#[derive(Default)]
+struct EntityAllocator {
+    used: EntityHashSet,
+    next_index: u32,
+}
+
+impl EntityAllocator {
+    /// Reserves the ids of the given entity and its descendants,
+    /// returning the first id that was already in use.
+    fn reserve(&mut self, entity: &ReadableEntity) -> Result<(), Entity> {
+        if let Some(id) = entity.entity {
+            if !self.used.insert(id) {
+                return Err(id);
+            }
+        }
+        entity
+            .children
+            .iter()
+            .try_for_each(|child| self.reserve(child))
+    }
+
+    fn allocate(&mut self) -> Entity {
+        loop {
+            let entity = Entity::from_raw(self.next_index);
+            self.next_index += 1;
+            if !self.used.contains(&entity) {
+                return entity;
+            }
+        }
+    }
+
+    /// Converts the given entity and its descendants into [`DynamicEntity`]s,
+    /// restoring their [`Parent`] and [`Children`] components.
+    fn flatten(
+        &mut self,
+        entity: ReadableEntity,
+        parent: Option<Entity>,
+        flattened: &mut Vec<DynamicEntity>,
+    ) -> Entity {
+        let id = entity.entity.unwrap_or_else(|| self.allocate());
+        let mut components = entity.components;
+        if let Some(parent) = parent {
+            let mut value = DynamicTupleStruct::default();
+            value.insert(parent);
+            components.push(Box::new(
+                Parent::from_reflect(&value).expect("`Parent` should be a tuple struct"),
+            ));
+        }
+
+        let index = flattened.len();
+        flattened.push(DynamicEntity {
+            entity: id,
+            components,
+        });
+
+        let children = entity
+            .children
+            .into_iter()
+            .map(|child| self.flatten(child, Some(id), flattened))
+            .collect::<Vec<_>>();
+        if !children.is_empty() {
+            let mut list = DynamicList::default();
+            for child in children {
+                list.push(child);
+            }
+            let mut value = DynamicTupleStruct::default();
+            value.insert(list);
+            flattened[index].components.push(Box::new(
+                Children::from_reflect(&value).expect("`Children` should be a tuple struct"),
+            ));
+        }
+
+        id
+    }
+}
+
+struct ReadableEntitiesDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReadableEntitiesDeserializer<'a> {
+    type Value = Vec<ReadableEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(ReadableEntitiesVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct ReadableEntitiesVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ReadableEntitiesVisitor<'a> {
+    type Value = Vec<ReadableEntity>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("list of entities")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut entities = Vec::new();
+        while let Some(entity) = seq.next_element_seed(ReadableEntityDeserializer {
+            registry: self.registry,
+        })? {
+            entities.push(entity);
+        }
+        Ok(entities)
+    }
+}
+
+struct ReadableEntityDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReadableEntityDeserializer<'a> {
+    type Value = ReadableEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            ENTITY_STRUCT,
+            &[
+                ENTITY_FIELD_ID,
+                ENTITY_FIELD_COMPONENTS,
+                ENTITY_FIELD_CHILDREN,
+            ],
+            ReadableEntityVisitor {
+                registry: self.registry,
+            },
+        )
+    }
+}
+
+struct ReadableEntityVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ReadableEntityVisitor<'a> {
+    type Value = ReadableEntity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("entity struct")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entity = None;
+        let mut components = None;
+        let mut children = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                ReadableEntityField::Id => {
+                    if entity.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_ID));
+                    }
+                    entity = Some(map.next_value::<Entity>()?);
+                }
+                ReadableEntityField::Components => {
+                    if components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
+                    }
+                    components = Some(map.next_value_seed(ReadableMapDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+                ReadableEntityField::Children => {
+                    if children.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_CHILDREN));
+                    }
+                    children = Some(map.next_value_seed(ReadableEntitiesDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+            }
+        }
+
+        Ok(ReadableEntity {
+            entity,
+            components: components.unwrap_or_default(),
+            children: children.unwrap_or_default(),
+        })
+    }
+}
+
+/// Deserializes a map of type name to value, accepting both full and short type paths.
+struct ReadableMapDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for ReadableMapDeserializer<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(ReadableMapVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct ReadableMapVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for ReadableMapVisitor<'a> {
+    type Value = Vec<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("map of reflect types")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added = HashSet::new();
+        let mut entries = Vec::new();
+        while let Some(type_name) = map.next_key::<String>()? {
+            let registration = self
+                .registry
+                .get_with_type_path(&type_name)
+                .or_else(|| self.registry.get_with_short_type_path(&type_name))
+                .ok_or_else(|| {
+                    if self.registry.is_ambiguous(&type_name) {
+                        Error::custom(format_args!(
+                            "ambiguous short type path `{type_name}`, use the full type path instead"
+                        ))
+                    } else {
+                        Error::custom(format_args!("no registration found for `{type_name}`"))
+                    }
+                })?;
+
+            if !added.insert(registration.type_id()) {
+                return Err(Error::custom(format_args!(
+                    "duplicate reflect type: `{}`",
+                    registration.type_info().type_path(),
+                )));
+            }
+
+            if ScenePrefabDeserializer::is_scene_prefab(registration) {
+                entries.push(map.next_value_seed(ScenePrefabDeserializer {
+                    registry: self.registry,
+                })?);
+                continue;
+            }
+
+            let value = match default_struct(registration) {
+                Some((struct_info, reflect_default)) => {
+                    map.next_value_seed(PartialStructDeserializer {
+                        struct_info,
+                        reflect_default,
+                        registry: self.registry,
+                    })?
+                }
+                None => {
+                    map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?
+                }
+            };
+            entries.push(value);
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Deserializes a struct whose missing fields are filled in from its default value.
+struct PartialStructDeserializer<'a> {
+    struct_info: &'static StructInfo,
+    reflect_default: &'a ReflectDefault,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for PartialStructDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            self.struct_info
+                .type_path_table()
+                .ident()
+                .unwrap_or_default(),
+            self.struct_info.field_names(),
+            self,
+        )
+    }
+}
+
+impl<'a, 'de> Visitor<'de> for PartialStructDeserializer<'a> {
+    type Value = Box<dyn Reflect>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        write!(
+            formatter,
+            "reflected struct `{}`",
+            self.struct_info.type_path()
+        )
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut value = self.reflect_default.default();
+        let ReflectMut::Struct(value_struct) = value.reflect_mut() else {
+            return Err(Error::custom(format_args!(
+                "expected a struct default value for `{}`",
+                self.struct_info.type_path()
+            )));
+        };
+
+        while let Some(Ident(name)) = map.next_key::<Ident>()? {
+            let field_info = self
+                .struct_info
+                .field(&name)
+                .ok_or_else(|| Error::unknown_field(&name, self.struct_info.field_names()))?;
+            let registration = self.registry.get(field_info.type_id()).ok_or_else(|| {
+                Error::custom(format_args!(
+                    "no registration found for `{}`",
+                    field_info.type_path()
+                ))
+            })?;
+            let field_value =
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+            value_struct
+                .field_mut(&name)
+                .ok_or_else(|| Error::unknown_field(&name, self.struct_info.field_names()))?
+                .try_apply(&*field_value)
+                .map_err(Error::custom)?;
+        }
+
+        Ok(value)
+    }
+}
+
+/// A struct field name, which some formats (such as RON) represent as an identifier.
+struct Ident(String);
+
+impl<'de> Deserialize<'de> for Ident {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct IdentVisitor;
+
+        impl<'de> Visitor<'de> for IdentVisitor {
+            type Value = Ident;
+
+            fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+                formatter.write_str("identifier")
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Ident(value.to_string()))
+            }
+
+            fn visit_string<E>(self, value: String) -> Result<Self::Value, E>
+            where
+                E: Error,
+            {
+                Ok(Ident(value))
+            }
+        }
+
+        deserializer.deserialize_identifier(IdentVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ron;
+    use crate::{serialize_ron, DynamicSceneBuilder};
+    use bevy_ecs::prelude::{Component, ReflectComponent, World};
+    use bevy_ecs::reflect::AppTypeRegistry;
+    use bevy_hierarchy::BuildChildren;
+    use bevy_reflect::TypePath;
+
+    #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    #[reflect(Component, Default, PartialEq)]
+    struct Health {
+        current: u32,
+        max: u32,
+        regeneration: f32,
+    }
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component, PartialEq)]
+    struct Tag(String);
+
+    mod other {
+        use bevy_ecs::prelude::{Component, ReflectComponent};
+        use bevy_reflect::Reflect;
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        pub struct Tag(pub u32);
+    }
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Health>();
+            registry.register::<Tag>();
+            registry.register::<Parent>();
+            registry.register::<Children>();
+            registry.register::<Entity>();
+            registry.register::<String>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    fn spawn_hierarchy(world: &mut World) -> (Entity, Entity, Entity) {
+        let grandchild = world
+            .spawn(Health {
+                current: 5,
+                ..Default::default()
+            })
+            .id();
+        let child = world
+            .spawn(Tag("child".to_string()))
+            .add_child(grandchild)
+            .id();
+        let root = world.spawn(Health::default()).add_child(child).id();
+        (root, child, grandchild)
+    }
+
+    fn deserialize(input: &str, registry: &TypeRegistry) -> Result<DynamicScene, ron::Error> {
+        let mut deserializer = ron::de::Deserializer::from_str(input).unwrap();
+        ReadableSceneDeserializer {
+            type_registry: registry,
+        }
+        .deserialize(&mut deserializer)
+    }
+
+    #[test]
+    fn should_serialize_nested_hierarchy() {
+        let mut world = create_world();
+        let (root, child, grandchild) = spawn_hierarchy(&mut world);
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let scene = DynamicScene::from_world(&world);
+        let output = serialize_ron(ReadableSceneSerializer::new(&scene, &registry)).unwrap();
+
+        let expected = format!(
+            r#"(
+  resources: {{}},
+  entities: [
+    (
+      id: {root},
+      components: {{
+        "Health": (),
+      }},
+      children: [
+        (
+          id: {child},
+          components: {{
+            "Tag": ("child"),
+          }},
+          children: [
+            (
+              id: {grandchild},
+              components: {{
+                "Health": (
+                  current: 5,
+                ),
+              }},
+            ),
+          ],
+        ),
+      ],
+    ),
+  ],
+)"#,
+            root = root.to_bits(),
+            child = child.to_bits(),
+            grandchild = grandchild.to_bits(),
+        );
+        assert_eq!(expected, output);
+    }
+
+    #[test]
+    fn should_error_on_hierarchy_cycle() {
+        let mut world = create_world();
+        let (root, ..) = spawn_hierarchy(&mut world);
+        let first = world.spawn(Tag("first".to_string())).id();
+        let second = world.spawn(Tag("second".to_string())).add_child(first).id();
+        world.entity_mut(first).add_child(second);
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let scene = DynamicScene::from_world(&world);
+        let error = serialize_ron(ReadableSceneSerializer::new(&scene, &registry)).unwrap_err();
+
+        let message = error.to_string();
+        assert!(
+            message.contains(&format!("{:?}", [first, second])),
+            "{message}"
+        );
+        assert!(!message.contains(&format!("{root:?}")), "{message}");
+    }
+
+    #[test]
+    fn should_roundtrip_with_dynamic_scene() {
+        let mut world = create_world();
+        let (root, child, grandchild) = spawn_hierarchy(&mut world);
+        let orphan = world.spawn(Tag("orphan".to_string())).id();
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let scene = DynamicSceneBuilder::from_world(&world)
+            .extract_entities([grandchild, orphan, root, child].into_iter())
+            .build();
+        let output = serialize_ron(ReadableSceneSerializer::new(&scene, &registry)).unwrap();
+        let deserialized = deserialize(&output, &registry).unwrap();
+
+        assert_eq!(scene.entities.len(), deserialized.entities.len());
+        for expected in &scene.entities {
+            let actual = deserialized
+                .entities
+                .iter()
+                .find(|entity| entity.entity == expected.entity)
+                .unwrap();
+            assert_eq!(expected.components.len(), actual.components.len());
+            for component in &expected.components {
+                let type_id = component.get_represented_type_info().unwrap().type_id();
+                let other = actual
+                    .components
+                    .iter()
+                    .find(|other| other.get_represented_type_info().unwrap().type_id() == type_id)
+                    .unwrap();
+                assert!(component.reflect_partial_eq(&**other).unwrap_or(true));
+            }
+            assert_eq!(
+                find_parent(&expected.components),
+                find_parent(&actual.components)
+            );
+            assert_eq!(
+                find_children(&expected.components),
+                find_children(&actual.components)
+            );
+        }
+    }
+
+    #[test]
+    fn should_deserialize_hand_written_scene() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let input = format!(
+            r#"(
+  entities: [
+    (
+      components: {{
+        "Health": (max: 10),
+      }},
+      children: [
+        (components: {{ "Tag": ("first") }}),
+        (id: {}, components: {{ "Tag": ("second") }}),
+      ],
+    ),
+  ],
+)"#,
+            Entity::from_raw(0).to_bits()
+        );
+        let scene = deserialize(&input, &registry).unwrap();
+        assert!(scene.resources.is_empty());
+
+        let [root, first, second] = &scene.entities[..] else {
+            panic!("expected 3 entities, got {}", scene.entities.len());
+        };
+        assert_eq!(second.entity, Entity::from_raw(0));
+        assert_ne!(root.entity, second.entity);
+        assert_ne!(first.entity, second.entity);
+
+        assert_eq!(
+            Health::from_reflect(&*root.components[0]),
+            Some(Health {
+                max: 10,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            find_children(&root.components),
+            Some(vec![first.entity, second.entity])
+        );
+        assert_eq!(find_parent(&first.components), Some(root.entity));
+        assert_eq!(find_parent(&second.components), Some(root.entity));
+    }
+
+    #[test]
+    fn should_use_full_type_path_when_ambiguous() {
+        let mut world = create_world();
+        world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .register::<other::Tag>();
+        let entity = world.spawn(other::Tag(3)).id();
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let scene = DynamicScene::from_world(&world);
+        let output = serialize_ron(ReadableSceneSerializer::new(&scene, &registry)).unwrap();
+        assert!(output.contains(&format!(r#""{}": (3)"#, other::Tag::type_path())));
+
+        let input = format!(
+            r#"(entities: [(id: {}, components: {{ "Tag": ("first") }})])"#,
+            entity.to_bits()
+        );
+        let error = deserialize(&input, &registry).err().unwrap();
+        assert!(error
+            .to_string()
+            .contains("ambiguous short type path `Tag`"));
+    }
+
+    #[test]
+    fn should_error_on_duplicate_entity_id() {
+        let world = create_world();
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        let input = format!(
+            "(entities: [(id: {id}, children: [(id: {id})])])",
+            id = Entity::from_raw(1).to_bits()
+        );
+        let error = deserialize(&input, &registry).err().unwrap();
+        assert!(error.to_string().contains("duplicate entity id"));
+    }
+}
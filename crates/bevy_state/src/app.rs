@@ -2,9 +2,13 @@ use bevy_app::{App, MainScheduleOrder, Plugin, PreStartup, PreUpdate, SubApp};
 use bevy_ecs::{event::Events, schedule::IntoSystemConfigs, world::FromWorld};
 use bevy_utils::{tracing::warn, warn_once};
 
+use crate::entity_state::{
+    apply_entity_state_transitions, EntityStateTransition, EntityStateTransitionEvent,
+};
 use crate::state::{
-    setup_state_transitions_in_world, ComputedStates, FreelyMutableState, NextState, State,
-    StateTransition, StateTransitionEvent, StateTransitionSteps, States, SubStates,
+    register_state_stack, setup_state_transitions_in_world, ComputedStates, FreelyMutableState,
+    NextState, State, StateStack, StateTransition, StateTransitionEvent, StateTransitionSteps,
+    States, SubStates,
 };
 use crate::state_scoped::clear_state_scoped_entities;
 
@@ -53,6 +57,22 @@ pub trait AppExtStates {
     ///
     /// For more information refer to [`StateScoped`](crate::state_scoped::StateScoped).
     fn enable_state_scoped_entities<S: States>(&mut self) -> &mut Self;
+
+    /// Enable the [`StateStack`] for state `S`, allowing states to be pushed and popped.
+    ///
+    /// This adds the [`StateStack<S>`] resource, and enables use of the [`OnPause`](crate::state::OnPause)
+    /// and [`OnResume`](crate::state::OnResume) schedules.
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic type.
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self;
+
+    /// Sets up per-entity state machines of type `S`, stored in [`EntityState<S>`](crate::entity_state::EntityState) components.
+    ///
+    /// Adds the [`EntityStateTransitionEvent<S>`] event, and applies
+    /// [`NextEntityState<S>`](crate::entity_state::NextEntityState) transitions in the [`EntityStateTransition`] schedule.
+    ///
+    /// This method is idempotent: it has no effect when called again using the same generic type.
+    fn add_entity_state<S: States>(&mut self) -> &mut Self;
 }
 
 /// Separate function to only warn once for all state installation methods.
@@ -181,6 +201,36 @@ impl AppExtStates for SubApp {
             clear_state_scoped_entities::<S>.in_set(StateTransitionSteps::ExitSchedules),
         )
     }
+
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self {
+        if !self.world().contains_resource::<NextState<S>>() {
+            let name = std::any::type_name::<S>();
+            warn!(
+                "State stack is enabled for state `{}`, but the state isn't installed in the app!",
+                name
+            );
+        }
+        if !self.world().contains_resource::<StateStack<S>>() {
+            self.init_resource::<StateStack<S>>();
+            let schedule = self.get_schedule_mut(StateTransition).expect(
+                "The `StateTransition` schedule is missing. Did you forget to add StatesPlugin or DefaultPlugins before calling enable_state_stack?"
+            );
+            register_state_stack::<S>(schedule);
+        }
+        self
+    }
+
+    fn add_entity_state<S: States>(&mut self) -> &mut Self {
+        warn_if_no_states_plugin_installed(self);
+        if !self
+            .world()
+            .contains_resource::<Events<EntityStateTransitionEvent<S>>>()
+        {
+            self.add_event::<EntityStateTransitionEvent<S>>()
+                .add_systems(EntityStateTransition, apply_entity_state_transitions::<S>);
+        }
+        self
+    }
 }
 
 impl AppExtStates for App {
@@ -208,9 +258,19 @@ impl AppExtStates for App {
         self.main_mut().enable_state_scoped_entities::<S>();
         self
     }
+
+    fn enable_state_stack<S: FreelyMutableState>(&mut self) -> &mut Self {
+        self.main_mut().enable_state_stack::<S>();
+        self
+    }
+
+    fn add_entity_state<S: States>(&mut self) -> &mut Self {
+        self.main_mut().add_entity_state::<S>();
+        self
+    }
 }
 
-/// Registers the [`StateTransition`] schedule in the [`MainScheduleOrder`] to enable state processing.
+/// Registers the [`StateTransition`] and [`EntityStateTransition`] schedules in the [`MainScheduleOrder`] to enable state processing.
 #[derive(Default)]
 pub struct StatesPlugin;
 
@@ -219,6 +279,8 @@ impl Plugin for StatesPlugin {
         let mut schedule = app.world_mut().resource_mut::<MainScheduleOrder>();
         schedule.insert_after(PreUpdate, StateTransition);
         schedule.insert_startup_before(PreStartup, StateTransition);
+        schedule.insert_after(StateTransition, EntityStateTransition);
+        schedule.insert_startup_after(StateTransition, EntityStateTransition);
         setup_state_transitions_in_world(app.world_mut());
     }
 }
@@ -1,5 +1,10 @@
+use crate::entity_state::EntityState;
 use crate::state::{State, States};
-use bevy_ecs::{change_detection::DetectChanges, system::Res};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    query::Changed,
+    system::{Query, Res},
+};
 
 /// A [`Condition`](bevy_ecs::prelude::Condition)-satisfying system that returns `true`
 /// if the state machine exists.
@@ -160,11 +165,74 @@ pub fn state_changed<S: States>(current_state: Option<Res<State<S>>>) -> bool {
     current_state.is_changed()
 }
 
+/// A [`Condition`](bevy_ecs::prelude::Condition)-satisfying system that returns `true`
+/// if any entity's [`EntityState<S>`] is equal to `state`.
+///
+/// To only iterate over the matching entities, filter a query with
+/// [`InEntityState`](crate::entity_state::InEntityState) instead.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_state::prelude::*;
+/// # #[derive(Resource, Default)]
+/// # struct Counter(u8);
+/// # let mut app = Schedule::default();
+/// # let mut world = World::new();
+/// # world.init_resource::<Counter>();
+/// #[derive(States, Clone, Copy, Default, Eq, PartialEq, Hash, Debug)]
+/// enum Behavior {
+///     #[default]
+///     Idle,
+///     Chase,
+/// }
+///
+/// app.add_systems(
+///     // `any_entity_in_state` will only return true if
+///     // some entity is in the given state
+///     chase_system.run_if(any_entity_in_state(Behavior::Chase)),
+/// );
+///
+/// fn chase_system(mut counter: ResMut<Counter>) {
+///     counter.0 += 1;
+/// }
+///
+/// world.spawn(EntityState::new(Behavior::Idle));
+///
+/// // No entity is chasing, so `chase_system` won't run
+/// app.run(&mut world);
+/// assert_eq!(world.resource::<Counter>().0, 0);
+///
+/// world.spawn(EntityState::new(Behavior::Chase));
+///
+/// // Now that an entity is chasing, `chase_system` will run
+/// app.run(&mut world);
+/// assert_eq!(world.resource::<Counter>().0, 1);
+/// ```
+pub fn any_entity_in_state<S: States>(
+    state: S,
+) -> impl FnMut(Query<&EntityState<S>>) -> bool + Clone {
+    move |query: Query<&EntityState<S>>| query.iter().any(|current| current.is(&state))
+}
+
+/// A [`Condition`](bevy_ecs::prelude::Condition)-satisfying system that returns `true`
+/// if any entity's [`EntityState<S>`] changed or was added since the condition last ran.
+///
+/// To find the entities that changed, use [`Changed<EntityState<S>>`](Changed) as a query filter.
+pub fn entity_state_changed<S: States>(query: Query<(), Changed<EntityState<S>>>) -> bool {
+    !query.is_empty()
+}
+
 #[cfg(test)]
 mod tests {
     use crate as bevy_state;
 
-    use bevy_ecs::schedule::{Condition, IntoSystemConfigs, Schedule};
+    use bevy_ecs::{
+        schedule::{Condition, IntoSystemConfigs, Schedule},
+        system::{ResMut, Resource},
+        world::World,
+    };
 
     use crate::prelude::*;
     use bevy_state_macros::States;
@@ -188,4 +256,43 @@ mod tests {
                 .distributive_run_if(state_changed::<TestState>),
         );
     }
+
+    #[test]
+    fn entity_state_conditions() {
+        #[derive(Resource, Default)]
+        struct Counter(u8);
+
+        fn count(mut counter: ResMut<Counter>) {
+            counter.0 += 1;
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Counter>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            count
+                .run_if(any_entity_in_state(TestState::B))
+                .run_if(entity_state_changed::<TestState>),
+        );
+
+        // No entity is in `B`.
+        let entity = world.spawn(EntityState::new(TestState::A)).id();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 0);
+
+        // An entity entered `B`.
+        world.spawn(EntityState::new(TestState::B));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        // No entity state changed since the last run.
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 1);
+
+        world
+            .entity_mut(entity)
+            .insert(EntityState::new(TestState::A));
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<Counter>().0, 2);
+    }
 }
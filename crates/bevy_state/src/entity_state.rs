@@ -0,0 +1,532 @@
+use std::{marker::PhantomData, mem};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_ecs::{
+    archetype::Archetype,
+    change_detection::DetectChanges,
+    component::{Component, ComponentId, Components, Tick},
+    entity::Entity,
+    event::{Event, EventWriter},
+    query::{Changed, FilteredAccess, Or, QueryFilter, WorldQuery},
+    schedule::ScheduleLabel,
+    storage::{Table, TableRow},
+    system::{Commands, Query},
+    world::{unsafe_world_cell::UnsafeWorldCell, Ref, World},
+};
+
+use crate::state::States;
+
+/// A finite-state machine stored on a single entity.
+///
+/// This is the per-entity counterpart of [`State<S>`](crate::state::State): each entity with this
+/// component has its own current value of `S`, which is useful for things like AI agents or doors.
+/// To *change* the state, insert or mutate a [`NextEntityState<S>`] on the same entity, and the
+/// transition will be applied during the [`EntityStateTransition`] schedule.
+///
+/// When the state changes, an [`OnExitEntityState<S>`] and then an [`OnEnterEntityState<S>`] are
+/// [triggered](Commands::trigger_targets) for the entity, and an [`EntityStateTransitionEvent<S>`] is sent.
+/// Entering the initial state triggers [`OnEnterEntityState<S>`] as well.
+/// Removing the component or despawning the entity doesn't trigger [`OnExitEntityState<S>`].
+///
+/// The value is only mutated by transitions, so [`Changed<EntityState<S>>`](Changed)
+/// can be used as a query filter for entities that just changed state,
+/// while [`InEntityState`] filters entities in a given state.
+///
+/// To enable entity states, remember to configure your application with
+/// [`add_entity_state`](crate::app::AppExtStates::add_entity_state).
+///
+/// ```
+/// use bevy_state::prelude::*;
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum Behavior {
+///     #[default]
+///     Idle,
+///     Chase,
+/// }
+///
+/// #[derive(Component)]
+/// struct Enemy;
+///
+/// fn spawn_enemy(mut commands: Commands) {
+///     commands
+///         .spawn((Enemy, EntityState::new(Behavior::Idle)))
+///         .observe(|trigger: Trigger<OnEnterEntityState<Behavior>>| {
+///             println!("{:?} is now {:?}", trigger.entity(), trigger.event().0);
+///         });
+/// }
+///
+/// fn start_chasing(mut commands: Commands, enemies: Query<(Entity, &EntityState<Behavior>), With<Enemy>>) {
+///     for (entity, state) in &enemies {
+///         if state.is(&Behavior::Idle) {
+///             commands.entity(entity).insert(NextEntityState::Pending(Behavior::Chase));
+///         }
+///     }
+/// }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub struct EntityState<S: States>(S);
+
+impl<S: States> EntityState<S> {
+    /// Creates a state machine starting in `state`.
+    ///
+    /// To change the state of an existing entity, use [`NextEntityState<S>`] instead.
+    pub fn new(state: S) -> Self {
+        Self(state)
+    }
+
+    /// Get the current state.
+    pub fn get(&self) -> &S {
+        &self.0
+    }
+
+    /// Returns `true` if the current state is `state`.
+    pub fn is(&self, state: &S) -> bool {
+        self.0 == *state
+    }
+}
+
+/// The next state of an entity's [`EntityState<S>`].
+///
+/// To queue a transition, insert this component on the entity, call [`NextEntityState::set`],
+/// or mutate the value to [`NextEntityState::Pending`] directly.
+#[derive(Component, Debug, Default, Clone)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(bevy_reflect::Reflect),
+    reflect(Component)
+)]
+pub enum NextEntityState<S: States> {
+    /// No state transition is pending
+    #[default]
+    Unchanged,
+    /// There is a pending transition for state `S`
+    Pending(S),
+}
+
+impl<S: States> NextEntityState<S> {
+    /// Tentatively set a pending state transition to `Some(state)`.
+    pub fn set(&mut self, state: S) {
+        *self = Self::Pending(state);
+    }
+
+    /// Remove any pending changes to [`EntityState<S>`]
+    pub fn reset(&mut self) {
+        *self = Self::Unchanged;
+    }
+}
+
+/// Runs [entity state transitions](EntityState).
+///
+/// By default, it will be triggered right after the [`StateTransition`](crate::state::StateTransition) schedule,
+/// but you can manually run it at arbitrary times like any other schedule.
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EntityStateTransition;
+
+/// Event sent when any [`EntityState<S>`] transition happens.
+/// This includes identity transitions, where `exited` and `entered` have the same value,
+/// and entering the initial state, where `exited` is `None`.
+///
+/// To respond to transitions of a specific entity, consider observing [`OnEnterEntityState`] or [`OnExitEntityState`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Event)]
+pub struct EntityStateTransitionEvent<S: States> {
+    /// The entity whose state changed.
+    pub entity: Entity,
+    /// The state being exited.
+    pub exited: Option<S>,
+    /// The state being entered.
+    pub entered: Option<S>,
+}
+
+/// Triggered for an entity when its [`EntityState<S>`] enters the contained state.
+///
+/// This ignores identity transitions.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct OnEnterEntityState<S: States>(pub S);
+
+/// Triggered for an entity when its [`EntityState<S>`] exits the contained state.
+///
+/// This is triggered before the [`OnEnterEntityState<S>`] of the new state and ignores identity transitions.
+#[derive(Debug, Clone, PartialEq, Eq, Event)]
+pub struct OnExitEntityState<S: States>(pub S);
+
+/// A [`States`] value known at compile time, used to filter queries with [`InEntityState`].
+pub trait StateValue: Send + Sync + 'static {
+    /// The state machine this value belongs to.
+    type State: States;
+
+    /// Returns the value.
+    fn value() -> Self::State;
+}
+
+/// Filter that selects entities whose [`EntityState<V::State>`](EntityState) is equal to [`V::value`](StateValue::value).
+///
+/// Since the state is compared on every entity, this is not an archetypal filter,
+/// so queries using it can't know how many entities they match ahead of time.
+///
+/// ```
+/// use bevy_state::prelude::*;
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum Behavior {
+///     #[default]
+///     Idle,
+///     Chase,
+/// }
+///
+/// struct Chasing;
+///
+/// impl StateValue for Chasing {
+///     type State = Behavior;
+///
+///     fn value() -> Behavior {
+///         Behavior::Chase
+///     }
+/// }
+///
+/// let mut world = World::new();
+/// world.spawn(EntityState::new(Behavior::Idle));
+/// let chaser = world.spawn(EntityState::new(Behavior::Chase)).id();
+///
+/// let mut query = world.query_filtered::<Entity, InEntityState<Chasing>>();
+/// assert_eq!(query.iter(&world).collect::<Vec<_>>(), [chaser]);
+/// ```
+pub struct InEntityState<V: StateValue>(PhantomData<V>);
+
+#[allow(unsafe_code)]
+// SAFETY: `fetch` only reads `EntityState<V::State>`, whose access is added by delegating
+// `update_component_access` and `matches_component_set` to `&EntityState<V::State>`.
+unsafe impl<V: StateValue> WorldQuery for InEntityState<V> {
+    type Item<'w> = bool;
+    type Fetch<'w> = (
+        <&'static EntityState<V::State> as WorldQuery>::Fetch<'w>,
+        V::State,
+    );
+    type State = (ComponentId, V::State);
+
+    fn shrink<'wlong: 'wshort, 'wshort>(item: Self::Item<'wlong>) -> Self::Item<'wshort> {
+        item
+    }
+
+    #[inline]
+    unsafe fn init_fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        state: &Self::State,
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Self::Fetch<'w> {
+        (
+            // SAFETY: The caller upholds the safety requirements of `init_fetch`.
+            unsafe {
+                <&EntityState<V::State> as WorldQuery>::init_fetch(
+                    world, &state.0, last_run, this_run,
+                )
+            },
+            state.1.clone(),
+        )
+    }
+
+    const IS_DENSE: bool = <&EntityState<V::State> as WorldQuery>::IS_DENSE;
+
+    #[inline]
+    unsafe fn set_archetype<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        state: &Self::State,
+        archetype: &'w Archetype,
+        table: &'w Table,
+    ) {
+        // SAFETY: The caller upholds the safety requirements of `set_archetype`.
+        unsafe {
+            <&EntityState<V::State> as WorldQuery>::set_archetype(
+                &mut fetch.0,
+                &state.0,
+                archetype,
+                table,
+            );
+        }
+    }
+
+    #[inline]
+    unsafe fn set_table<'w>(fetch: &mut Self::Fetch<'w>, state: &Self::State, table: &'w Table) {
+        // SAFETY: The caller upholds the safety requirements of `set_table`.
+        unsafe {
+            <&EntityState<V::State> as WorldQuery>::set_table(&mut fetch.0, &state.0, table);
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn fetch<'w>(
+        fetch: &mut Self::Fetch<'w>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> Self::Item<'w> {
+        // SAFETY: The caller upholds the safety requirements of `fetch`.
+        let state = unsafe {
+            <&EntityState<V::State> as WorldQuery>::fetch(&mut fetch.0, entity, table_row)
+        };
+        state.is(&fetch.1)
+    }
+
+    fn update_component_access(state: &Self::State, access: &mut FilteredAccess<ComponentId>) {
+        <&EntityState<V::State> as WorldQuery>::update_component_access(&state.0, access);
+    }
+
+    fn init_state(world: &mut World) -> Self::State {
+        (
+            <&EntityState<V::State> as WorldQuery>::init_state(world),
+            V::value(),
+        )
+    }
+
+    fn get_state(components: &Components) -> Option<Self::State> {
+        Some((
+            <&EntityState<V::State> as WorldQuery>::get_state(components)?,
+            V::value(),
+        ))
+    }
+
+    fn matches_component_set(
+        state: &Self::State,
+        set_contains_id: &impl Fn(ComponentId) -> bool,
+    ) -> bool {
+        <&EntityState<V::State> as WorldQuery>::matches_component_set(&state.0, set_contains_id)
+    }
+}
+
+impl<V: StateValue> QueryFilter for InEntityState<V> {
+    const IS_ARCHETYPAL: bool = false;
+
+    #[inline(always)]
+    #[allow(unsafe_code)]
+    unsafe fn filter_fetch(
+        fetch: &mut Self::Fetch<'_>,
+        entity: Entity,
+        table_row: TableRow,
+    ) -> bool {
+        // SAFETY: The caller upholds the safety requirements of `filter_fetch`.
+        unsafe { Self::fetch(fetch, entity, table_row) }
+    }
+}
+
+/// Applies the pending [`NextEntityState<S>`] of every entity, triggering
+/// [`OnExitEntityState<S>`] and [`OnEnterEntityState<S>`] and sending [`EntityStateTransitionEvent<S>`]s.
+///
+/// Also enters the initial state of newly added [`EntityState<S>`] components.
+pub fn apply_entity_state_transitions<S: States>(
+    mut commands: Commands,
+    mut query: Query<
+        (Entity, Ref<EntityState<S>>, Option<&mut NextEntityState<S>>),
+        Or<(Changed<EntityState<S>>, Changed<NextEntityState<S>>)>,
+    >,
+    mut events: EventWriter<EntityStateTransitionEvent<S>>,
+) {
+    for (entity, state, next) in &mut query {
+        if state.is_added() {
+            events.send(EntityStateTransitionEvent {
+                entity,
+                exited: None,
+                entered: Some(state.0.clone()),
+            });
+            commands.trigger_targets(OnEnterEntityState(state.0.clone()), entity);
+        }
+
+        let Some(mut next) = next else {
+            continue;
+        };
+        let NextEntityState::Pending(entered) = mem::take(next.as_mut()) else {
+            continue;
+        };
+        let exited = state.0.clone();
+        events.send(EntityStateTransitionEvent {
+            entity,
+            exited: Some(exited.clone()),
+            entered: Some(entered.clone()),
+        });
+        if exited == entered {
+            continue;
+        }
+        // Exit observers still see the old state, while enter observers already see the new one.
+        commands.trigger_targets(OnExitEntityState(exited), entity);
+        commands
+            .entity(entity)
+            .try_insert(EntityState(entered.clone()));
+        commands.trigger_targets(OnEnterEntityState(entered), entity);
+    }
+}
+
+#[cfg(all(test, feature = "bevy_app"))]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::{
+        event::Events,
+        observer::Trigger,
+        system::{Query, ResMut, Resource},
+    };
+    use bevy_state_macros::States;
+
+    use crate as bevy_state;
+    use crate::app::{AppExtStates, StatesPlugin};
+
+    use super::*;
+
+    #[derive(States, PartialEq, Eq, Debug, Default, Hash, Clone, Copy)]
+    enum Behavior {
+        #[default]
+        Idle,
+        Chase,
+    }
+
+    #[derive(Resource, Default)]
+    struct Log(Vec<(Entity, &'static str, Behavior, Behavior)>);
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .add_entity_state::<Behavior>()
+            .init_resource::<Log>();
+        app
+    }
+
+    fn spawn_logged(app: &mut App, state: Behavior) -> Entity {
+        let mut entity = app.world_mut().spawn(EntityState::new(state));
+        entity.observe(
+            |trigger: Trigger<OnEnterEntityState<Behavior>>,
+             query: Query<&EntityState<Behavior>>,
+             mut log: ResMut<Log>| {
+                let current = *query.get(trigger.entity()).unwrap().get();
+                log.0
+                    .push((trigger.entity(), "enter", trigger.event().0, current));
+            },
+        );
+        entity.observe(
+            |trigger: Trigger<OnExitEntityState<Behavior>>,
+             query: Query<&EntityState<Behavior>>,
+             mut log: ResMut<Log>| {
+                let current = *query.get(trigger.entity()).unwrap().get();
+                log.0
+                    .push((trigger.entity(), "exit", trigger.event().0, current));
+            },
+        );
+        entity.id()
+    }
+
+    fn transition(app: &mut App) -> Vec<(Entity, &'static str, Behavior, Behavior)> {
+        app.world_mut().run_schedule(EntityStateTransition);
+        std::mem::take(&mut app.world_mut().resource_mut::<Log>().0)
+    }
+
+    fn drain_events(app: &mut App) -> Vec<EntityStateTransitionEvent<Behavior>> {
+        app.world_mut()
+            .resource_mut::<Events<EntityStateTransitionEvent<Behavior>>>()
+            .drain()
+            .collect()
+    }
+
+    #[test]
+    fn transitions_trigger_observers_for_the_entity() {
+        let mut app = setup();
+        let agent = spawn_logged(&mut app, Behavior::Idle);
+        let other = spawn_logged(&mut app, Behavior::Idle);
+
+        assert_eq!(
+            transition(&mut app),
+            [
+                (agent, "enter", Behavior::Idle, Behavior::Idle),
+                (other, "enter", Behavior::Idle, Behavior::Idle),
+            ]
+        );
+        drain_events(&mut app);
+
+        app.world_mut()
+            .entity_mut(agent)
+            .insert(NextEntityState::Pending(Behavior::Chase));
+        assert_eq!(
+            transition(&mut app),
+            [
+                (agent, "exit", Behavior::Idle, Behavior::Idle),
+                (agent, "enter", Behavior::Chase, Behavior::Chase),
+            ]
+        );
+        assert_eq!(
+            drain_events(&mut app),
+            [EntityStateTransitionEvent {
+                entity: agent,
+                exited: Some(Behavior::Idle),
+                entered: Some(Behavior::Chase),
+            }]
+        );
+        assert!(app
+            .world()
+            .get::<EntityState<Behavior>>(other)
+            .unwrap()
+            .is(&Behavior::Idle));
+
+        // Nothing is pending anymore.
+        assert!(transition(&mut app).is_empty());
+        assert!(drain_events(&mut app).is_empty());
+    }
+
+    struct Chasing;
+
+    impl StateValue for Chasing {
+        type State = Behavior;
+
+        fn value() -> Behavior {
+            Behavior::Chase
+        }
+    }
+
+    #[test]
+    fn filters_entities_in_state() {
+        let mut app = setup();
+        let idle = app.world_mut().spawn(EntityState::new(Behavior::Idle)).id();
+        let chaser = app
+            .world_mut()
+            .spawn(EntityState::new(Behavior::Chase))
+            .id();
+        app.world_mut().spawn_empty();
+
+        let mut query = app
+            .world_mut()
+            .query_filtered::<Entity, InEntityState<Chasing>>();
+        assert_eq!(query.iter(app.world()).collect::<Vec<_>>(), [chaser]);
+
+        app.world_mut()
+            .entity_mut(idle)
+            .insert(NextEntityState::Pending(Behavior::Chase));
+        app.world_mut()
+            .entity_mut(chaser)
+            .insert(NextEntityState::Pending(Behavior::Idle));
+        transition(&mut app);
+        assert_eq!(query.iter(app.world()).collect::<Vec<_>>(), [idle]);
+    }
+
+    #[test]
+    fn identity_transitions_only_send_events() {
+        let mut app = setup();
+        let agent = spawn_logged(&mut app, Behavior::Idle);
+        transition(&mut app);
+        drain_events(&mut app);
+
+        app.world_mut()
+            .entity_mut(agent)
+            .insert(NextEntityState::Pending(Behavior::Idle));
+        assert!(transition(&mut app).is_empty());
+        assert_eq!(
+            drain_events(&mut app),
+            [EntityStateTransitionEvent {
+                entity: agent,
+                exited: Some(Behavior::Idle),
+                entered: Some(Behavior::Idle),
+            }]
+        );
+    }
+}
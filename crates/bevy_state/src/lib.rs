@@ -23,7 +23,12 @@
 //!
 //! - 3 Transition Schedules - [`OnEnter<S>`](crate::state::OnEnter), [`OnExit<S>`](crate::state::OnExit) and [`OnTransition<S>`](crate::state::OnTransition) - which are used
 //!   to trigger systems specifically during matching transitions.
+//! - A [`StateStack<S>`](crate::state::StateStack) that allows pushing a state over the current one and popping back to it,
+//!   along with the [`OnPause<S>`](crate::state::OnPause) and [`OnResume<S>`](crate::state::OnResume) schedules.
 //! - A [`StateTransitionEvent<S>`](crate::state::StateTransitionEvent) that gets fired when a given state changes.
+//! - An [`EntityState<S>`](crate::entity_state::EntityState) component for per-entity state machines, whose transitions
+//!   trigger [`OnEnterEntityState<S>`](crate::entity_state::OnEnterEntityState) and
+//!   [`OnExitEntityState<S>`](crate::entity_state::OnExitEntityState) observers on the entity.
 //! - The [`in_state<S>`](crate::condition::in_state) and [`state_changed<S>`](crate::condition::state_changed) run conditions - which are used
 //!   to determine whether a system should run based on the current state.
 
@@ -32,6 +37,8 @@
 pub mod app;
 /// Provides definitions for the runtime conditions that interact with the state system
 pub mod condition;
+/// Provides [`EntityState`](crate::entity_state::EntityState) and related types for per-entity state machines.
+pub mod entity_state;
 /// Provides definitions for the basic traits required by the state system
 pub mod state;
 
@@ -47,10 +54,15 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::condition::*;
     #[doc(hidden)]
+    pub use crate::entity_state::{
+        EntityState, EntityStateTransition, EntityStateTransitionEvent, InEntityState,
+        NextEntityState, OnEnterEntityState, OnExitEntityState, StateValue,
+    };
+    #[doc(hidden)]
     pub use crate::state::{
         last_transition, ComputedStates, EnterSchedules, ExitSchedules, NextState, OnEnter, OnExit,
-        OnTransition, State, StateSet, StateTransition, StateTransitionEvent, States, SubStates,
-        TransitionSchedules,
+        OnPause, OnResume, OnTransition, State, StateSet, StateStack, StateTransition,
+        StateTransitionEvent, States, SubStates, TransitionSchedules,
     };
     #[doc(hidden)]
     pub use crate::state_scoped::StateScoped;
@@ -1,6 +1,7 @@
 mod computed_states;
 mod freely_mutable_state;
 mod resources;
+mod stack;
 mod state_set;
 mod states;
 mod sub_states;
@@ -10,6 +11,7 @@ pub use bevy_state_macros::*;
 pub use computed_states::*;
 pub use freely_mutable_state::*;
 pub use resources::*;
+pub use stack::*;
 pub use state_set::*;
 pub use states::*;
 pub use sub_states::*;
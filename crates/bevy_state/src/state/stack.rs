@@ -0,0 +1,386 @@
+use bevy_ecs::{
+    schedule::{IntoSystemConfigs, Schedule},
+    system::{Res, ResMut, Resource},
+};
+use bevy_utils::tracing::warn;
+
+use super::{
+    freely_mutable_state::FreelyMutableState,
+    resources::{NextState, State},
+    states::States,
+    transitions::{ApplyStateTransition, StateTransitionSteps},
+};
+
+/// A pushdown stack of paused states for [`State<S>`], allowing a state to be temporarily
+/// replaced and later returned to.
+///
+/// The current state is still stored in [`State<S>`]: this resource only holds the states
+/// it was pushed over. Stack operations are queued like [`NextState<S>`] and applied during the
+/// [`StateTransition`](crate::state::StateTransition) schedule:
+///
+/// - [`push`](Self::push) pauses the current state and enters a new one, running
+///   [`OnPause`](crate::state::OnPause) for the paused state instead of [`OnExit`](crate::state::OnExit).
+/// - [`pop`](Self::pop) exits the current state and returns to the last paused state, running
+///   [`OnResume`](crate::state::OnResume) for it instead of [`OnEnter`](crate::state::OnEnter).
+/// - [`replace`](Self::replace) exits the current state and enters a new one, leaving the paused states as is.
+///   This is the same as setting [`NextState<S>`] directly.
+///
+/// [`StateScoped`](crate::state_scoped::StateScoped) entities of a state are only despawned
+/// when it is exited, not when it is paused.
+///
+/// Only the value of `S` is kept in the stack. The [`SubStates`](crate::state::SubStates) and
+/// [`ComputedStates`](crate::state::ComputedStates) depending on a paused state are exited as usual,
+/// and are entered again when it is resumed: computed states are computed from the resumed state again,
+/// but sub-states start over from their default value. To return to a sub-state, store it before pushing
+/// and set it again after popping.
+///
+/// To enable the stack for a state, use
+/// [`enable_state_stack`](crate::app::AppExtStates::enable_state_stack).
+///
+/// ```
+/// use bevy_state::prelude::*;
+/// use bevy_ecs::prelude::*;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default, States)]
+/// enum GameState {
+///     #[default]
+///     InGame,
+///     Paused,
+/// }
+///
+/// fn toggle_pause(state: Res<State<GameState>>, mut stack: ResMut<StateStack<GameState>>) {
+///     match state.get() {
+///         GameState::InGame => stack.push(GameState::Paused),
+///         GameState::Paused => stack.pop(),
+///     }
+/// }
+/// ```
+#[derive(Resource, Debug, Clone)]
+pub struct StateStack<S: States> {
+    paused: Vec<S>,
+    pending: Option<StackOperation<S>>,
+    applied: Option<StackOperation<S>>,
+}
+
+impl<S: States> Default for StateStack<S> {
+    fn default() -> Self {
+        Self {
+            paused: Vec::new(),
+            pending: None,
+            applied: None,
+        }
+    }
+}
+
+/// An operation on a [`StateStack`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StackOperation<S: States> {
+    /// Pause the current state and enter the given state.
+    Push(S),
+    /// Exit the current state and resume the last paused state.
+    Pop,
+    /// Exit the current state and enter the given state.
+    Replace(S),
+}
+
+impl<S: States> StateStack<S> {
+    /// Queues pausing the current state and entering `state`.
+    ///
+    /// This overrides any operation queued since the last state transition.
+    /// If `state` is already the current state, the operation is ignored.
+    pub fn push(&mut self, state: S) {
+        self.pending = Some(StackOperation::Push(state));
+    }
+
+    /// Queues exiting the current state and resuming the last paused state.
+    ///
+    /// This overrides any operation queued since the last state transition.
+    /// If no state is paused, the operation is ignored.
+    pub fn pop(&mut self) {
+        self.pending = Some(StackOperation::Pop);
+    }
+
+    /// Queues exiting the current state and entering `state`, without changing the paused states.
+    ///
+    /// This overrides any operation queued since the last state transition.
+    pub fn replace(&mut self, state: S) {
+        self.pending = Some(StackOperation::Replace(state));
+    }
+
+    /// Removes the queued operation, if any.
+    pub fn reset(&mut self) {
+        self.pending = None;
+    }
+
+    /// Returns the queued operation, if any.
+    pub fn pending(&self) -> Option<&StackOperation<S>> {
+        self.pending.as_ref()
+    }
+
+    /// Returns the operation applied by the current or last run of the
+    /// [`StateTransition`](crate::state::StateTransition) schedule, if any.
+    pub fn applied(&self) -> Option<&StackOperation<S>> {
+        self.applied.as_ref()
+    }
+
+    /// Returns the paused states, from the bottom of the stack to the top.
+    pub fn paused(&self) -> &[S] {
+        &self.paused
+    }
+
+    /// Returns `true` if `state` is paused somewhere in the stack.
+    pub fn is_paused(&self, state: &S) -> bool {
+        self.paused.contains(state)
+    }
+
+    /// Returns `true` if the last transition paused the exited state rather than exiting it.
+    pub(crate) fn paused_by_last_transition(&self) -> bool {
+        matches!(self.applied, Some(StackOperation::Push(_)))
+    }
+
+    /// Returns `true` if the last transition resumed the entered state rather than entering it.
+    pub(crate) fn resumed_by_last_transition(&self) -> bool {
+        matches!(self.applied, Some(StackOperation::Pop))
+    }
+}
+
+/// Registers the systems applying the operations of a [`StateStack<S>`]
+/// in the [`StateTransition`](crate::state::StateTransition) schedule.
+///
+/// The [`StateStack<S>`] resource must be inserted separately.
+/// When using `bevy_app`, prefer [`enable_state_stack`](crate::app::AppExtStates::enable_state_stack).
+pub fn register_state_stack<S: FreelyMutableState>(schedule: &mut Schedule) {
+    schedule.add_systems(
+        apply_state_stack::<S>
+            .in_set(StateTransitionSteps::DependentTransitions)
+            .before(ApplyStateTransition::<S>::default()),
+    );
+}
+
+/// Turns the pending operation of a [`StateStack<S>`] into a [`NextState<S>`].
+fn apply_state_stack<S: FreelyMutableState>(
+    mut stack: ResMut<StateStack<S>>,
+    state: Option<Res<State<S>>>,
+    mut next_state: ResMut<NextState<S>>,
+) {
+    if stack.applied.is_some() {
+        stack.applied = None;
+    }
+    let Some(operation) = stack.pending.take() else {
+        return;
+    };
+    let Some(state) = state else {
+        warn!(
+            "Ignoring {operation:?} as the state `{}` doesn't exist.",
+            std::any::type_name::<S>()
+        );
+        return;
+    };
+
+    let next = match &operation {
+        StackOperation::Push(next) => {
+            if next == state.get() {
+                warn!("Ignoring push of {next:?}, which is already the current state.");
+                return;
+            }
+            stack.paused.push(state.get().clone());
+            next.clone()
+        }
+        StackOperation::Pop => {
+            let Some(next) = stack.paused.pop() else {
+                warn!(
+                    "Ignoring pop as no state `{}` is paused.",
+                    std::any::type_name::<S>()
+                );
+                return;
+            };
+            next
+        }
+        StackOperation::Replace(next) => next.clone(),
+    };
+    stack.applied = Some(operation);
+    next_state.set(next);
+}
+
+#[cfg(all(test, feature = "bevy_app"))]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::prelude::*;
+    use bevy_state_macros::States;
+
+    use crate as bevy_state;
+    use crate::app::{AppExtStates, StatesPlugin};
+    use crate::state::{
+        ComputedStates, OnEnter, OnExit, OnPause, OnResume, StateSet, StateTransition, SubStates,
+    };
+    use crate::state_scoped::StateScoped;
+
+    use super::*;
+
+    #[derive(States, PartialEq, Eq, Debug, Default, Hash, Clone, Copy)]
+    enum GameState {
+        #[default]
+        InGame,
+        Paused,
+        Settings,
+    }
+
+    #[derive(Resource, Default, Debug, PartialEq, Eq)]
+    struct Log(Vec<String>);
+
+    fn log(message: String) -> impl Fn(ResMut<Log>) {
+        move |mut log: ResMut<Log>| log.0.push(message.clone())
+    }
+
+    fn setup() -> App {
+        let mut app = App::new();
+        app.add_plugins(StatesPlugin)
+            .init_state::<GameState>()
+            .enable_state_stack::<GameState>()
+            .enable_state_scoped_entities::<GameState>()
+            .init_resource::<Log>();
+
+        for (state, name) in [
+            (GameState::InGame, "in_game"),
+            (GameState::Paused, "paused"),
+            (GameState::Settings, "settings"),
+        ] {
+            app.add_systems(OnEnter(state), log(format!("enter {name}")))
+                .add_systems(OnExit(state), log(format!("exit {name}")))
+                .add_systems(OnPause(state), log(format!("pause {name}")))
+                .add_systems(OnResume(state), log(format!("resume {name}")));
+        }
+
+        app.world_mut().run_schedule(StateTransition);
+        app.world_mut().resource_mut::<Log>().0.clear();
+        app
+    }
+
+    fn transition(
+        app: &mut App,
+        operation: impl FnOnce(&mut StateStack<GameState>),
+    ) -> Vec<String> {
+        operation(&mut app.world_mut().resource_mut::<StateStack<GameState>>());
+        app.world_mut().run_schedule(StateTransition);
+        std::mem::take(&mut app.world_mut().resource_mut::<Log>().0)
+    }
+
+    fn current(app: &App) -> GameState {
+        *app.world().resource::<State<GameState>>().get()
+    }
+
+    #[test]
+    fn push_and_pop_pause_and_resume_states() {
+        let mut app = setup();
+
+        let log = transition(&mut app, |stack| stack.push(GameState::Paused));
+        assert_eq!(log, ["pause in_game", "enter paused"]);
+        assert_eq!(current(&app), GameState::Paused);
+
+        let log = transition(&mut app, |stack| stack.push(GameState::Settings));
+        assert_eq!(log, ["pause paused", "enter settings"]);
+        assert_eq!(
+            app.world().resource::<StateStack<GameState>>().paused(),
+            [GameState::InGame, GameState::Paused]
+        );
+
+        let log = transition(&mut app, StateStack::pop);
+        assert_eq!(log, ["exit settings", "resume paused"]);
+        assert_eq!(current(&app), GameState::Paused);
+
+        let log = transition(&mut app, StateStack::pop);
+        assert_eq!(log, ["exit paused", "resume in_game"]);
+        assert_eq!(current(&app), GameState::InGame);
+
+        // Popping an empty stack is ignored.
+        let log = transition(&mut app, StateStack::pop);
+        assert!(log.is_empty());
+        assert_eq!(current(&app), GameState::InGame);
+    }
+
+    #[test]
+    fn pushing_the_current_state_is_ignored() {
+        let mut app = setup();
+
+        let log = transition(&mut app, |stack| stack.push(GameState::InGame));
+        assert!(log.is_empty());
+        let stack = app.world().resource::<StateStack<GameState>>();
+        assert!(stack.paused().is_empty());
+        assert_eq!(stack.applied(), None);
+        assert_eq!(current(&app), GameState::InGame);
+    }
+
+    #[derive(SubStates, PartialEq, Eq, Debug, Default, Hash, Clone, Copy)]
+    #[source(GameState = GameState::InGame)]
+    enum Combat {
+        #[default]
+        Exploring,
+        Fighting,
+    }
+
+    #[derive(PartialEq, Eq, Debug, Hash, Clone, Copy)]
+    struct Playing;
+
+    impl ComputedStates for Playing {
+        type SourceStates = GameState;
+
+        fn compute(sources: GameState) -> Option<Self> {
+            (sources == GameState::InGame).then_some(Playing)
+        }
+    }
+
+    #[test]
+    fn dependent_states_are_not_stacked() {
+        let mut app = setup();
+        app.add_sub_state::<Combat>()
+            .add_computed_state::<Playing>()
+            .insert_resource(NextState::Pending(Combat::Fighting));
+        app.world_mut().run_schedule(StateTransition);
+
+        transition(&mut app, |stack| stack.push(GameState::Paused));
+        assert!(!app.world().contains_resource::<State<Combat>>());
+        assert!(!app.world().contains_resource::<State<Playing>>());
+
+        // Computed states are computed again, but sub-states start over from their default.
+        transition(&mut app, StateStack::pop);
+        assert_eq!(
+            app.world().resource::<State<Combat>>().get(),
+            &Combat::Exploring
+        );
+        assert!(app.world().contains_resource::<State<Playing>>());
+    }
+
+    #[test]
+    fn replace_keeps_paused_states() {
+        let mut app = setup();
+
+        transition(&mut app, |stack| stack.push(GameState::Paused));
+        let log = transition(&mut app, |stack| stack.replace(GameState::Settings));
+        assert_eq!(log, ["exit paused", "enter settings"]);
+        assert_eq!(
+            app.world().resource::<StateStack<GameState>>().paused(),
+            [GameState::InGame]
+        );
+
+        let log = transition(&mut app, StateStack::pop);
+        assert_eq!(log, ["exit settings", "resume in_game"]);
+    }
+
+    #[test]
+    fn state_scoped_entities_survive_pause() {
+        let mut app = setup();
+        let in_game = app.world_mut().spawn(StateScoped(GameState::InGame)).id();
+
+        transition(&mut app, |stack| stack.push(GameState::Paused));
+        let paused = app.world_mut().spawn(StateScoped(GameState::Paused)).id();
+        app.world_mut().flush();
+        assert!(app.world().get_entity(in_game).is_some());
+
+        transition(&mut app, StateStack::pop);
+        assert!(app.world().get_entity(in_game).is_some());
+        assert!(app.world().get_entity(paused).is_none());
+
+        transition(&mut app, |stack| stack.replace(GameState::Settings));
+        assert!(app.world().get_entity(in_game).is_none());
+    }
+}
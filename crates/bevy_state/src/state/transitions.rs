@@ -7,7 +7,7 @@ use bevy_ecs::{
     world::World,
 };
 
-use super::{resources::State, states::States};
+use super::{resources::State, stack::StateStack, states::States};
 
 /// The label of a [`Schedule`] that **only** runs whenever [`State<S>`] enters the provided state.
 ///
@@ -21,6 +21,20 @@ pub struct OnEnter<S: States>(pub S);
 #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct OnExit<S: States>(pub S);
 
+/// The label of a [`Schedule`] that **only** runs whenever the provided state is paused
+/// by [pushing](super::StateStack::push) another state over it.
+///
+/// When a state is paused, this schedule runs instead of [`OnExit`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnPause<S: States>(pub S);
+
+/// The label of a [`Schedule`] that **only** runs whenever the provided state is resumed
+/// by [popping](super::StateStack::pop) the state that was pushed over it.
+///
+/// When a state is resumed, this schedule runs instead of [`OnEnter`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnResume<S: States>(pub S);
+
 /// The label of a [`Schedule`] that **only** runs whenever [`State<S>`]
 /// exits AND enters the provided `exited` and `entered` states.
 ///
@@ -219,7 +233,14 @@ pub(crate) fn run_enter<S: States>(
         return;
     };
 
-    let _ = world.try_run_schedule(OnEnter(entered));
+    if world
+        .get_resource::<StateStack<S>>()
+        .is_some_and(StateStack::resumed_by_last_transition)
+    {
+        let _ = world.try_run_schedule(OnResume(entered));
+    } else {
+        let _ = world.try_run_schedule(OnEnter(entered));
+    }
 }
 
 pub(crate) fn run_exit<S: States>(
@@ -236,7 +257,14 @@ pub(crate) fn run_exit<S: States>(
         return;
     };
 
-    let _ = world.try_run_schedule(OnExit(exited));
+    if world
+        .get_resource::<StateStack<S>>()
+        .is_some_and(StateStack::paused_by_last_transition)
+    {
+        let _ = world.try_run_schedule(OnPause(exited));
+    } else {
+        let _ = world.try_run_schedule(OnExit(exited));
+    }
 }
 
 pub(crate) fn run_transition<S: States>(
@@ -4,14 +4,14 @@ use bevy_ecs::{
     component::Component,
     entity::Entity,
     event::EventReader,
-    system::{Commands, Query},
+    system::{Commands, Query, Res},
 };
 #[cfg(feature = "bevy_hierarchy")]
 use bevy_hierarchy::DespawnRecursiveExt;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::prelude::*;
 
-use crate::state::{StateTransitionEvent, States};
+use crate::state::{StateStack, StateTransitionEvent, States};
 
 /// Entities marked with this component will be removed
 /// when the world's state of the matching type no longer matches the supplied value.
@@ -21,6 +21,9 @@ use crate::state::{StateTransitionEvent, States};
 ///
 /// If `bevy_hierarchy` feature is enabled, which it is by default, the despawn will be recursive.
 ///
+/// If the state uses a [`StateStack`], entities are kept while their state is paused
+/// and only removed once it is exited.
+///
 /// ```
 /// use bevy_state::prelude::*;
 /// use bevy_ecs::prelude::*;
@@ -68,6 +71,7 @@ pub fn clear_state_scoped_entities<S: States>(
     mut commands: Commands,
     mut transitions: EventReader<StateTransitionEvent<S>>,
     query: Query<(Entity, &StateScoped<S>)>,
+    stack: Option<Res<StateStack<S>>>,
 ) {
     // We use the latest event, because state machine internals generate at most 1
     // transition event (per type) each frame. No event means no change happened
@@ -81,6 +85,9 @@ pub fn clear_state_scoped_entities<S: States>(
     let Some(exited) = &transition.exited else {
         return;
     };
+    if stack.is_some_and(|stack| stack.paused_by_last_transition() || stack.is_paused(exited)) {
+        return;
+    }
     for (entity, binding) in &query {
         if binding.0 == *exited {
             #[cfg(feature = "bevy_hierarchy")]
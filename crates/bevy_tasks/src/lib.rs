@@ -23,6 +23,12 @@ mod single_threaded_task_pool;
 #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
 pub use single_threaded_task_pool::{Scope, TaskPool, TaskPoolBuilder, ThreadExecutor};
 
+mod priority;
+pub use priority::TaskPriority;
+
+mod stats;
+pub use stats::TaskPoolStats;
+
 mod usages;
 #[cfg(not(target_arch = "wasm32"))]
 pub use usages::tick_global_task_pools_on_main_thread;
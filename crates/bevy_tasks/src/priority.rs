@@ -0,0 +1,20 @@
+/// How urgently a task spawned with [`TaskPool::spawn_with_priority`](crate::TaskPool::spawn_with_priority)
+/// should run, compared to the other tasks of its pool.
+///
+/// Whenever a thread of the pool looks for a task to run, it picks a [`High`](Self::High) priority task if
+/// one is ready, then a [`Normal`](Self::Normal) one, and only runs [`Background`](Self::Background) tasks
+/// when no other task is ready. Priorities never interrupt a running task, and threads run a few ready
+/// tasks in a row before looking again, so they order work rather than guarantee latency.
+///
+/// On the single threaded task pool, tasks run in the order they were spawned regardless of their priority.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum TaskPriority {
+    /// For work that should only use otherwise idle threads, like long running jobs whose results
+    /// aren't waited on.
+    Background,
+    /// The priority of tasks spawned with [`TaskPool::spawn`](crate::TaskPool::spawn) and scopes.
+    #[default]
+    Normal,
+    /// For short tasks that something is waiting on, like loading the assets needed for the next frame.
+    High,
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 use std::{cell::RefCell, future::Future, marker::PhantomData, mem, rc::Rc};
 
-use crate::Task;
+use crate::{Task, TaskPoolStats, TaskPriority};
 
 thread_local! {
     static LOCAL_EXECUTOR: async_executor::LocalExecutor<'static> = const { async_executor::LocalExecutor::new() };
@@ -46,6 +46,16 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// No op on the single threaded task pool
+    pub fn lend_threads_to(self, _pool: &TaskPool) -> Self {
+        self
+    }
+
+    /// No op on the single threaded task pool
+    pub fn track_stats(self, _track_stats: bool) -> Self {
+        self
+    }
+
     /// Creates a new [`TaskPool`]
     pub fn build(self) -> TaskPool {
         TaskPool::new_internal()
@@ -78,6 +88,14 @@ impl TaskPool {
         1
     }
 
+    /// Returns empty statistics, as the single threaded task pool doesn't track its tasks.
+    pub fn stats(&self) -> TaskPoolStats {
+        TaskPoolStats {
+            threads: self.thread_num(),
+            ..TaskPoolStats::default()
+        }
+    }
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -172,6 +190,19 @@ impl TaskPool {
         }
     }
 
+    /// Spawns a static future onto the thread pool. This is exactly the same as [`TaskPool::spawn`],
+    /// as the single threaded task pool ignores priorities.
+    pub fn spawn_with_priority<T>(
+        &self,
+        _priority: TaskPriority,
+        future: impl Future<Output = T> + 'static,
+    ) -> Task<T>
+    where
+        T: 'static,
+    {
+        self.spawn(future)
+    }
+
     /// Spawns a static future on the JS event loop. This is exactly the same as [`TaskPool::spawn`].
     pub fn spawn_local<T>(&self, future: impl Future<Output = T> + 'static) -> Task<T>
     where
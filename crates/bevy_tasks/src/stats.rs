@@ -0,0 +1,150 @@
+use std::time::Duration;
+
+/// A snapshot of the tasks of a [`TaskPool`](crate::TaskPool), returned by [`TaskPool::stats`](crate::TaskPool::stats).
+///
+/// Tasks spawned on a [`Scope`](crate::Scope) are counted too. The single threaded task pool doesn't track
+/// its tasks, so its statistics are always empty.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TaskPoolStats {
+    /// The number of threads owned by the pool.
+    pub threads: usize,
+    /// The number of tasks that were spawned but never started running.
+    pub queued: usize,
+    /// The number of tasks that started running and haven't completed yet, including the ones waiting
+    /// on something else, like IO.
+    pub running: usize,
+    /// The number of tasks that completed since the pool was created.
+    pub completed: u64,
+    /// The total time spent running tasks since the pool was created, on any thread.
+    pub busy_time: Duration,
+}
+
+impl TaskPoolStats {
+    /// Returns the fraction of the time the threads of the pool were running tasks between `previous`
+    /// and this snapshot, taken `elapsed` apart.
+    ///
+    /// This can exceed `1.0` when threads outside of the pool help running its tasks, like the thread
+    /// running a [`TaskPool::scope`](crate::TaskPool::scope) or threads borrowed from another pool.
+    pub fn utilization(&self, previous: &TaskPoolStats, elapsed: Duration) -> f64 {
+        let available = elapsed.as_secs_f64() * self.threads as f64;
+        if available <= 0.0 {
+            return 0.0;
+        }
+        self.busy_time
+            .saturating_sub(previous.busy_time)
+            .as_secs_f64()
+            / available
+    }
+
+    /// Returns the number of tasks that completed between `previous` and this snapshot.
+    pub fn completed_since(&self, previous: &TaskPoolStats) -> u64 {
+        self.completed.saturating_sub(previous.completed)
+    }
+}
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+pub(crate) use counters::{TaskCounters, Tracked};
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+mod counters {
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::{
+            atomic::{AtomicU64, AtomicUsize, Ordering},
+            Arc,
+        },
+        task::{Context, Poll},
+        time::{Duration, Instant},
+    };
+
+    use super::TaskPoolStats;
+
+    /// The counters behind [`TaskPoolStats`], updated by the [`Tracked`] tasks of a pool.
+    #[derive(Debug, Default)]
+    pub(crate) struct TaskCounters {
+        queued: AtomicUsize,
+        running: AtomicUsize,
+        completed: AtomicU64,
+        busy_nanos: AtomicU64,
+    }
+
+    impl TaskCounters {
+        pub(crate) fn stats(&self, threads: usize) -> TaskPoolStats {
+            TaskPoolStats {
+                threads,
+                queued: self.queued.load(Ordering::Relaxed),
+                running: self.running.load(Ordering::Relaxed),
+                completed: self.completed.load(Ordering::Relaxed),
+                busy_time: Duration::from_nanos(self.busy_nanos.load(Ordering::Relaxed)),
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum TaskState {
+        Queued,
+        Running,
+        Completed,
+    }
+
+    /// A future updating the [`TaskCounters`] of its pool as it is polled.
+    pub(crate) struct Tracked<F> {
+        future: F,
+        counters: Arc<TaskCounters>,
+        state: TaskState,
+    }
+
+    impl<F> Tracked<F> {
+        pub(crate) fn new(future: F, counters: &Arc<TaskCounters>) -> Self {
+            counters.queued.fetch_add(1, Ordering::Relaxed);
+            Self {
+                future,
+                counters: Arc::clone(counters),
+                state: TaskState::Queued,
+            }
+        }
+    }
+
+    impl<F: Future> Future for Tracked<F> {
+        type Output = F::Output;
+
+        #[allow(unsafe_code)]
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            // SAFETY: `future` is structurally pinned: it is never moved out of `self`, neither here nor
+            // when dropped. The other fields aren't pinned.
+            let this = unsafe { self.get_unchecked_mut() };
+            // SAFETY: See above.
+            let future = unsafe { Pin::new_unchecked(&mut this.future) };
+            let counters = &this.counters;
+            if this.state == TaskState::Queued {
+                this.state = TaskState::Running;
+                counters.queued.fetch_sub(1, Ordering::Relaxed);
+                counters.running.fetch_add(1, Ordering::Relaxed);
+            }
+
+            let start = Instant::now();
+            let poll = future.poll(cx);
+            let busy_nanos = start.elapsed().as_nanos().try_into().unwrap_or(u64::MAX);
+            counters.busy_nanos.fetch_add(busy_nanos, Ordering::Relaxed);
+
+            if poll.is_ready() {
+                this.state = TaskState::Completed;
+                counters.running.fetch_sub(1, Ordering::Relaxed);
+                counters.completed.fetch_add(1, Ordering::Relaxed);
+            }
+            poll
+        }
+    }
+
+    impl<F> Drop for Tracked<F> {
+        fn drop(&mut self) {
+            // The task was canceled, or panicked while running.
+            match self.state {
+                TaskState::Queued => self.counters.queued.fetch_sub(1, Ordering::Relaxed),
+                TaskState::Running => self.counters.running.fetch_sub(1, Ordering::Relaxed),
+                TaskState::Completed => return,
+            };
+        }
+    }
+}
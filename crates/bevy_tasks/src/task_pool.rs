@@ -3,6 +3,7 @@ use std::{
     marker::PhantomData,
     mem,
     panic::AssertUnwindSafe,
+    pin::Pin,
     sync::Arc,
     thread::{self, JoinHandle},
 };
@@ -13,8 +14,9 @@ use futures_lite::FutureExt;
 
 use crate::{
     block_on,
+    stats::{TaskCounters, Tracked},
     thread_executor::{ThreadExecutor, ThreadExecutorTicker},
-    Task,
+    Task, TaskPoolStats, TaskPriority,
 };
 
 struct CallOnDrop(Option<Arc<dyn Fn() + Send + Sync + 'static>>);
@@ -42,6 +44,10 @@ pub struct TaskPoolBuilder {
 
     on_thread_spawn: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
     on_thread_destroy: Option<Arc<dyn Fn() + Send + Sync + 'static>>,
+    /// The executors of the pools the threads run tasks of when idle, see [`Self::lend_threads_to`].
+    borrowers: Vec<Arc<async_executor::Executor<'static>>>,
+    /// If set, the tasks of the pool are counted in its [`stats`](TaskPool::stats).
+    track_stats: bool,
 }
 
 impl TaskPoolBuilder {
@@ -88,6 +94,29 @@ impl TaskPoolBuilder {
         self
     }
 
+    /// Lets the threads of the created pool run the tasks of `pool` when they are idle.
+    ///
+    /// The threads prefer the [`High`](TaskPriority::High) and [`Normal`](TaskPriority::Normal) priority
+    /// tasks of their own pool, but run the tasks of `pool` spawned with these priorities before their
+    /// own [`Background`](TaskPriority::Background) ones. Tasks are still counted in the
+    /// [`stats`](TaskPool::stats) of the pool they were spawned on.
+    ///
+    /// This can be called several times to lend the threads to several pools, the first ones being preferred.
+    pub fn lend_threads_to(mut self, pool: &TaskPool) -> Self {
+        self.borrowers
+            .push(Arc::clone(&pool.high_priority_executor));
+        self.borrowers.push(Arc::clone(&pool.executor));
+        self
+    }
+
+    /// Counts the tasks of the created pool and the time spent running them, see [`TaskPool::stats`].
+    ///
+    /// This is disabled by default, as every task then updates shared counters each time it is polled.
+    pub fn track_stats(mut self, track_stats: bool) -> Self {
+        self.track_stats = track_stats;
+        self
+    }
+
     /// Creates a new [`TaskPool`] based on the current options.
     pub fn build(self) -> TaskPool {
         TaskPool::new_internal(self)
@@ -104,10 +133,19 @@ impl TaskPoolBuilder {
 ///
 /// If the result is not required, one may also use [`Task::detach`] and the pool
 /// will still execute a task, even if it is dropped.
+///
+/// Tasks can be given a [`TaskPriority`] with [`TaskPool::spawn_with_priority`], and
+/// [`TaskPool::stats`] tells how busy the pool is.
 #[derive(Debug)]
 pub struct TaskPool {
-    /// The executor for the pool.
+    /// The executor for the pool, running [`TaskPriority::Normal`] tasks.
     executor: Arc<async_executor::Executor<'static>>,
+    /// The executor running [`TaskPriority::High`] tasks.
+    high_priority_executor: Arc<async_executor::Executor<'static>>,
+    /// The executor running [`TaskPriority::Background`] tasks.
+    background_executor: Arc<async_executor::Executor<'static>>,
+    /// The counters behind [`TaskPool::stats`], if enabled with [`TaskPoolBuilder::track_stats`].
+    counters: Option<Arc<TaskCounters>>,
 
     // The inner state of the pool.
     threads: Vec<JoinHandle<()>>,
@@ -134,6 +172,8 @@ impl TaskPool {
         let (shutdown_tx, shutdown_rx) = async_channel::unbounded::<()>();
 
         let executor = Arc::new(async_executor::Executor::new());
+        let high_priority_executor = Arc::new(async_executor::Executor::new());
+        let background_executor = Arc::new(async_executor::Executor::new());
 
         let num_threads = builder
             .num_threads
@@ -142,6 +182,9 @@ impl TaskPool {
         let threads = (0..num_threads)
             .map(|i| {
                 let ex = Arc::clone(&executor);
+                let high_priority_ex = Arc::clone(&high_priority_executor);
+                let background_ex = Arc::clone(&background_executor);
+                let borrowers = builder.borrowers.clone();
                 let shutdown_rx = shutdown_rx.clone();
 
                 let thread_name = if let Some(thread_name) = builder.thread_name.as_deref() {
@@ -168,12 +211,19 @@ impl TaskPool {
                             let _destructor = CallOnDrop(on_thread_destroy);
                             loop {
                                 let res = std::panic::catch_unwind(|| {
-                                    let tick_forever = async move {
+                                    // An executor only runs its tasks when the future it runs is
+                                    // pending, so the innermost future has the highest priority.
+                                    let tick_forever = async {
                                         loop {
-                                            local_executor.tick().await;
+                                            high_priority_ex.tick().or(local_executor.tick()).await;
                                         }
                                     };
-                                    block_on(ex.run(tick_forever.or(shutdown_rx.recv())))
+                                    let mut run: Pin<Box<dyn Future<Output = _>>> =
+                                        Box::pin(ex.run(tick_forever.or(shutdown_rx.recv())));
+                                    for borrower in &borrowers {
+                                        run = Box::pin(borrower.run(run));
+                                    }
+                                    block_on(background_ex.run(run))
                                 });
                                 if let Ok(value) = res {
                                     // Use unwrap_err because we expect a Closed error
@@ -189,6 +239,9 @@ impl TaskPool {
 
         Self {
             executor,
+            high_priority_executor,
+            background_executor,
+            counters: builder.track_stats.then(Arc::default),
             threads,
             shutdown_tx,
         }
@@ -199,6 +252,33 @@ impl TaskPool {
         self.threads.len()
     }
 
+    /// Returns a snapshot of the tasks of the pool.
+    ///
+    /// Tasks are only counted if enabled with [`TaskPoolBuilder::track_stats`], otherwise only
+    /// [`threads`](TaskPoolStats::threads) is set.
+    ///
+    /// ```
+    /// use bevy_tasks::TaskPoolBuilder;
+    ///
+    /// let pool = TaskPoolBuilder::new().track_stats(true).build();
+    /// pool.scope(|s| {
+    ///     s.spawn(async {});
+    ///     s.spawn(async {});
+    /// });
+    /// let stats = pool.stats();
+    /// assert_eq!(stats.completed, 2);
+    /// assert_eq!(stats.queued + stats.running, 0);
+    /// ```
+    pub fn stats(&self) -> TaskPoolStats {
+        match &self.counters {
+            Some(counters) => counters.stats(self.thread_num()),
+            None => TaskPoolStats {
+                threads: self.thread_num(),
+                ..TaskPoolStats::default()
+            },
+        }
+    }
+
     /// Allows spawning non-`'static` futures on the thread pool. The function takes a callback,
     /// passing a scope object into it. The scope object provided to the callback can be used
     /// to spawn tasks. This function will await the completion of all tasks before returning.
@@ -367,6 +447,7 @@ impl TaskPool {
 
         let scope = Scope {
             executor,
+            counters: self.counters.clone(),
             external_executor,
             scope_executor,
             spawned,
@@ -533,7 +614,36 @@ impl TaskPool {
     where
         T: Send + 'static,
     {
-        Task::new(self.executor.spawn(future))
+        self.spawn_with_priority(TaskPriority::Normal, future)
+    }
+
+    /// Spawns a static future onto the thread pool, like [`TaskPool::spawn`], with the given
+    /// [`TaskPriority`].
+    ///
+    /// ```
+    /// use bevy_tasks::{TaskPool, TaskPriority};
+    ///
+    /// let pool = TaskPool::new();
+    /// let task = pool.spawn_with_priority(TaskPriority::Background, async { 2 + 2 });
+    /// assert_eq!(bevy_tasks::block_on(task), 4);
+    /// ```
+    pub fn spawn_with_priority<T>(
+        &self,
+        priority: TaskPriority,
+        future: impl Future<Output = T> + Send + 'static,
+    ) -> Task<T>
+    where
+        T: Send + 'static,
+    {
+        let executor = match priority {
+            TaskPriority::High => &self.high_priority_executor,
+            TaskPriority::Normal => &self.executor,
+            TaskPriority::Background => &self.background_executor,
+        };
+        Task::new(match &self.counters {
+            Some(counters) => executor.spawn(Tracked::new(future, counters)),
+            None => executor.spawn(future),
+        })
     }
 
     /// Spawns a static future on the thread-local async executor for the
@@ -599,6 +709,7 @@ impl Drop for TaskPool {
 #[derive(Debug)]
 pub struct Scope<'scope, 'env: 'scope, T> {
     executor: &'scope async_executor::Executor<'scope>,
+    counters: Option<Arc<TaskCounters>>,
     external_executor: &'scope ThreadExecutor<'scope>,
     scope_executor: &'scope ThreadExecutor<'scope>,
     spawned: &'scope ConcurrentQueue<FallibleTask<Result<T, Box<(dyn std::any::Any + Send)>>>>,
@@ -617,10 +728,12 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
     ///
     /// For more information, see [`TaskPool::scope`].
     pub fn spawn<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
-        let task = self
-            .executor
-            .spawn(AssertUnwindSafe(f).catch_unwind())
-            .fallible();
+        let future = AssertUnwindSafe(f).catch_unwind();
+        let task = match &self.counters {
+            Some(counters) => self.executor.spawn(Tracked::new(future, counters)),
+            None => self.executor.spawn(future),
+        }
+        .fallible();
         // ConcurrentQueue only errors when closed or full, but we never
         // close and use an unbounded queue, so it is safe to unwrap
         self.spawned.push(task).unwrap();
@@ -633,10 +746,12 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
     ///
     /// For more information, see [`TaskPool::scope`].
     pub fn spawn_on_scope<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
-        let task = self
-            .scope_executor
-            .spawn(AssertUnwindSafe(f).catch_unwind())
-            .fallible();
+        let future = AssertUnwindSafe(f).catch_unwind();
+        let task = match &self.counters {
+            Some(counters) => self.scope_executor.spawn(Tracked::new(future, counters)),
+            None => self.scope_executor.spawn(future),
+        }
+        .fallible();
         // ConcurrentQueue only errors when closed or full, but we never
         // close and use an unbounded queue, so it is safe to unwrap
         self.spawned.push(task).unwrap();
@@ -650,10 +765,12 @@ impl<'scope, 'env, T: Send + 'scope> Scope<'scope, 'env, T> {
     ///
     /// For more information, see [`TaskPool::scope`].
     pub fn spawn_on_external<Fut: Future<Output = T> + 'scope + Send>(&self, f: Fut) {
-        let task = self
-            .external_executor
-            .spawn(AssertUnwindSafe(f).catch_unwind())
-            .fallible();
+        let future = AssertUnwindSafe(f).catch_unwind();
+        let task = match &self.counters {
+            Some(counters) => self.external_executor.spawn(Tracked::new(future, counters)),
+            None => self.external_executor.spawn(future),
+        }
+        .fallible();
         // ConcurrentQueue only errors when closed or full, but we never
         // close and use an unbounded queue, so it is safe to unwrap
         self.spawned.push(task).unwrap();
@@ -940,4 +1057,101 @@ mod tests {
 
         assert_eq!(count.load(Ordering::Acquire), 1);
     }
+
+    /// Spawns a task blocking the only thread of `pool` until the returned sender is dropped.
+    fn block_thread(pool: &TaskPool) -> (Task<()>, std::sync::mpsc::Sender<()>) {
+        let (started_tx, started_rx) = std::sync::mpsc::channel();
+        let (release_tx, release_rx) = std::sync::mpsc::channel::<()>();
+        let blocker = pool.spawn_with_priority(TaskPriority::High, async move {
+            started_tx.send(()).unwrap();
+            let _ = release_rx.recv();
+        });
+        started_rx.recv().unwrap();
+        (blocker, release_tx)
+    }
+
+    #[test]
+    fn test_priorities_and_stats() {
+        let pool = TaskPoolBuilder::new()
+            .num_threads(1)
+            .track_stats(true)
+            .build();
+        let (blocker, release) = block_thread(&pool);
+
+        let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let tasks: Vec<_> = [
+            TaskPriority::Background,
+            TaskPriority::Normal,
+            TaskPriority::High,
+        ]
+        .into_iter()
+        .map(|priority| {
+            let order = order.clone();
+            pool.spawn_with_priority(priority, async move {
+                order.lock().unwrap().push(priority);
+            })
+        })
+        .collect();
+
+        let stats = pool.stats();
+        assert_eq!(stats.threads, 1);
+        assert_eq!(stats.queued, 3);
+        assert_eq!(stats.running, 1);
+        assert_eq!(stats.completed, 0);
+
+        drop(release);
+        block_on(blocker);
+        for task in tasks {
+            block_on(task);
+        }
+        assert_eq!(
+            *order.lock().unwrap(),
+            [
+                TaskPriority::High,
+                TaskPriority::Normal,
+                TaskPriority::Background
+            ]
+        );
+
+        let stats = pool.stats();
+        assert_eq!(stats.queued + stats.running, 0);
+        assert_eq!(stats.completed, 4);
+        assert!(stats.busy_time > std::time::Duration::ZERO);
+    }
+
+    #[test]
+    fn test_untracked_stats() {
+        let pool = TaskPoolBuilder::new().num_threads(1).build();
+        pool.scope(|s| s.spawn(async {}));
+        assert_eq!(block_on(pool.spawn(async { 42 })), 42);
+        assert_eq!(
+            pool.stats(),
+            TaskPoolStats {
+                threads: 1,
+                ..TaskPoolStats::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_lend_threads() {
+        let pool = TaskPoolBuilder::new()
+            .num_threads(1)
+            .track_stats(true)
+            .build();
+        let helper = TaskPoolBuilder::new()
+            .num_threads(1)
+            .track_stats(true)
+            .lend_threads_to(&pool)
+            .build();
+        let (blocker, release) = block_thread(&pool);
+
+        // The only thread of `pool` is blocked, so the task can only run on the thread of `helper`.
+        assert_eq!(block_on(pool.spawn(async { 42 })), 42);
+        assert_eq!(pool.stats().completed, 1);
+        assert_eq!(helper.stats().completed, 0);
+
+        drop(release);
+        block_on(blocker);
+    }
 }
@@ -9,7 +9,9 @@
 /// Common run conditions
 pub mod common_conditions;
 mod fixed;
+mod manual;
 mod real;
+mod scheduled;
 mod stopwatch;
 #[allow(clippy::module_inception)]
 mod time;
@@ -17,7 +19,9 @@ mod timer;
 mod virt;
 
 pub use fixed::*;
+pub use manual::*;
 pub use real::*;
+pub use scheduled::*;
 pub use stopwatch::*;
 pub use time::*;
 pub use timer::*;
@@ -29,7 +33,7 @@ pub mod prelude {
     pub use crate::{Fixed, Real, Time, Timer, TimerMode, Virtual};
 }
 
-use bevy_app::{prelude::*, RunFixedMainLoop};
+use bevy_app::{prelude::*, FixedPreUpdate, RunFixedMainLoop};
 use bevy_ecs::event::{signal_event_update_system, EventRegistry, ShouldUpdateEvents};
 use bevy_ecs::prelude::*;
 use bevy_utils::{tracing::warn, Duration, Instant};
@@ -65,6 +69,28 @@ impl Plugin for TimePlugin {
         app.add_systems(First, time_system.in_set(TimeSystem))
             .add_systems(RunFixedMainLoop, run_fixed_main_schedule);
 
+        app.init_resource::<ScheduledCallbacks<Real>>()
+            .init_resource::<ScheduledCallbacks<Virtual>>()
+            .init_resource::<ScheduledCallbacks<Fixed>>()
+            .add_systems(
+                PreUpdate,
+                (
+                    run_scheduled_callbacks::<Real>.run_if(has_scheduled_callbacks::<Real>),
+                    run_scheduled_callbacks::<Virtual>.run_if(has_scheduled_callbacks::<Virtual>),
+                    advance_timelines::<Real>.run_if(any_with_component::<Timeline<Real>>),
+                    advance_timelines::<Virtual>.run_if(any_with_component::<Timeline<Virtual>>),
+                )
+                    .in_set(TimeScheduleSystem),
+            )
+            .add_systems(
+                FixedPreUpdate,
+                (
+                    run_scheduled_callbacks::<Fixed>.run_if(has_scheduled_callbacks::<Fixed>),
+                    advance_timelines::<Fixed>.run_if(any_with_component::<Timeline<Fixed>>),
+                )
+                    .in_set(TimeScheduleSystem),
+            );
+
         // Ensure the events are not dropped until `FixedMain` systems can observe them
         app.add_systems(FixedPostUpdate, signal_event_update_system);
         let mut event_registry = app.world_mut().resource_mut::<EventRegistry>();
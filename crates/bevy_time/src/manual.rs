@@ -0,0 +1,107 @@
+use bevy_app::App;
+use bevy_utils::Duration;
+
+use crate::{Real, Time, TimeUpdateStrategy};
+
+/// Extension trait for [`App`] to advance time deterministically, mainly for tests.
+///
+/// Each update advances [`Time<Real>`] by exactly the chosen delta through
+/// [`TimeUpdateStrategy::ManualDuration`], which then drives [`Time<Virtual>`](crate::Virtual)
+/// and runs as many [`Time<Fixed>`](crate::Fixed) steps as have accumulated.
+/// Note that [`Time<Virtual>`](crate::Virtual) still applies its [maximum delta](Time::max_delta)
+/// and relative speed, and doesn't advance while paused.
+///
+/// Requires the [`TimePlugin`](crate::TimePlugin).
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_time::{ManualTimeExt, Time, TimePlugin};
+/// # use bevy_utils::Duration;
+/// let mut app = App::new();
+/// app.add_plugins(TimePlugin);
+///
+/// app.advance_time(Duration::from_millis(100));
+/// app.advance_time_in_steps(Duration::from_millis(50), 4);
+/// assert_eq!(app.world().resource::<Time>().elapsed(), Duration::from_millis(300));
+/// ```
+pub trait ManualTimeExt {
+    /// Runs a single update that advances time by `delta`.
+    ///
+    /// Unlike with [`TimeUpdateStrategy::ManualDuration`] alone, time also advances on the first update.
+    /// The [`TimeUpdateStrategy`] is left set to `delta` afterwards.
+    fn advance_time(&mut self, delta: Duration) -> &mut Self;
+
+    /// Runs `steps` updates that each advance time by `delta`.
+    fn advance_time_in_steps(&mut self, delta: Duration, steps: u32) -> &mut Self;
+}
+
+impl ManualTimeExt for App {
+    fn advance_time(&mut self, delta: Duration) -> &mut Self {
+        let mut real = self.world_mut().resource_mut::<Time<Real>>();
+        if real.first_update().is_none() {
+            // The first update only sets the starting instant, start the clock now so it advances.
+            let startup = real.startup();
+            real.update_with_instant(startup);
+        }
+        self.insert_resource(TimeUpdateStrategy::ManualDuration(delta));
+        self.update();
+        self
+    }
+
+    fn advance_time_in_steps(&mut self, delta: Duration, steps: u32) -> &mut Self {
+        for _ in 0..steps {
+            self.advance_time(delta);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::{App, FixedUpdate};
+    use bevy_ecs::system::{ResMut, Resource};
+
+    use crate::{Fixed, ManualTimeExt, Real, Time, TimePlugin, Virtual};
+
+    #[derive(Resource, Default)]
+    struct FixedUpdateCounter(u32);
+
+    fn count_fixed_updates(mut counter: ResMut<FixedUpdateCounter>) {
+        counter.0 += 1;
+    }
+
+    #[test]
+    fn advances_virtual_and_fixed_time() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .init_resource::<FixedUpdateCounter>()
+            .add_systems(FixedUpdate, count_fixed_updates);
+        let timestep = app.world().resource::<Time<Fixed>>().timestep();
+
+        app.advance_time(timestep);
+        assert_eq!(app.world().resource::<Time<Virtual>>().delta(), timestep);
+        assert_eq!(app.world().resource::<FixedUpdateCounter>().0, 1);
+
+        app.advance_time_in_steps(timestep / 2, 4);
+        assert_eq!(
+            app.world().resource::<Time<Virtual>>().elapsed(),
+            timestep * 3
+        );
+        assert_eq!(
+            app.world().resource::<Time<Fixed>>().elapsed(),
+            timestep * 3
+        );
+        assert_eq!(app.world().resource::<FixedUpdateCounter>().0, 3);
+    }
+
+    #[test]
+    fn virtual_time_is_clamped() {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin);
+        let max_delta = app.world().resource::<Time<Virtual>>().max_delta();
+
+        app.advance_time(max_delta * 2);
+        assert_eq!(app.world().resource::<Time<Virtual>>().delta(), max_delta);
+        assert_eq!(app.world().resource::<Time<Real>>().delta(), max_delta * 2);
+    }
+}
@@ -0,0 +1,518 @@
+use std::marker::PhantomData;
+
+use bevy_ecs::{prelude::*, system::BoxedSystem};
+use bevy_utils::Duration;
+
+use crate::{Time, Timer, TimerMode, Virtual};
+
+/// Set of the systems that run [`ScheduledCallbacks`] and advance [`Timeline`]s.
+///
+/// For the [`Real`](crate::Real) and [`Virtual`](Virtual) clocks it runs in [`PreUpdate`](bevy_app::PreUpdate),
+/// and for the [`Fixed`](crate::Fixed) clock in [`FixedPreUpdate`](bevy_app::FixedPreUpdate).
+/// The systems of a clock only run while it has scheduled callbacks or timelines.
+#[derive(Debug, PartialEq, Eq, Clone, Hash, SystemSet)]
+pub struct TimeScheduleSystem;
+
+/// Identifies a callback registered in [`ScheduledCallbacks`], to [cancel](ScheduledCallbacks::cancel) it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CallbackId(u64);
+
+/// Resource running systems after a delay, or repeatedly at an interval, measured by the clock `T`.
+///
+/// Each callback is a system run with exclusive access to the [`World`], on the frame (or fixed tick)
+/// its [`Timer`] finishes. Since [`Time<Virtual>`](Virtual) is the default clock, callbacks
+/// respect its pause and relative speed; use [`Real`](crate::Real) to keep running while paused,
+/// or [`Fixed`](crate::Fixed) to run them in [`FixedPreUpdate`](bevy_app::FixedPreUpdate).
+/// A repeating callback whose interval elapsed several times during a frame runs as many times.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{ScheduledCallbacks, TimerMode, Virtual};
+/// # use std::time::Duration;
+/// fn spawn_wave(mut commands: Commands) {
+///     // ...
+/// }
+///
+/// fn start_level(mut callbacks: ResMut<ScheduledCallbacks<Virtual>>) {
+///     // Spawn a wave every 30 seconds, starting in 30 seconds.
+///     callbacks.schedule(Duration::from_secs(30), TimerMode::Repeating, spawn_wave);
+/// }
+/// # bevy_ecs::system::assert_is_system(start_level);
+/// ```
+#[derive(Resource)]
+pub struct ScheduledCallbacks<T: Default + Send + Sync + 'static = Virtual> {
+    callbacks: Vec<ScheduledCallback>,
+    running: bool,
+    cancelled: Vec<CallbackId>,
+    next_id: u64,
+    marker: PhantomData<T>,
+}
+
+struct ScheduledCallback {
+    id: CallbackId,
+    timer: Timer,
+    system: BoxedSystem,
+    initialized: bool,
+}
+
+impl<T: Default + Send + Sync + 'static> Default for ScheduledCallbacks<T> {
+    fn default() -> Self {
+        Self {
+            callbacks: Vec::new(),
+            running: false,
+            cancelled: Vec::new(),
+            next_id: 0,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> ScheduledCallbacks<T> {
+    /// Runs `system` once after `delay` if `mode` is [`TimerMode::Once`],
+    /// or every `delay` until [cancelled](Self::cancel) if it is [`TimerMode::Repeating`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `delay` is zero and `mode` is [`TimerMode::Repeating`], as the callback would run endlessly.
+    pub fn schedule<M>(
+        &mut self,
+        delay: Duration,
+        mode: TimerMode,
+        system: impl IntoSystem<(), (), M>,
+    ) -> CallbackId {
+        assert!(
+            !(delay.is_zero() && mode == TimerMode::Repeating),
+            "repeating callbacks must have a non-zero delay"
+        );
+        let id = CallbackId(self.next_id);
+        self.next_id += 1;
+        self.callbacks.push(ScheduledCallback {
+            id,
+            timer: Timer::new(delay, mode),
+            system: Box::new(IntoSystem::into_system(system)),
+            initialized: false,
+        });
+        id
+    }
+
+    /// Removes a callback, so that it doesn't run anymore.
+    pub fn cancel(&mut self, id: CallbackId) {
+        self.callbacks.retain(|callback| callback.id != id);
+        // The callback may be running, in which case it's not in the list.
+        if self.running {
+            self.cancelled.push(id);
+        }
+    }
+
+    /// Returns `true` if the callback is still scheduled to run.
+    pub fn contains(&self, id: CallbackId) -> bool {
+        self.callbacks.iter().any(|callback| callback.id == id)
+    }
+
+    /// Returns the time left until the callback next runs, if it is still scheduled.
+    pub fn remaining(&self, id: CallbackId) -> Option<Duration> {
+        self.callbacks
+            .iter()
+            .find(|callback| callback.id == id)
+            .map(|callback| callback.timer.remaining())
+    }
+
+    /// Returns the number of scheduled callbacks.
+    pub fn len(&self) -> usize {
+        self.callbacks.len()
+    }
+
+    /// Returns `true` if no callback is scheduled.
+    pub fn is_empty(&self) -> bool {
+        self.callbacks.is_empty()
+    }
+}
+
+/// Ticks the [`ScheduledCallbacks`] of the clock `T` and runs the finished ones.
+pub fn run_scheduled_callbacks<T: Default + Send + Sync + 'static>(world: &mut World) {
+    let delta = world.resource::<Time<T>>().delta();
+    let mut scheduled = world.resource_mut::<ScheduledCallbacks<T>>();
+    let scheduled = scheduled.bypass_change_detection();
+    scheduled.running = true;
+    let mut callbacks = std::mem::take(&mut scheduled.callbacks);
+
+    for callback in &mut callbacks {
+        callback.timer.tick(delta);
+        for _ in 0..callback.timer.times_finished_this_tick() {
+            if world
+                .resource::<ScheduledCallbacks<T>>()
+                .cancelled
+                .contains(&callback.id)
+            {
+                break;
+            }
+            if !callback.initialized {
+                callback.system.initialize(world);
+                callback.initialized = true;
+            }
+            callback.system.run((), world);
+            callback.system.apply_deferred(world);
+        }
+    }
+
+    let mut scheduled = world.resource_mut::<ScheduledCallbacks<T>>();
+    scheduled.running = false;
+    let cancelled = std::mem::take(&mut scheduled.cancelled);
+    callbacks.retain(|callback| {
+        let done = callback.timer.mode() == TimerMode::Once && callback.timer.finished();
+        !done && !cancelled.contains(&callback.id)
+    });
+    // Keep the callbacks scheduled while running after the ones that were already there.
+    callbacks.append(&mut scheduled.callbacks);
+    scheduled.callbacks = callbacks;
+}
+
+/// A run condition that is `true` if the [`ScheduledCallbacks`] of the clock `T` are not empty.
+pub fn has_scheduled_callbacks<T: Default + Send + Sync + 'static>(
+    callbacks: Res<ScheduledCallbacks<T>>,
+) -> bool {
+    !callbacks.is_empty()
+}
+
+/// A sequence of events keyed by time, triggered on its entity as the clock `T` advances.
+///
+/// Each frame (or fixed tick), the timeline's elapsed time advances by the [`Time<T>`](Time) delta,
+/// and every event whose key was reached is triggered with the timeline's entity as target,
+/// in key order, for [observers](Observer) to react to.
+/// Since [`Time<Virtual>`](Virtual) is the default clock, timelines respect its pause and relative speed.
+///
+/// A [`TimerMode::Once`] timeline stops at its [duration](Self::duration) and triggers [`TimelineFinished`],
+/// while a [`TimerMode::Repeating`] one starts over.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_time::{Timeline, TimerMode, Virtual};
+/// # use std::time::Duration;
+/// #[derive(Event, Clone)]
+/// struct Flash;
+///
+/// #[derive(Event, Clone)]
+/// struct Explode;
+///
+/// fn spawn_bomb(mut commands: Commands) {
+///     commands
+///         .spawn(
+///             Timeline::<Virtual>::new()
+///                 .with_event(Duration::from_secs(1), Flash)
+///                 .with_event(Duration::from_secs(2), Flash)
+///                 .with_event(Duration::from_secs(3), Explode),
+///         )
+///         .observe(|trigger: Trigger<Explode>, mut commands: Commands| {
+///             commands.entity(trigger.entity()).despawn();
+///         });
+/// }
+/// # bevy_ecs::system::assert_is_system(spawn_bomb);
+/// ```
+#[derive(Component)]
+pub struct Timeline<T: Default + Send + Sync + 'static = Virtual> {
+    keys: Vec<TimelineKey>,
+    next_key: usize,
+    elapsed: Duration,
+    duration: Option<Duration>,
+    mode: TimerMode,
+    paused: bool,
+    finished: bool,
+    marker: PhantomData<T>,
+}
+
+struct TimelineKey {
+    at: Duration,
+    trigger: Box<dyn Fn(&mut Commands, Entity) + Send + Sync>,
+}
+
+impl<T: Default + Send + Sync + 'static> Default for Timeline<T> {
+    fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            next_key: 0,
+            elapsed: Duration::ZERO,
+            duration: None,
+            mode: TimerMode::Once,
+            paused: false,
+            finished: false,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Default + Send + Sync + 'static> Timeline<T> {
+    /// Creates an empty timeline that plays once.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an event triggered when the timeline reaches `at`.
+    ///
+    /// Events with the same key are triggered in the order they were added.
+    pub fn with_event<E: Event + Clone>(mut self, at: Duration, event: E) -> Self {
+        let index = self.keys.partition_point(|key| key.at <= at);
+        self.keys.insert(
+            index,
+            TimelineKey {
+                at,
+                trigger: Box::new(move |commands, entity| {
+                    commands.trigger_targets(event.clone(), entity);
+                }),
+            },
+        );
+        self
+    }
+
+    /// Sets the duration of the timeline, which defaults to the key of its last event.
+    pub fn with_duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// Sets whether the timeline plays once or loops.
+    pub fn with_mode(mut self, mode: TimerMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the time elapsed since the timeline started, or since it last looped.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
+    /// Returns the duration of the timeline.
+    pub fn duration(&self) -> Duration {
+        self.duration
+            .or_else(|| self.keys.last().map(|key| key.at))
+            .unwrap_or_default()
+    }
+
+    /// Returns whether the timeline plays once or loops.
+    pub fn mode(&self) -> TimerMode {
+        self.mode
+    }
+
+    /// Moves the timeline to `elapsed`, so that the events from that point on are triggered again.
+    pub fn seek(&mut self, elapsed: Duration) {
+        self.elapsed = elapsed.min(self.duration());
+        self.next_key = self.keys.partition_point(|key| key.at < self.elapsed);
+        self.finished = false;
+    }
+
+    /// Pauses the timeline.
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    /// Unpauses the timeline.
+    pub fn unpause(&mut self) {
+        self.paused = false;
+    }
+
+    /// Returns `true` if the timeline is paused.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Returns `true` if the timeline played once to the end.
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Advances the timeline by `delta`, calling `reached` for each key reached.
+    /// Returns `true` if the timeline just finished.
+    fn advance(&mut self, delta: Duration, mut reached: impl FnMut(&TimelineKey)) -> bool {
+        if self.paused || self.finished {
+            return false;
+        }
+        self.elapsed += delta;
+        let duration = self.duration();
+        loop {
+            while let Some(key) = self
+                .keys
+                .get(self.next_key)
+                .filter(|key| key.at <= self.elapsed)
+            {
+                reached(key);
+                self.next_key += 1;
+            }
+            if self.elapsed < duration {
+                return false;
+            }
+            match self.mode {
+                TimerMode::Once => {
+                    self.elapsed = duration;
+                    self.finished = true;
+                    return true;
+                }
+                TimerMode::Repeating if duration.is_zero() => {
+                    // Every key is at zero: trigger them once per advance.
+                    self.elapsed = Duration::ZERO;
+                    self.next_key = 0;
+                    return false;
+                }
+                TimerMode::Repeating => {
+                    self.elapsed -= duration;
+                    self.next_key = 0;
+                }
+            }
+        }
+    }
+}
+
+/// Triggered on the entity of a [`TimerMode::Once`] [`Timeline`] when it reaches its end.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimelineFinished;
+
+/// Advances every [`Timeline`] of the clock `T`, triggering the events it reaches.
+pub fn advance_timelines<T: Default + Send + Sync + 'static>(
+    mut commands: Commands,
+    time: Res<Time<T>>,
+    mut timelines: Query<(Entity, &mut Timeline<T>)>,
+) {
+    let delta = time.delta();
+    for (entity, mut timeline) in &mut timelines {
+        let finished = timeline.advance(delta, |key| (key.trigger)(&mut commands, entity));
+        if finished {
+            commands.trigger_targets(TimelineFinished, entity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Real, TimePlugin, TimeUpdateStrategy};
+    use bevy_app::prelude::*;
+
+    #[derive(Resource, Default)]
+    struct Count(u32);
+
+    #[derive(Event, Clone)]
+    struct Key(u32);
+
+    #[derive(Resource, Default)]
+    struct Triggered(Vec<u32>);
+
+    fn app() -> App {
+        let mut app = App::new();
+        app.add_plugins(TimePlugin)
+            .init_resource::<Count>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )));
+        // The first update only initializes the clocks.
+        app.update();
+        app
+    }
+
+    fn increment(mut count: ResMut<Count>) {
+        count.0 += 1;
+    }
+
+    #[test]
+    fn scheduled_callbacks() {
+        let mut app = app();
+        let mut callbacks = app
+            .world_mut()
+            .resource_mut::<ScheduledCallbacks<Virtual>>();
+        let once = callbacks.schedule(Duration::from_millis(250), TimerMode::Once, increment);
+        let repeating =
+            callbacks.schedule(Duration::from_millis(200), TimerMode::Repeating, increment);
+
+        app.update();
+        assert_eq!(app.world().resource::<Count>().0, 0);
+        app.update();
+        assert_eq!(app.world().resource::<Count>().0, 1);
+        app.update();
+        assert_eq!(app.world().resource::<Count>().0, 2);
+        assert!(!app
+            .world()
+            .resource::<ScheduledCallbacks<Virtual>>()
+            .contains(once));
+
+        // Pausing virtual time pauses its callbacks, but not the real time ones.
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+        app.world_mut()
+            .resource_mut::<ScheduledCallbacks<Real>>()
+            .schedule(Duration::from_millis(100), TimerMode::Once, increment);
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Count>().0, 3);
+
+        app.world_mut().resource_mut::<Time<Virtual>>().unpause();
+        app.world_mut()
+            .resource_mut::<ScheduledCallbacks<Virtual>>()
+            .cancel(repeating);
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Count>().0, 3);
+        assert!(app
+            .world()
+            .resource::<ScheduledCallbacks<Virtual>>()
+            .is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero delay")]
+    fn zero_delay_repeating_callbacks_are_rejected() {
+        ScheduledCallbacks::<Virtual>::default().schedule(
+            Duration::ZERO,
+            TimerMode::Repeating,
+            increment,
+        );
+    }
+
+    #[test]
+    fn cancelling_outside_of_callbacks() {
+        let mut callbacks = ScheduledCallbacks::<Virtual>::default();
+        let id = callbacks.schedule(Duration::ZERO, TimerMode::Once, increment);
+        callbacks.cancel(id);
+        callbacks.cancel(id);
+        assert!(callbacks.is_empty());
+        assert!(callbacks.cancelled.is_empty());
+    }
+
+    #[test]
+    fn timeline() {
+        let mut app = app();
+        app.init_resource::<Triggered>();
+        let entity = app
+            .world_mut()
+            .spawn(
+                Timeline::<Virtual>::new()
+                    .with_event(Duration::from_millis(300), Key(3))
+                    .with_event(Duration::ZERO, Key(0))
+                    .with_event(Duration::from_millis(100), Key(1))
+                    .with_duration(Duration::from_millis(400))
+                    .with_mode(TimerMode::Repeating),
+            )
+            .observe(|trigger: Trigger<Key>, mut triggered: ResMut<Triggered>| {
+                triggered.0.push(trigger.event().0);
+            })
+            .id();
+
+        app.update();
+        assert_eq!(app.world().resource::<Triggered>().0, [0, 1]);
+        app.world_mut()
+            .resource_mut::<Time<Virtual>>()
+            .set_relative_speed(4.0);
+        app.update();
+        assert_eq!(app.world().resource::<Triggered>().0, [0, 1, 3, 0, 1]);
+
+        let mut entity = app.world_mut().entity_mut(entity);
+        let mut timeline = entity.get_mut::<Timeline<Virtual>>().unwrap();
+        timeline.mode = TimerMode::Once;
+        timeline.seek(Duration::from_millis(300));
+        entity.observe(
+            |_: Trigger<TimelineFinished>, mut triggered: ResMut<Triggered>| {
+                triggered.0.push(u32::MAX);
+            },
+        );
+        app.update();
+        assert_eq!(
+            app.world().resource::<Triggered>().0,
+            [0, 1, 3, 0, 1, 3, u32::MAX]
+        );
+    }
+}
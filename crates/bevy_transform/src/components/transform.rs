@@ -38,7 +38,32 @@ use std::ops::Mul;
 #[cfg_attr(
     feature = "bevy-support",
     derive(Component, Reflect),
-    reflect(Component, Default, PartialEq)
+    reflect(Component, Default, PartialEq),
+    reflect(methods(
+        looking_at = Self::looking_at as fn(Self, Vec3, Dir3) -> Self,
+        looking_to = Self::looking_to as fn(Self, Dir3, Dir3) -> Self,
+        with_translation,
+        with_rotation,
+        with_scale,
+        compute_matrix,
+        forward,
+        back,
+        left,
+        right,
+        up,
+        down,
+        rotate,
+        rotate_x,
+        rotate_y,
+        rotate_z,
+        rotate_local,
+        translate_around,
+        rotate_around,
+        look_at = Self::look_at as fn(&mut Self, Vec3, Dir3),
+        look_to = Self::look_to as fn(&mut Self, Dir3, Dir3),
+        mul_transform,
+        transform_point,
+    ))
 )]
 pub struct Transform {
     /// Position of the entity. In 2d, the last value of the `Vec3` is used for z-ordering.
@@ -565,3 +590,28 @@ impl Mul<Vec3> for Transform {
         self.transform_point(value)
     }
 }
+
+#[cfg(all(test, feature = "bevy-support"))]
+mod tests {
+    use super::*;
+    use bevy_reflect::{func::ArgList, TypeRegistry};
+
+    #[test]
+    fn call_reflected_looking_at() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<Transform>();
+
+        let transform = Transform::from_xyz(1.0, 2.0, 3.0);
+        let args = ArgList::new()
+            .push_owned(transform)
+            .push_owned(Vec3::ZERO)
+            .push_owned(Dir3::Y);
+        let method = registry.get_method("Transform::looking_at").unwrap();
+        let value = method.call(args).unwrap().unwrap_owned();
+
+        assert_eq!(
+            value.downcast_ref::<Transform>(),
+            Some(&transform.looking_at(Vec3::ZERO, Dir3::Y))
+        );
+    }
+}
@@ -0,0 +1,282 @@
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::{Children, HierarchyQueryExt, Parent};
+use bevy_math::Quat;
+use bevy_reflect::prelude::*;
+use bevy_time::{Fixed, Time};
+
+use crate::components::{GlobalTransform, Transform};
+
+/// Smooths the motion of an entity whose [`Transform`] is only updated in the fixed timestep schedules.
+///
+/// Simulating at a fixed rate makes an entity move in steps that don't line up with rendered frames.
+/// With this component, the [`Transform`] of the last two fixed ticks is recorded in [`FixedLast`](bevy_app::FixedLast),
+/// and in [`PostUpdate`](bevy_app::PostUpdate), right after transform propagation, the [`GlobalTransform`]
+/// of the entity and its descendants is recomputed from a blend of them based on
+/// [`Time::<Fixed>::overstep_fraction`], so that they move smoothly.
+///
+/// The blend is only visual: the [`Transform`] is never changed, so gameplay systems always see the
+/// transform of the latest tick, while the [`GlobalTransform`] is the blended one.
+///
+/// The transform should only be changed in the fixed timestep schedules. To teleport an entity without
+/// it visibly sliding to its new position, call [`reset`](Self::reset) in the same tick.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct TransformInterpolation {
+    /// How the transform is estimated between fixed ticks.
+    pub mode: InterpolationMode,
+    previous: Option<Transform>,
+    current: Option<Transform>,
+}
+
+/// How a [`TransformInterpolation`] estimates the transform between fixed ticks.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, PartialEq)]
+pub enum InterpolationMode {
+    /// Blends the transforms of the two latest ticks.
+    ///
+    /// The entity is displayed up to one tick behind the simulation, but always at a position it reached.
+    #[default]
+    Interpolate,
+    /// Extends the motion between the two latest ticks past the latest one.
+    ///
+    /// The entity is displayed where it is predicted to be, which avoids the latency of interpolation,
+    /// but overshoots when its motion changes.
+    Extrapolate,
+}
+
+impl TransformInterpolation {
+    /// Creates a component that interpolates between fixed ticks.
+    pub fn interpolate() -> Self {
+        Self::default()
+    }
+
+    /// Creates a component that extrapolates past the latest fixed tick.
+    pub fn extrapolate() -> Self {
+        Self {
+            mode: InterpolationMode::Extrapolate,
+            ..Self::default()
+        }
+    }
+
+    /// Forgets the recorded transforms, so that the next tick is displayed without blending.
+    pub fn reset(&mut self) {
+        self.previous = None;
+        self.current = None;
+    }
+
+    /// Returns the transform of the fixed tick before the latest one, if recorded.
+    pub fn previous(&self) -> Option<&Transform> {
+        self.previous.as_ref()
+    }
+
+    /// Returns the transform of the latest fixed tick, if recorded.
+    pub fn current(&self) -> Option<&Transform> {
+        self.current.as_ref()
+    }
+
+    /// Returns the transform displayed at `overstep_fraction` of a tick past the latest one.
+    pub fn blend(&self, overstep_fraction: f32) -> Option<Transform> {
+        let current = self.current?;
+        let previous = self.previous.unwrap_or(current);
+        Some(match self.mode {
+            InterpolationMode::Interpolate => Transform {
+                translation: previous
+                    .translation
+                    .lerp(current.translation, overstep_fraction),
+                rotation: previous.rotation.slerp(current.rotation, overstep_fraction),
+                scale: previous.scale.lerp(current.scale, overstep_fraction),
+            },
+            InterpolationMode::Extrapolate => {
+                let rotation_delta = current.rotation * previous.rotation.inverse();
+                Transform {
+                    translation: current.translation
+                        + (current.translation - previous.translation) * overstep_fraction,
+                    rotation: Quat::IDENTITY.slerp(rotation_delta, overstep_fraction)
+                        * current.rotation,
+                    scale: current.scale + (current.scale - previous.scale) * overstep_fraction,
+                }
+            }
+        })
+    }
+}
+
+/// Records the [`Transform`] of entities with a [`TransformInterpolation`] at the end of a fixed tick.
+pub fn record_interpolated_transforms(mut query: Query<(&Transform, &mut TransformInterpolation)>) {
+    for (transform, mut interpolation) in &mut query {
+        interpolation.previous = interpolation.current.or(Some(*transform));
+        interpolation.current = Some(*transform);
+    }
+}
+
+/// Sets the [`GlobalTransform`] of entities with a [`TransformInterpolation`] and of their descendants
+/// from a blend of their transforms of the last two fixed ticks.
+///
+/// Runs after transform propagation, and recomputes the [`GlobalTransform`] of entities whose
+/// [`TransformInterpolation`] was removed, so that they stop being displayed at the blended transform.
+pub fn interpolate_transforms(
+    time: Res<Time<Fixed>>,
+    interpolated: Query<Entity, With<TransformInterpolation>>,
+    mut removed: RemovedComponents<TransformInterpolation>,
+    parents: Query<&Parent>,
+    mut transforms: Query<(
+        &Transform,
+        Option<&TransformInterpolation>,
+        &mut GlobalTransform,
+        Option<&Children>,
+    )>,
+) {
+    let overstep_fraction = time.overstep_fraction();
+    for entity in interpolated.iter().chain(removed.read()) {
+        // Descendants are updated with their ancestors.
+        if parents
+            .iter_ancestors(entity)
+            .any(|ancestor| interpolated.contains(ancestor))
+        {
+            continue;
+        }
+        let parent = parents
+            .get(entity)
+            .ok()
+            .and_then(|parent| transforms.get(parent.get()).ok())
+            .map(|(_, _, global_transform, _)| *global_transform);
+        update_global_transforms(&mut transforms, entity, parent, overstep_fraction);
+    }
+}
+
+fn update_global_transforms(
+    transforms: &mut Query<(
+        &Transform,
+        Option<&TransformInterpolation>,
+        &mut GlobalTransform,
+        Option<&Children>,
+    )>,
+    entity: Entity,
+    parent: Option<GlobalTransform>,
+    overstep_fraction: f32,
+) {
+    let Ok((transform, interpolation, mut global_transform, children)) = transforms.get_mut(entity)
+    else {
+        return;
+    };
+    let transform = interpolation
+        .and_then(|interpolation| interpolation.blend(overstep_fraction))
+        .unwrap_or(*transform);
+    let global = match parent {
+        Some(parent) => parent.mul_transform(transform),
+        None => GlobalTransform::from(transform),
+    };
+    *global_transform = global;
+    let children = children
+        .map(|children| children.to_vec())
+        .unwrap_or_default();
+    for child in children {
+        update_global_transforms(transforms, child, Some(global), overstep_fraction);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bevy_app::prelude::*;
+    use bevy_ecs::prelude::*;
+    use bevy_hierarchy::BuildChildren;
+    use bevy_math::Vec3;
+    use bevy_time::{Fixed, Time, TimePlugin, TimeUpdateStrategy};
+
+    use crate::prelude::*;
+
+    #[derive(Component)]
+    struct Velocity(Vec3);
+
+    fn movement(mut query: Query<(&mut Transform, &Velocity)>) {
+        for (mut transform, velocity) in &mut query {
+            transform.translation += velocity.0;
+        }
+    }
+
+    #[test]
+    fn interpolate_between_ticks() {
+        let timestep = Time::<Fixed>::default().timestep();
+        let mut app = App::new();
+        app.add_plugins((TimePlugin, TransformPlugin))
+            .insert_resource(TimeUpdateStrategy::ManualDuration(timestep / 4))
+            .add_systems(FixedUpdate, movement);
+        let interpolated = app
+            .world_mut()
+            .spawn((
+                TransformBundle::default(),
+                TransformInterpolation::interpolate(),
+                Velocity(Vec3::X),
+            ))
+            .id();
+        let child = app
+            .world_mut()
+            .spawn(TransformBundle::from_transform(Transform::from_xyz(
+                0.0, 1.0, 0.0,
+            )))
+            .set_parent(interpolated)
+            .id();
+        let extrapolated = app
+            .world_mut()
+            .spawn((
+                TransformBundle::default(),
+                TransformInterpolation::extrapolate(),
+                Velocity(Vec3::X),
+            ))
+            .id();
+
+        let translation = |app: &App, entity| {
+            app.world()
+                .get::<GlobalTransform>(entity)
+                .unwrap()
+                .translation()
+                .x
+        };
+        // Run until two ticks were recorded, and the next one is a quarter of a tick away.
+        while app
+            .world()
+            .get::<TransformInterpolation>(interpolated)
+            .unwrap()
+            .current()
+            .filter(|current| current.translation.x >= 2.0)
+            .is_none()
+        {
+            app.update();
+        }
+        app.update();
+        app.update();
+        app.update();
+
+        let overstep = app.world().resource::<Time<Fixed>>().overstep_fraction();
+        assert_eq!(overstep, 0.75);
+        assert_eq!(translation(&app, interpolated), 1.75);
+        assert_eq!(translation(&app, extrapolated), 2.75);
+        assert_eq!(
+            app.world()
+                .get::<GlobalTransform>(child)
+                .unwrap()
+                .translation(),
+            Vec3::new(1.75, 1.0, 0.0)
+        );
+        // The gameplay transform is left alone.
+        for entity in [interpolated, extrapolated] {
+            assert_eq!(
+                app.world().get::<Transform>(entity).unwrap().translation.x,
+                2.0
+            );
+        }
+
+        // The simulation continues from the recorded transform.
+        app.update();
+        assert_eq!(
+            app.world()
+                .get::<TransformInterpolation>(interpolated)
+                .unwrap()
+                .current()
+                .unwrap()
+                .translation
+                .x,
+            3.0
+        );
+        assert_eq!(translation(&app, interpolated), 2.0);
+    }
+}
@@ -21,6 +21,10 @@ pub mod traits;
 #[cfg(feature = "bevy-support")]
 pub mod plugins;
 
+/// Interpolation of transforms updated in the fixed timestep schedules
+#[cfg(feature = "bevy-support")]
+pub mod interpolation;
+
 /// [`GlobalTransform`]: components::GlobalTransform
 /// Helpers related to computing global transforms
 #[cfg(feature = "bevy-support")]
@@ -38,7 +42,8 @@ pub mod prelude {
     #[doc(hidden)]
     pub use crate::{
         bundles::TransformBundle, commands::BuildChildrenTransformExt, helper::TransformHelper,
-        plugins::TransformPlugin, plugins::TransformSystem, traits::TransformPoint,
+        interpolation::TransformInterpolation, plugins::TransformPlugin, plugins::TransformSystem,
+        traits::TransformPoint,
     };
 }
 
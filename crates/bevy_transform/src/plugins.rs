@@ -1,8 +1,15 @@
-use bevy_app::{App, Plugin, PostStartup, PostUpdate};
-use bevy_ecs::schedule::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet};
+use bevy_app::{App, FixedLast, Plugin, PostStartup, PostUpdate};
+use bevy_ecs::schedule::{
+    common_conditions::resource_exists, IntoSystemConfigs, IntoSystemSetConfigs, SystemSet,
+};
 use bevy_hierarchy::ValidParentCheckPlugin;
+use bevy_time::{Fixed, Time};
 
 use crate::{
+    interpolation::{
+        interpolate_transforms, record_interpolated_transforms, InterpolationMode,
+        TransformInterpolation,
+    },
     prelude::{GlobalTransform, Transform},
     systems::{propagate_transforms, sync_simple_transforms},
 };
@@ -12,6 +19,9 @@ use crate::{
 pub enum TransformSystem {
     /// Propagates changes in transform to children's [`GlobalTransform`]
     TransformPropagate,
+    /// Blends the [`GlobalTransform`] of entities with a [`TransformInterpolation`] between fixed ticks,
+    /// after propagation
+    TransformInterpolate,
 }
 
 /// The base plugin for handling [`Transform`] components
@@ -27,6 +37,8 @@ impl Plugin for TransformPlugin {
 
         app.register_type::<Transform>()
             .register_type::<GlobalTransform>()
+            .register_type::<TransformInterpolation>()
+            .register_type::<InterpolationMode>()
             .add_plugins(ValidParentCheckPlugin::<GlobalTransform>::default())
             .configure_sets(
                 PostStartup,
@@ -57,6 +69,20 @@ impl Plugin for TransformPlugin {
                         .ambiguous_with(PropagateTransformsSet),
                     propagate_transforms.in_set(PropagateTransformsSet),
                 ),
-            );
+            )
+            .configure_sets(
+                PostUpdate,
+                TransformSystem::TransformInterpolate
+                    .in_set(TransformSystem::TransformPropagate)
+                    .after(PropagateTransformsSet)
+                    .run_if(resource_exists::<Time<Fixed>>),
+            )
+            .add_systems(
+                PostUpdate,
+                interpolate_transforms
+                    .in_set(TransformSystem::TransformInterpolate)
+                    .after(sync_simple_transforms),
+            )
+            .add_systems(FixedLast, record_interpolated_transforms);
     }
 }